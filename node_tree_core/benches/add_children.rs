@@ -0,0 +1,47 @@
+//!
+//! Benchmarks adding a large batch of identically named children via `add_children()` against
+//! calling `add_child()` once per child. Run via `cargo bench --bench add_children`.
+//!
+
+use std::time::{ Duration, Instant };
+
+use node_tree::prelude::*;
+use node_tree::trees::TreeSimple;
+
+
+const BATCH_SIZES: [usize; 3] = [1_000, 2_000, 4_000];
+
+
+class! {
+    dec Leaf;
+}
+
+
+/// Builds a fresh tree and returns how long it took to add the batch of children to its root.
+fn measure(count: usize, batched: bool) -> Duration {
+    let mut tree:     Box<TreeSimple> = TreeSimple::new(Leaf::new(), LoggerVerbosity::NoDebug);
+    let     children: Vec<Leaf>       = (0..count).map(|_| Leaf::new()).collect();
+    
+    let started: Instant = Instant::now();
+    if batched {
+        tree.root_mut().add_children(children);
+    } else {
+        for child in children {
+            tree.root_mut().add_child(child);
+        }
+    }
+    let elapsed: Duration = started.elapsed();
+
+    assert_eq!(tree.root().num_children(), count);
+    elapsed
+}
+
+
+fn main() {
+    println!("{:<8} {:>14} {:>14}", "children", "add_child", "add_children");
+    for count in BATCH_SIZES {
+        let repeated: Duration = measure(count, false);
+        let batched:  Duration = measure(count, true);
+        println!("{count:<8} {:>12.3}ms {:>12.3}ms", repeated.as_secs_f64() * 1000.0, batched.as_secs_f64() * 1000.0);
+    }
+}
@@ -0,0 +1,66 @@
+//!
+//! Benchmarks the register/unregister/retrieve churn of `RIDHolder`, which backs the node storage
+//! of every `NodeTree`. Run via `cargo bench --bench rid_holder`.
+//!
+
+use std::hint::black_box;
+use std::time::{ Duration, Instant };
+
+use node_tree::structs::rid::{ RID, RIDHolder };
+
+
+const ITEMS:  usize = 100_000;
+const ROUNDS: u32   = 20;
+
+
+/// Runs the given routine for each round and returns the average time that it took.
+fn measure(mut routine: impl FnMut()) -> Duration {
+    routine(); // Warm up.
+    
+    let started: Instant = Instant::now();
+    for _ in 0..ROUNDS {
+        routine();
+    }
+    started.elapsed() / ROUNDS
+}
+
+fn report(name: &str, elapsed: Duration, ops: usize) {
+    println!("{name:<10} {:>10.3}ms {:>8.2}ns/op", elapsed.as_secs_f64() * 1000.0, elapsed.as_nanos() as f64 / ops as f64);
+}
+
+
+fn main() {
+    let register: Duration = measure(|| {
+        let mut holder: RIDHolder<u64> = RIDHolder::new();
+        for i in 0..ITEMS {
+            black_box(holder.push(i as u64));
+        }
+    });
+    report("register", register, ITEMS);
+
+    let mut holder: RIDHolder<u64> = RIDHolder::new();
+    let     rids:   Vec<RID>       = (0..ITEMS).map(|i| holder.push(i as u64)).collect();
+    
+    let retrieve: Duration = measure(|| {
+        for &rid in &rids {
+            black_box(holder.retrieve(rid));
+        }
+    });
+    report("retrieve", retrieve, ITEMS);
+
+    let iterate: Duration = measure(|| {
+        black_box(holder.iter().sum::<u64>());
+    });
+    report("iterate", iterate, ITEMS);
+
+    // Free every other item and register them again, as happens when nodes are spawned and freed.
+    let churn: Duration = measure(|| {
+        for &rid in rids.iter().step_by(2) {
+            black_box(holder.take(rid));
+        }
+        for i in 0..ITEMS / 2 {
+            black_box(holder.push(i as u64));
+        }
+    });
+    report("churn", churn, ITEMS);
+}
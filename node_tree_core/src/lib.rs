@@ -50,8 +50,14 @@
 //!     export         let some_parameter: String;
 //!     export default let some_parameter_default: bool;
 //!
+//!     // Exportable fields marked with `notify` are kept private, and instead generate a getter
+//!     // `hp()`, a setter `set_hp()`, and a `hp_changed` signal which is emitted by the setter.
+//!     export(notify) let hp: u32;
+//!
 //!     // Hooks are any system functions that can be overridden.
-//!     // This include the constructor `_init()`, `loaded()`, `ready()`, `process()`, `terminal()`, and `process_mode()`.
+//!     // This include the constructor `_init()`, `loaded()`, `ready()`, `process()`, `input()`, `terminal()`, their fallible
+//!     // `try_ready()`, `try_process()` and `try_terminal()` variants, `dependency_ready()`, `child_added()`,
+//!     // `child_removed()`, `reinstance()`, `process_mode()`, `process_order()`, and `log_identity()`.
 //!
 //!     /// The constructor may only need to be implemented if there exists fields that do not have
 //!     /// a default value.
@@ -78,32 +84,44 @@ pub mod structs;
 pub mod traits;
 pub mod utils;
 pub mod trees;
+
+#[cfg(feature = "testing")]
+pub mod testing;
+
 pub mod prelude {
     //! Contains everything you'll need to create and handle Nodes and NodeTrees.
     //! You'll probably want to import all from this module.
     
-    pub use node_tree_derive::{ Abstract, Register, Tree, scene, connect, class };
+    pub use node_tree_derive::{ Abstract, Register, Exportable, Tree, scene, connect, class };
     pub use crate::structs::{
-        logger::{ LoggerVerbosity, Log },
-        node_base::NodeBase,
+        logger::{ LoggerVerbosity, Log, LogLevel, LogRecord, LogSummary, PanicContext },
+        input::InputEvent,
+        node_base::{ NodeBase, NodeError },
         node_field::{ Field, ExportableField, UniqueField, DefaultField },
         node_path::NodePath,
-        node_tree_base::{ NodeTreeBase, TreeStatus, TreeProcess, ProcessMode, TerminationReason, initialize_base },
-        tree_pointer::{ Tp, TpDyn },
+        node_tree_base::{ NodeTreeBase, TreeStatus, TreeProcess, ProcessMode, ProcessOrder, TerminationReason, NodeErrorPolicy, NodePanicPolicy, CancelHandle, initialize_base },
+        tree_pointer::{ Tp, TpDyn, TpStrong },
         tree_option::TreeOption,
         tree_result::TreeResult,
-        node_scene::NodeScene,
-        rid::RID,
+        node_scene::{ NodeScene, SceneDiff },
+        placeholder::Placeholder,
+        rid::{ RID, RidStats },
+        rng::NodeRng,
+        geometry::{ Vec2, Rect },
         signals::Signal
     };
     pub use crate::traits::{
         node::{ Node, NodeAbstract },
-        exportable::{ Voidable, Exportable },
+        exportable::{ Voidable, Exportable, ExportError, ValueKind },
         registered::Registered,
         node_tree::NodeTree,
-        instanceable::Instanceable
+        instanceable::Instanceable,
+        bounded::{ Bounded, register_bounded }
     };
-    pub use crate::{ nodepath, debug, info, warn, error };
+    pub use crate::services::connection_registry::{ PersistentConnection, register_signal, register_method };
+    pub use crate::services::resource_cache::ResourceCache;
+    pub use crate::{ nodepath, impl_exportable_enum, debug, info, warn, error };
 }
 
 pub use ctor;
+pub use node_tree_derive::main;
@@ -0,0 +1,159 @@
+//===================================================================================================================================================================================//
+//
+//   /$$$$$$                                                      /$$     /$$                           /$$$$$$$                      /$$             /$$                        
+//  /$$__  $$                                                    | $$    |__/                          | $$__  $$                    |__/            | $$                        
+// | $$  \__/  /$$$$$$  /$$$$$$$  /$$$$$$$   /$$$$$$   /$$$$$$$ /$$$$$$   /$$  /$$$$$$  /$$$$$$$       | $$  \ $$  /$$$$$$   /$$$$$$  /$$  /$$$$$$$ /$$$$$$    /$$$$$$  /$$   /$$
+// | $$       /$$__  $$| $$__  $$| $$__  $$ /$$__  $$ /$$_____/|_  $$_/  | $$ /$$__  $$| $$__  $$      | $$$$$$$/ /$$__  $$ /$$__  $$| $$ /$$_____/|_  $$_/   /$$__  $$| $$  | $$
+// | $$      | $$  \ $$| $$  \ $$| $$  \ $$| $$$$$$$$| $$        | $$    | $$| $$  \ $$| $$  \ $$      | $$__  $$| $$$$$$$$| $$  \ $$| $$|  $$$$$$   | $$    | $$  \__/| $$  | $$
+// | $$    $$| $$  | $$| $$  | $$| $$  | $$| $$_____/| $$        | $$ /$$| $$| $$  | $$| $$  | $$      | $$  \ $$| $$_____/| $$  | $$| $$ \____  $$  | $$ /$$| $$      | $$  | $$
+// |  $$$$$$/|  $$$$$$/| $$  | $$| $$  | $$|  $$$$$$$|  $$$$$$$  |  $$$$/| $$|  $$$$$$/| $$  | $$      | $$  | $$|  $$$$$$$|  $$$$$$$| $$ /$$$$$$$/  |  $$$$/| $$      |  $$$$$$$
+//  \______/  \______/ |__/  |__/|__/  |__/ \_______/ \_______/   \___/  |__/ \______/ |__/  |__/      |__/  |__/ \_______/ \____  $$|__/|_______/    \___/  |__/       \____  $$
+//                                                                                                                          /$$  \ $$                                   /$$  | $$
+//                                                                                                                         |  $$$$$$/                                  |  $$$$$$/
+//                                                                                                                          \______/                                    \______/ 
+//
+//===================================================================================================================================================================================//
+
+//?
+//? Created by LunaticWyrm467 and others.
+//? 
+//? All code is licensed under the MIT license.
+//? Feel free to reproduce, modify, and do whatever.
+//?
+
+//!
+//! Provides the registries which allow for signal connections to be declared by name, so that they
+//! can be saved along with a scene and re-established once it is loaded.
+//!
+//! As closures cannot be serialized, a persistent connection only stores the name of the signal,
+//! the path to the target node and the name of the method to call. The signal and method names
+//! are then resolved through the registries below, which are populated via `register_signal()`
+//! and `register_method()`.
+//! 
+
+use std::{ any::{ Any, TypeId }, cell::RefCell, collections::HashMap, rc::Rc };
+
+use crate::structs::{ logger::Log, node_path::NodePath, signals::Signal, rid::RID };
+use crate::traits::{ node::Node, node_tree::NodeTree };
+
+
+/// A listener which receives a signal's arguments in a type-erased form.
+type Listener = Box<dyn FnMut(&dyn Any)>;
+
+/// Connects a type-erased listener to a node's signal, returning a function which disconnects it.
+type SignalConnector = Rc<dyn Fn(&dyn Node, Listener) -> Option<Box<dyn FnOnce()>>>;
+
+/// Invokes a node's method with a type-erased set of arguments.
+type MethodInvoker = Rc<dyn Fn(&mut dyn Node, &dyn Any) -> Result<(), String>>;
+
+thread_local! {
+    static SIGNAL_REGISTRY: RefCell<HashMap<(TypeId, String), SignalConnector>> = RefCell::new(HashMap::new());
+    static METHOD_REGISTRY: RefCell<HashMap<(TypeId, String), MethodInvoker>>   = RefCell::new(HashMap::new());
+}
+
+
+/// A declarative connection between a node's signal and a method of another node, which is saved
+/// along with the node's scene.
+/// See `NodeBase::connect_persistent()` for more information.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PersistentConnection {
+    
+    /// The name of the signal on the source node.
+    pub signal: String,
+
+    /// The path to the target node, relative to the source node.
+    pub target: NodePath,
+
+    /// The name of the method on the target node.
+    pub method: String
+}
+
+
+/// Registers one of a node type's signals under the given name, so that it can be used by
+/// persistent connections. Registering the same name more than once replaces the prior signal.
+///
+/// # Note
+/// The registry is per-thread, so this must be called on the same thread as the `NodeTree`.
+///
+/// # Example
+/// ```rust, ignore
+/// register_signal::<Button, ()>("pressed", |button| &button.pressed);
+/// ```
+pub fn register_signal<N: Node, T: 'static>(name: &str, signal: fn(&N) -> &Signal<T>) {
+    let connector: SignalConnector = Rc::new(move |node: &dyn Node, mut callback: Listener| {
+        let signal: &Signal<T> = signal(node.as_any().downcast_ref::<N>()?);
+        let rid:    RID        = unsafe { signal.connect(move |args: &T| callback(args)) };
+        Some(Box::new(signal.disconnector(rid)) as Box<dyn FnOnce()>)
+    });
+
+    SIGNAL_REGISTRY.with(|registry| {
+        registry.borrow_mut().insert((TypeId::of::<N>(), name.to_string()), connector);
+    });
+}
+
+/// Registers one of a node type's methods under the given name, so that it can be called by
+/// persistent connections. The method receives the arguments that the connected signal was emitted
+/// with, so `T` must match the connected signal's type. Registering the same name more than once
+/// replaces the prior method.
+///
+/// # Note
+/// The registry is per-thread, so this must be called on the same thread as the `NodeTree`.
+///
+/// # Example
+/// ```rust, ignore
+/// register_method::<Door, ()>("open", |door, _| door.open());
+/// ```
+pub fn register_method<N: Node, T: 'static>(name: &str, method: fn(&mut N, &T)) {
+    let invoker: MethodInvoker = Rc::new(move |node: &mut dyn Node, args: &dyn Any| {
+        let node: &mut N = node.as_any_mut().downcast_mut::<N>().ok_or("mismatched node type".to_string())?;
+        let args: &T     = args.downcast_ref::<T>().ok_or("the signal's arguments do not match the method's".to_string())?;
+        method(node, args);
+        Ok(())
+    });
+
+    METHOD_REGISTRY.with(|registry| {
+        registry.borrow_mut().insert((TypeId::of::<N>(), name.to_string()), invoker);
+    });
+}
+
+/// Establishes a persistent connection for the node of the given `RID`.
+/// The target is resolved each time that the signal is emitted, so it does not need to be a part of
+/// the tree yet. The connection is severed once the source node leaves the tree.
+///
+/// # Safety
+/// The tree pointer must be valid for as long as the source node is a part of it.
+pub(crate) unsafe fn establish(tree: *mut dyn NodeTree, source: RID, connection: &PersistentConnection) -> Result<(), String> {
+    let node:      &dyn Node       = (*tree).get_node(source).ok_or("the source node is not a part of the tree".to_string())?;
+    let connector: SignalConnector = SIGNAL_REGISTRY.with(|registry| registry.borrow().get(&(node.as_any().type_id(), connection.signal.clone())).cloned())
+        .ok_or(format!("the signal \"{}\" has not been registered for {}", connection.signal, node.name_as_type()))?;
+
+    let target: NodePath = connection.target.clone();
+    let method: String   = connection.method.clone();
+    let disconnect: Box<dyn FnOnce()> = connector(node, Box::new(move |args: &dyn Any| {
+        let tree: &mut dyn NodeTree = unsafe { &mut *tree };
+        let target_rid: Option<RID> = match tree.get_node(source) {
+            Some(source) => source.get_node_raw(target.clone()),
+            None         => return
+        };
+
+        let result: Result<(), String> = match target_rid.and_then(|rid| tree.get_node_mut(rid)) {
+            Some(node) => invoke(node, &method, args),
+            None       => Err(format!("the target node at \"{target}\" could not be found"))
+        };
+        if let Err(err) = result {
+            if let Some(source) = tree.get_node(source) {
+                source.post(Log::Warn(&format!("Failed to call \"{method}\" through a persistent connection: {err}")));
+            }
+        }
+    })).ok_or("mismatched node type".to_string())?;
+
+    node.on_tree_exit(disconnect);
+    Ok(())
+}
+
+/// Calls a registered method on a node with a type-erased set of arguments.
+fn invoke(node: &mut dyn Node, method: &str, args: &dyn Any) -> Result<(), String> {
+    let invoker: MethodInvoker = METHOD_REGISTRY.with(|registry| registry.borrow().get(&(node.as_any().type_id(), method.to_string())).cloned())
+        .ok_or(format!("the method \"{method}\" has not been registered for {}", node.name_as_type()))?;
+    invoker(node, args)
+}
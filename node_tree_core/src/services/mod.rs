@@ -1 +1,3 @@
+pub mod connection_registry;
 pub mod node_registry;
+pub mod resource_cache;
@@ -32,8 +32,10 @@ use dashmap::DashMap;
 
 pub use toml_edit::Value;
 
+use crate::structs::placeholder::Placeholder;
 use crate::traits::node::Node;
 use crate::traits::exportable::Exportable;
+use crate::traits::registered::Registered;
 
 
 /// Represents a static node registry.
@@ -46,7 +48,7 @@ use crate::traits::exportable::Exportable;
 /// This however also has its downsides, such as the moving of the module that a node is defined
 /// in, or renaming the node itself resulting in the invalidation of prior save data.
 ///
-/// Furthermore, the interesting thing about using a `HashMap<Box<str>, Box<dyn Serializable>>`/`HashMap<Box<str>, toml::Value>
+/// Furthermore, the interesting thing about using a `HashMap<Box<str>, Box<dyn Exportable>>`/`HashMap<Box<str>, toml::Value>
 /// as the opaque representation for a `Node`'s owned fields is that a derive macro can easily generate
 /// the deserialization function and register it to the `NODE_REGISTRY`. An example of a generate
 /// function is shown below:
@@ -63,9 +65,9 @@ use crate::traits::exportable::Exportable;
 /// implemented via `class!`:
 /// fn deserialize(owned_state: SFieldMap) -> Result<Box<dyn Node>, String> {
 ///     let node: NodeName = NodeName::load_from_owned(
-///         String::from_value(*owned_state.remove("field_a").ok_or("corrupt save data; `field_a` missing".to_string())?).ok_or("corrupt save data; `field_a` invalid type".to_string())?,
-///         u32::from_value(*owned_state.remove("field_b").ok_or("corrupt save data; `field_b` missing".to_string())?).ok_or("corrupt save data; `field_b` invalid type".to_string())?,
-///         Vec::from_value(*owned_state.remove("field_c").ok_or("corrupt save data; `field_c` missing".to_string())?).ok_or("corrupt save data; `field_c` invalid type".to_string())?,
+///         String::try_from_value(*owned_state.remove("field_a").ok_or("corrupt save data; `field_a` missing".to_string())?).map_err(|err| format!("corrupt save data; `field_a` is invalid: {err}"))?,
+///         u32::try_from_value(*owned_state.remove("field_b").ok_or("corrupt save data; `field_b` missing".to_string())?).map_err(|err| format!("corrupt save data; `field_b` is invalid: {err}"))?,
+///         Vec::try_from_value(*owned_state.remove("field_c").ok_or("corrupt save data; `field_c` missing".to_string())?).map_err(|err| format!("corrupt save data; `field_c` is invalid: {err}"))?,
 ///     );
 ///     Box::new(node)
 /// }
@@ -78,6 +80,9 @@ pub type FieldMap = HashMap<Box<str>, Box<dyn Exportable>>;
 /// Used as an alias for a map containing the serialized fields of a node, along with its associated values.
 pub type SFieldMap = HashMap<Box<str>, Value>;
 
+/// The field under which a node's schema version is saved.
+pub const SCHEMA_VERSION_KEY: &str = "__schema_version";
+
 /// Used as an alias for the deserializer function.
 pub type Deserializer = dyn Fn(SFieldMap) -> Result<Box<dyn Node>, String>;
 
@@ -106,15 +111,54 @@ pub unsafe fn register_deserializer(name: Box<str>, deserializer: impl Fn(SField
 pub fn deserialize(name: &str, owned_state: SFieldMap) -> Result<Box<dyn Node>, String> {
     #![allow(static_mut_refs)] // SAFETY: Only modified during initialization before main.
     
+    // Built-in nodes are not registered, as they are not declared via the `class!` macro.
+    if name == std::any::type_name::<Placeholder>() {
+        return Ok(Box::new(Placeholder::load_from_owned(owned_state)?));
+    }
+    
     // Safety:
     // This does not mutate state and `register_deserializer`, which does mutate state, is marked
     // unsafe and is expected to run before the main function is invoked.
     unsafe {
         (NODE_REGISTRY.as_ref()
-         .ok_or("attempting to deserialize from an unregistered node".to_string())?
+         .ok_or(format!("attempting to deserialize from an unregistered node `{name}`"))?
          .registry
          .get(name)
-         .ok_or("attempting to deserialize from an unregistered node".to_string()))?
+         .ok_or(format!("attempting to deserialize from an unregistered node `{name}`")))?
             (owned_state)
     }
 }
+
+/// Returns whether a node of the given type name can be deserialized, either because a
+/// deserializer was registered under its name or because it is a built-in node.
+pub fn is_registered(name: &str) -> bool {
+    #![allow(static_mut_refs)] // SAFETY: Only modified during initialization before main.
+    if name == std::any::type_name::<Placeholder>() {
+        return true;
+    }
+    
+    // Safety:
+    // See `deserialize()`.
+    unsafe {
+        NODE_REGISTRY.as_ref().is_some_and(|registry| registry.registry.contains_key(name))
+    }
+}
+
+/// Creates a fresh instance of a node by passing its exported fields through its registered
+/// deserializer, rather than by cloning it.
+/// Fields which are not exported are left void, just as they would be for a node loaded from disk,
+/// and are expected to be restored by the node's `loaded()` hook.
+/// The new node keeps the name of the original, but is not connected to any `NodeTree`.
+pub fn reinstance(node: &dyn Node) -> Result<Box<dyn Node>, String> {
+    let owned_state: SFieldMap = node.save_from_owned()
+        .into_iter()
+        .filter(|(_, value)| !unsafe { value.is_ghost_export() })
+        .map(|(field, value)| (field, value.to_value()))
+        .collect();
+
+    let mut instance: Box<dyn Node> = deserialize(&node.name_as_type(), owned_state)?;
+    unsafe {
+        instance.set_name_unchecked(node.name());
+    }
+    Ok(instance)
+}
@@ -0,0 +1,111 @@
+//===================================================================================================================================================================================//
+//
+//  /$$$$$$$                                                                               /$$$$$$                      /$$                
+// | $$__  $$                                                                             /$$__  $$                    | $$                
+// | $$  \ $$  /$$$$$$   /$$$$$$$  /$$$$$$  /$$   /$$  /$$$$$$   /$$$$$$$  /$$$$$$       | $$  \__/  /$$$$$$   /$$$$$$$| $$$$$$$   /$$$$$$ 
+// | $$$$$$$/ /$$__  $$ /$$_____/ /$$__  $$| $$  | $$ /$$__  $$ /$$_____/ /$$__  $$      | $$       |____  $$ /$$_____/| $$__  $$ /$$__  $$
+// | $$__  $$| $$$$$$$$|  $$$$$$ | $$  \ $$| $$  | $$| $$  \__/| $$      | $$$$$$$$      | $$        /$$$$$$$| $$      | $$  \ $$| $$$$$$$$
+// | $$  \ $$| $$_____/ \____  $$| $$  | $$| $$  | $$| $$      | $$      | $$_____/      | $$    $$ /$$__  $$| $$      | $$  | $$| $$_____/
+// | $$  | $$|  $$$$$$$ /$$$$$$$/|  $$$$$$/|  $$$$$$/| $$      |  $$$$$$$|  $$$$$$$      |  $$$$$$/|  $$$$$$$|  $$$$$$$| $$  | $$|  $$$$$$$
+// |__/  |__/ \_______/|_______/  \______/  \______/ |__/       \_______/ \_______/       \______/  \_______/ \_______/|__/  |__/ \_______/
+//
+//===================================================================================================================================================================================//
+
+//?
+//? Created by LunaticWyrm467 and others.
+//? 
+//? All code is licensed under the MIT license.
+//? Feel free to reproduce, modify, and do whatever.
+//?
+
+//!
+//! Provides a cache of parsed scenes keyed by their path on disk, so that a scene which is
+//! instanced many times is only read and parsed once.
+//!
+//! Each `NodeTree` owns its own cache. As the tree is confined to a single thread, so is its cache,
+//! which is why the cached scenes are shared via `Rc` rather than `Arc`.
+//! 
+
+use std::{ collections::HashMap, fs, path::{ Path, PathBuf }, rc::Rc, time::SystemTime };
+
+use crate::structs::node_scene::NodeScene;
+
+
+/// A scene which was loaded from disk, along with the modification time of its file at the time.
+#[derive(Debug)]
+struct CachedScene {
+    modified: SystemTime,
+    scene:    Rc<NodeScene>
+}
+
+
+/// A cache of `NodeScene`s which were loaded from disk, keyed by their path.
+/// A cached scene is reloaded if its file has been modified since it was cached.
+/// See `NodeTreeBase::load_scene_cached()`.
+#[derive(Debug, Default)]
+pub struct ResourceCache {
+    scenes: HashMap<PathBuf, CachedScene>
+}
+
+impl ResourceCache {
+    
+    /// Creates an empty cache.
+    pub fn new() -> Self {
+        ResourceCache {
+            scenes: HashMap::new()
+        }
+    }
+
+    /// Gets the scene at the given path, only loading it from disk if it is not cached or if its
+    /// file was modified since it was cached.
+    /// Returns `Err` if the scene could not be loaded.
+    ///
+    /// # Note
+    /// If the modification time of the file cannot be read on this platform, then the scene is
+    /// loaded from disk every time and is never cached.
+    pub fn load_scene(&mut self, path: &Path) -> Result<Rc<NodeScene>, String> {
+        let modified: Option<SystemTime> = fs::metadata(path).and_then(|metadata| metadata.modified()).ok();
+        if let (Some(modified), Some(cached)) = (modified, self.scenes.get(path)) {
+            if cached.modified == modified {
+                return Ok(cached.scene.clone());
+            }
+        }
+
+        let scene: Rc<NodeScene> = Rc::new(NodeScene::load(path)?);
+        match modified {
+            Some(modified) => {
+                self.scenes.insert(path.to_path_buf(), CachedScene { modified, scene: scene.clone() });
+            },
+            None => {
+                self.scenes.remove(path);
+            }
+        }
+        Ok(scene)
+    }
+
+    /// Returns whether the scene at the given path is cached.
+    pub fn contains(&self, path: &Path) -> bool {
+        self.scenes.contains_key(path)
+    }
+
+    /// Removes the scene at the given path from the cache, returning whether it was cached.
+    pub fn evict(&mut self, path: &Path) -> bool {
+        self.scenes.remove(path).is_some()
+    }
+
+    /// Removes every scene from the cache.
+    /// Any `Rc<NodeScene>` that was handed out remains valid.
+    pub fn clear(&mut self) {
+        self.scenes.clear();
+    }
+
+    /// Returns the number of cached scenes.
+    pub fn len(&self) -> usize {
+        self.scenes.len()
+    }
+
+    /// Returns whether the cache is empty.
+    pub fn is_empty(&self) -> bool {
+        self.scenes.is_empty()
+    }
+}
@@ -0,0 +1,134 @@
+//===================================================================================================================================================================================//
+//
+//   /$$$$$$                                                /$$                        
+//  /$$__  $$                                              | $$                        
+// | $$  \__/  /$$$$$$   /$$$$$$  /$$$$$$/$$$$   /$$$$$$  /$$$$$$    /$$$$$$  /$$   /$$
+// | $$ /$$$$ /$$__  $$ /$$__  $$| $$_  $$_  $$ /$$__  $$|_  $$_/   /$$__  $$| $$  | $$
+// | $$|_  $$| $$$$$$$$| $$  \ $$| $$ \ $$ \ $$| $$$$$$$$  | $$    | $$  \__/| $$  | $$
+// | $$  \ $$| $$_____/| $$  | $$| $$ | $$ | $$| $$_____/  | $$ /$$| $$      | $$  | $$
+// |  $$$$$$/|  $$$$$$$|  $$$$$$/| $$ | $$ | $$|  $$$$$$$  |  $$$$/| $$      |  $$$$$$$
+//  \______/  \_______/ \______/ |__/ |__/ |__/ \_______/   \___/  |__/       \____  $$
+//                                                                            /$$  | $$
+//                                                                           |  $$$$$$/
+//                                                                            \______/ 
+//
+//===================================================================================================================================================================================//
+
+//?
+//? Created by LunaticWyrm467 and others.
+//? 
+//? All code is licensed under the MIT license.
+//? Feel free to reproduce, modify, and do whatever.
+//?
+
+//!
+//! Contains a minimal set of geometric primitives, which are used to describe the spatial extent
+//! of nodes via the `Bounded` trait.
+//!
+//! These are intentionally bare; they exist only to give spatial and UI trees a shared
+//! vocabulary without baking a specific geometry model into the core. With the `glam` feature
+//! enabled, `Vec2` can be freely converted to and from `glam::Vec2`. As `glam` has no rectangle
+//! type, a `Rect` is converted through its `min` and `max` corners instead.
+//! 
+
+
+/// A two-dimensional vector or point.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Vec2 {
+    pub x: f32,
+    pub y: f32
+}
+
+impl Vec2 {
+
+    /// A vector with both components set to zero.
+    pub const ZERO: Vec2 = Vec2 { x: 0.0, y: 0.0 };
+
+    /// Creates a new `Vec2`.
+    pub const fn new(x: f32, y: f32) -> Self {
+        Vec2 { x, y }
+    }
+
+    /// Returns the component-wise minimum of two vectors.
+    pub fn min(self, other: Vec2) -> Vec2 {
+        Vec2::new(self.x.min(other.x), self.y.min(other.y))
+    }
+
+    /// Returns the component-wise maximum of two vectors.
+    pub fn max(self, other: Vec2) -> Vec2 {
+        Vec2::new(self.x.max(other.x), self.y.max(other.y))
+    }
+}
+
+
+/// An axis-aligned rectangle, described by its minimum and maximum corners.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Rect {
+    pub min: Vec2,
+    pub max: Vec2
+}
+
+impl Rect {
+
+    /// Creates a new `Rect` from two opposing corners, in any order.
+    pub fn new(a: Vec2, b: Vec2) -> Self {
+        Rect {
+            min: a.min(b),
+            max: a.max(b)
+        }
+    }
+
+    /// Creates a new `Rect` from its top-left position and its size.
+    pub fn from_pos_size(pos: Vec2, size: Vec2) -> Self {
+        Rect::new(pos, Vec2::new(pos.x + size.x, pos.y + size.y))
+    }
+
+    /// Gets the width of the rectangle.
+    pub fn width(&self) -> f32 {
+        self.max.x - self.min.x
+    }
+
+    /// Gets the height of the rectangle.
+    pub fn height(&self) -> f32 {
+        self.max.y - self.min.y
+    }
+
+    /// Gets the smallest rectangle which contains both this and the other rectangle.
+    pub fn union(&self, other: &Rect) -> Rect {
+        Rect {
+            min: self.min.min(other.min),
+            max: self.max.max(other.max)
+        }
+    }
+
+    /// Returns whether the given point lies within the rectangle, inclusive of its edges.
+    pub fn contains(&self, point: Vec2) -> bool {
+        point.x >= self.min.x && point.x <= self.max.x && point.y >= self.min.y && point.y <= self.max.y
+    }
+
+    /// Returns whether this rectangle overlaps with the other rectangle, inclusive of edges.
+    pub fn intersects(&self, other: &Rect) -> bool {
+        self.min.x <= other.max.x && self.max.x >= other.min.x && self.min.y <= other.max.y && self.max.y >= other.min.y
+    }
+}
+
+
+/*
+ * Glam
+ *      Conversions
+ */
+
+
+#[cfg(feature = "glam")]
+impl From<glam::Vec2> for Vec2 {
+    fn from(value: glam::Vec2) -> Self {
+        Vec2::new(value.x, value.y)
+    }
+}
+
+#[cfg(feature = "glam")]
+impl From<Vec2> for glam::Vec2 {
+    fn from(value: Vec2) -> Self {
+        glam::Vec2::new(value.x, value.y)
+    }
+}
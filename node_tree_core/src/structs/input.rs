@@ -0,0 +1,62 @@
+//===================================================================================================================================================================================//
+//
+//  /$$$$$$                                 /$$    
+// |_  $$_/                                | $$    
+//   | $$   /$$$$$$$   /$$$$$$  /$$   /$$ /$$$$$$  
+//   | $$  | $$__  $$ /$$__  $$| $$  | $$|_  $$_/  
+//   | $$  | $$  \ $$| $$  \ $$| $$  | $$  | $$    
+//   | $$  | $$  | $$| $$  | $$| $$  | $$  | $$ /$$
+//  /$$$$$$| $$  | $$| $$$$$$$/|  $$$$$$/  |  $$$$/
+// |______/|__/  |__/| $$____/  \______/    \___/  
+//                   | $$                          
+//                   | $$                          
+//                   |__/                          
+//
+//===================================================================================================================================================================================//
+
+//?
+//? Created by LunaticWyrm467 and others.
+//? 
+//? All code is licensed under the MIT license.
+//? Feel free to reproduce, modify, and do whatever.
+//?
+
+//!
+//! Contains the framework-agnostic `InputEvent` type which is dispatched through the tree via
+//! `NodeTreeBase::feed_input()`.
+//!
+//! This crate does not poll any input devices itself. Instead, wire up whichever windowing or
+//! terminal library you are using and translate its events into `InputEvent`s.
+//!
+
+use std::any::Any;
+
+
+/// A single input event which is fed through the tree from the top down.
+/// Each node receives it through its `input()` hook until one of them marks the event as handled
+/// via `NodeTreeBase::set_input_as_handled()`.
+#[derive(Debug)]
+pub enum InputEvent {
+
+    /// A key was either pressed or released.
+    /// The key code is left up to the windowing library being used.
+    Key { code: u32, pressed: bool },
+
+    /// Text was entered.
+    Text(String),
+
+    /// Any other user-defined event.
+    Custom(Box<dyn Any>)
+}
+
+impl InputEvent {
+    
+    /// Attempts to get a reference to the payload of a `Custom` event as the given type.
+    /// Returns `None` if this is not a `Custom` event or if the type does not match.
+    pub fn custom<T: Any>(&self) -> Option<&T> {
+        match self {
+            Self::Custom(payload) => payload.downcast_ref::<T>(),
+            _                     => None
+        }
+    }
+}
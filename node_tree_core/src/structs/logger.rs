@@ -26,13 +26,16 @@
 //! logs.
 //! 
 
+use std::fmt;
+use std::rc::Rc;
 use std::time::SystemTime;
 
 use chrono::{ DateTime, Utc };
 
 
 use super::node_tree_base::NodeIdentity;
-use crate::prelude::{ RID, NodeTreeBase };
+use super::node_path::NodePath;
+use crate::prelude::{ RID, NodeTreeBase, Node };
 use crate::utils::functions::draw_tree;
 
 
@@ -48,7 +51,93 @@ pub enum LoggerVerbosity {
     All,
     NoDebug,
     OnlyIssues,
-    OnlyPanics
+    OnlyPanics,
+
+    /// Decides whether to post each log via a user-defined predicate.
+    /// See `LoggerVerbosity::custom()`.
+    Custom(LogFilter)
+}
+
+impl LoggerVerbosity {
+    
+    /// Creates a custom verbosity level, which only posts the logs that satisfy the given
+    /// predicate.
+    ///
+    /// # Example
+    /// ```rust, ignore
+    /// // Only post debug logs from nodes under `World`, along with every other kind of log.
+    /// let verbosity: LoggerVerbosity = LoggerVerbosity::custom(|record| {
+    ///     record.level != LogLevel::Debug || record.system.to_str().starts_with("Root/World")
+    /// });
+    /// ```
+    pub fn custom(filter: impl Fn(&LogRecord) -> bool + 'static) -> Self {
+        LoggerVerbosity::Custom(LogFilter(Rc::new(filter)))
+    }
+}
+
+/// Wraps a user-defined log filter.
+#[derive(Clone)]
+pub struct LogFilter(Rc<dyn Fn(&LogRecord) -> bool>);
+
+impl fmt::Debug for LogFilter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("LogFilter")
+    }
+}
+
+
+/// The severity of a log, ordered from least to most severe.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LogLevel {
+    Debug,
+    Info,
+    Warn,
+    Panic
+}
+
+/// A structured description of a log, which is passed to custom log filters.
+#[derive(Debug, Clone)]
+pub struct LogRecord {
+
+    /// The severity of the log.
+    pub level: LogLevel,
+
+    /// The system or node that posted the log.
+    pub system: SystemCall,
+
+    /// The log's message.
+    pub message: String
+}
+
+impl LogRecord {
+    
+    /// Gets the record as a `Log`, which is used for formatting.
+    fn as_log(&self) -> Log<'_> {
+        match self.level {
+            LogLevel::Debug => Log::Debug(&self.message),
+            LogLevel::Info  => Log::Info(&self.message),
+            LogLevel::Warn  => Log::Warn(&self.message),
+            LogLevel::Panic => Log::Panic(&self.message)
+        }
+    }
+}
+
+
+/// The number of logs of each level that were posted to a logger.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct LogSummary {
+    pub debug: usize,
+    pub info:  usize,
+    pub warn:  usize,
+    pub panic: usize
+}
+
+impl LogSummary {
+    
+    /// Returns whether no warnings or panics were posted.
+    pub fn is_clean(&self) -> bool {
+        self.warn == 0 && self.panic == 0
+    }
 }
 
 
@@ -100,6 +189,16 @@ impl <'a >Log<'a> {
         }
     }
 
+    /// Gets the level of the Log.
+    pub fn level(&self) -> LogLevel {
+        match self {
+            Log::Debug(_) => LogLevel::Debug,
+            Log::Info(_)  => LogLevel::Info,
+            Log::Warn(_)  => LogLevel::Warn,
+            Log::Panic(_) => LogLevel::Panic
+        }
+    }
+
     /// Gets the message associated to the Log.
     pub fn get_msg(&self) -> &'a str {
         match self {
@@ -146,18 +245,70 @@ impl <'a >Log<'a> {
 }
 
 
+/*
+ * Panic
+ *      Context
+ */
+
+
+/// Describes the state of the tree at the point of a panic, which is passed to the panic handler
+/// set via `NodeTreeBase::set_panic_handler()`.
+#[derive(Debug, Clone)]
+pub struct PanicContext {
+
+    /// The absolute path of the node that caused the panic.
+    /// This is empty if the node is not registered to the tree.
+    pub path: NodePath,
+
+    /// The full type name of the node that caused the panic.
+    /// This is empty if the node is not registered to the tree.
+    pub type_name: String,
+
+    /// The panic message.
+    pub message: String,
+
+    /// The time of the crash.
+    pub time: String,
+
+    /// A visual snapshot of the tree surrounding the node that caused the panic, with the
+    /// offending node highlighted.
+    /// This is empty if the node is not registered to the tree.
+    pub tree: String
+}
+
+/// Wraps a user-defined panic handler.
+#[derive(Clone)]
+pub struct PanicHandler(Rc<dyn Fn(&PanicContext)>);
+
+impl fmt::Debug for PanicHandler {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("PanicHandler")
+    }
+}
+
+
 /*
  * Logger
  *      Struct
  */
 
 
+/// A log which was posted, kept so that the log may be exported after the fact.
+#[derive(Debug, Clone)]
+struct LogEntry {
+    time:   String,
+    record: LogRecord,
+    report: Option<String>  // The crash report that followed the log, if it was a panic.
+}
+
 #[derive(Debug, Clone)]
 pub struct Logger {
-    log:          String,
-    verbosity_lv: LoggerVerbosity,
-    crash_header: String,
-    crash_footer: String
+    log:           String,
+    entries:       Vec<LogEntry>,
+    verbosity_lv:  LoggerVerbosity,
+    crash_header:  String,
+    crash_footer:  String,
+    panic_handler: Option<PanicHandler>
 }
 
 impl Logger {
@@ -165,10 +316,12 @@ impl Logger {
     /// Creates a new Logger instance.
     pub fn new(verbosity_lv: LoggerVerbosity) -> Self {
         let mut logger: Logger = Logger {
-            log:          String::new(),
+            log:           String::new(),
+            entries:       Vec::new(),
             verbosity_lv,
-            crash_header: "Unfortunately the program has crashed. Please contact the development team with the following crash report as well as the attachment of the log posted during the time of the crash.".to_string(),
-            crash_footer: "Goodbye World! (Program Exited)".to_string()
+            crash_header:  "Unfortunately the program has crashed. Please contact the development team with the following crash report as well as the attachment of the log posted during the time of the crash.".to_string(),
+            crash_footer:  "Goodbye World! (Program Exited)".to_string(),
+            panic_handler: None
         };
         
         logger.post_manual(SystemCall::Named("SysLogger".to_string()), Log::Debug("System logger has initialized. Hello World!"));
@@ -185,44 +338,68 @@ impl Logger {
         self.crash_footer = msg.to_string();
     }
 
+    /// Replaces the default crash report that is printed upon a panic with a custom handler.
+    pub fn set_panic_handler(&mut self, handler: impl Fn(&PanicContext) + 'static) {
+        self.panic_handler = Some(PanicHandler(Rc::new(handler)));
+    }
+
     /// Posts a new message to the log using the `NodeTreeBase` as a reference.
     /// This will return whether the NodeTree should quit or not.
+    ///
+    /// # Note
+    /// Logs posted on behalf of an RID that is not registered to the tree are attributed to an
+    /// `Unregistered Node` system rather than a node path.
+    ///
     /// # Safety
     /// This is marked unsafe because there is no way to validate that the passed in pointer to the
     /// NodeTree is valid.
     pub unsafe fn post(&mut self, calling: RID, log: Log, node_tree: *mut NodeTreeBase) -> bool {
-        match &self.verbosity_lv {
-            LoggerVerbosity::All        => {},
-            LoggerVerbosity::NoDebug    => if log.is_debug()        { return false; },
-            LoggerVerbosity::OnlyIssues => if !log.is_problematic() { return false; },
-            LoggerVerbosity::OnlyPanics => if !log.is_panic()       { return false; }
-        }
-        
         let node_tree: &NodeTreeBase = &*node_tree;
         let system:    SystemCall    = {
             match node_tree.get_node_identity(calling) {
-                Some(NodeIdentity::NodePath)         => SystemCall::NodePath(unsafe { node_tree.get_node(calling).unwrap_unchecked() }.get_absolute_path().to_string()),
+                Some(NodeIdentity::NodePath)         => {
+                    let node: &dyn Node = unsafe { node_tree.get_node(calling).unwrap_unchecked() };
+                    SystemCall::NodePath(node.log_identity().unwrap_or_else(|| node.get_absolute_path().to_string()))
+                },
                 Some(NodeIdentity::UniqueName(name)) => SystemCall::Named(name),
-                None                                 => unimplemented!()
+                None                                 => SystemCall::Named(format!("Unregistered Node (RID {calling})"))
             }
         };
+        
+        if !self.permits(&system, &log) {
+            return false;
+        }
 
-        let colour: String = log.get_colour();
-        let panic:  bool   = log.is_panic();
-        let time:   String = self.post_manual(system, log);
+        let colour:  String = log.get_colour();
+        let panic:   bool   = log.is_panic();
+        let message: String = log.get_msg().to_string();
+        let time:    String = self.write(system, log);
 
         if panic {
-            let node_tree_visual: String = draw_tree(node_tree, calling, 6, 6);
-            println!("
+            let node:             Option<&dyn Node> = node_tree.get_node(calling);
+            let node_tree_visual: String            = node.map(|_| draw_tree(node_tree, calling, 6, 6)).unwrap_or_default();
+            match &self.panic_handler {
+                Some(PanicHandler(handler)) => {
+                    let context: PanicContext = PanicContext {
+                        path:      node.map(|node| NodePath::from_str(&("/".to_string() + &node.get_absolute_path().to_string()))).unwrap_or_default(),
+                        type_name: node.map(|node| node.name_as_type()).unwrap_or_default(),
+                        message,
+                        time:      time.clone(),
+                        tree:      node_tree_visual.clone()
+                    };
+                    handler(&context);
+                },
+                None => println!("
 {}{}
 
 \u{001b}[0m{}{}
 Time of Crash: {}
 Exit Code: {}
 
-{}\u{001b}[0m", colour, self.crash_header, node_tree_visual, colour, time, 1, self.crash_footer);
+{}\u{001b}[0m", colour, self.crash_header, node_tree_visual, colour, time, 1, self.crash_footer)
+            }
             
-            self.log += &format!("
+            let report: String = format!("
 {}
 
 {}
@@ -230,22 +407,45 @@ Time of Crash: {}
 Exit Code: {}
 
 {}", self.crash_header, node_tree_visual, time, 1, self.crash_footer);
+            
+            self.log += &report;
+            if let Some(entry) = self.entries.last_mut() {
+                entry.report = Some(report);
+            }
         }
         
         panic
     }
 
+    /// Determines whether a log from the given system should be posted under the logger's
+    /// verbosity level.
+    fn permits(&self, system: &SystemCall, log: &Log) -> bool {
+        match &self.verbosity_lv {
+            LoggerVerbosity::All                  => true,
+            LoggerVerbosity::NoDebug              => !log.is_debug(),
+            LoggerVerbosity::OnlyIssues           => log.is_problematic(),
+            LoggerVerbosity::OnlyPanics           => log.is_panic(),
+            LoggerVerbosity::Custom(LogFilter(f)) => f(&LogRecord {
+                level:   log.level(),
+                system:  system.clone(),
+                message: log.get_msg().to_string()
+            })
+        }
+    }
+
     /// Posts a new message to the log, without printing a crash report if there is an Error.
     /// Returns the time of the posted message
     pub fn post_manual(&mut self, system: SystemCall, log: Log) -> String {
-        let time: String = DateTime::<Utc>::from(SystemTime::now()).format("%d/%m/%Y %T").to_string();
-        match &self.verbosity_lv {
-            LoggerVerbosity::All        => {},
-            LoggerVerbosity::NoDebug    => if log.is_debug()        { return time; },
-            LoggerVerbosity::OnlyIssues => if !log.is_problematic() { return time; },
-            LoggerVerbosity::OnlyPanics => if !log.is_panic()       { return time; }
+        if !self.permits(&system, &log) {
+            return DateTime::<Utc>::from(SystemTime::now()).format("%d/%m/%Y %T").to_string();
         }
-        
+        self.write(system, log)
+    }
+
+    /// Writes a message to the log without checking it against the logger's verbosity level.
+    /// Returns the time of the written message.
+    fn write(&mut self, system: SystemCall, log: Log) -> String {
+        let time: String = DateTime::<Utc>::from(SystemTime::now()).format("%d/%m/%Y %T").to_string();
         println!(
             "{}<{} UTC> | {} | {} | {}\u{001b}[0m",
             log.get_colour(),
@@ -262,6 +462,16 @@ Exit Code: {}
             log.get_lv(),
             log.get_msg()
         );
+        
+        self.entries.push(LogEntry {
+            time:   time.clone(),
+            record: LogRecord {
+                level:   log.level(),
+                system,
+                message: log.get_msg().to_string()
+            },
+            report: None
+        });
 
         time
     }
@@ -270,6 +480,45 @@ Exit Code: {}
     pub fn to_str(&self) -> &str {
         &self.log
     }
+
+    /// Counts the logs that were posted by their level.
+    /// Logs which were filtered out by the logger's verbosity are not counted.
+    pub fn summary(&self) -> LogSummary {
+        let mut summary: LogSummary = LogSummary::default();
+        for entry in &self.entries {
+            match entry.record.level {
+                LogLevel::Debug => summary.debug += 1,
+                LogLevel::Info  => summary.info  += 1,
+                LogLevel::Warn  => summary.warn  += 1,
+                LogLevel::Panic => summary.panic += 1
+            }
+        }
+        summary
+    }
+
+    /// Formats every posted log at or above the given level, in the same format as `to_str()`.
+    /// If `colour` is set, each log is wrapped in the same ANSI colour codes that are used when
+    /// printing to the console.
+    pub fn export(&self, min_level: LogLevel, colour: bool) -> String {
+        let mut buffer: String = String::new();
+        for entry in self.entries.iter().filter(|entry| entry.record.level >= min_level) {
+            let log: Log = entry.record.as_log();
+            if colour {
+                buffer += &log.get_colour();
+            }
+            
+            buffer += &format!("<{} UTC> | {} | {} | {}", entry.time, entry.record.system.format(), log.get_lv(), log.get_msg());
+            if colour {
+                buffer += "\u{001b}[0m";
+            }
+            buffer += "\n";
+
+            if let Some(report) = &entry.report {
+                buffer += report;
+            }
+        }
+        buffer
+    }
 }
 
 
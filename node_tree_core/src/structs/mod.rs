@@ -3,9 +3,13 @@ pub mod node_base;
 pub mod node_path;
 pub mod node_tree_base;
 pub mod node_scene;
+pub mod placeholder;
 pub mod tree_pointer;
 pub mod tree_option;
 pub mod tree_result;
 pub mod signals;
 pub mod rid;
 pub mod logger;
+pub mod input;
+pub mod rng;
+pub mod geometry;
@@ -25,19 +25,24 @@
 //! Every `Node` type must contain a `base: Rc<NodeBase>` field for this reason.
 //!
 
-use std::{ rc::Rc, sync::Mutex };
+use std::{ fmt, rc::Rc, sync::Mutex, cell::{ Cell, RefCell }, collections::{ HashMap, HashSet }, path::Path, any::{ Any, TypeId } };
 
 use super::{
     logger::Log,
     node_path::{ PathSeg, NodePath },
     node_scene::NodeScene,
-    node_tree_base::{ NodeTreeBase, TerminationReason },
+    placeholder::Placeholder,
+    geometry::Rect,
+    rng::{ self, NodeRng },
+    node_tree_base::{ NodeTreeBase, TerminationReason, TreeStatus, ProcessMode, CancelHandle },
     tree_pointer::{ Tp, TpDyn },
+    tree_option::TreeOption,
     tree_result::TreeResult,
     rid::RID
 };
 
-use crate::traits::{ node::Node, node_tree::NodeTree, node_getter::NodeGetter, instanceable::Instanceable };
+use crate::services::connection_registry::{ self, PersistentConnection };
+use crate::traits::{ node::Node, node_tree::NodeTree, node_getter::NodeGetter, instanceable::Instanceable, bounded };
 use crate::utils::functions::ensure_unique_name;
 
 
@@ -48,12 +53,50 @@ pub enum NodeStatus {
     JustPanicked(String)
 }
 
+/// A recoverable error which can be returned from a node's `try_ready()`, `try_process()` or
+/// `try_terminal()` hooks.
+/// The `NodeTree` logs the error on behalf of the node that returned it, and then either continues
+/// or terminates depending on its `NodeErrorPolicy`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NodeError {
+    
+    /// A node that was required could not be found.
+    MissingNode(String),
+
+    /// The node was in a state that it could not recover from on its own.
+    InvalidState(String),
+
+    /// Any other kind of error.
+    Custom(String)
+}
+
+impl NodeError {
+    
+    /// Creates a custom error from any displayable value.
+    pub fn custom(msg: impl fmt::Display) -> Self {
+        NodeError::Custom(msg.to_string())
+    }
+}
+
+impl fmt::Display for NodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NodeError::MissingNode(path) => write!(f, "Missing node: {path}"),
+            NodeError::InvalidState(msg) => write!(f, "Invalid state: {msg}"),
+            NodeError::Custom(msg)       => write!(f, "{msg}")
+        }
+    }
+}
+
+impl std::error::Error for NodeError {}
+
 /// Holds all of the node's internal information such as its name, children, parent, owner, and
 /// owning `NodeTree`.
 /// Also allows for the modification of the node's internal state.
 ///
 /// # Note
-/// Cloning this will result in a new `NodeBase` with the same name.
+/// Cloning this will result in a new `NodeBase` with the same name, groups, process mode
+/// override, auto-free policy, and persistent connections. Attached data is not cloned.
 pub struct NodeBase {
     name:     String,
     rid:      RID,
@@ -63,7 +106,18 @@ pub struct NodeBase {
     children: Vec<RID>,
     status:   Rc<Mutex<NodeStatus>>,
     loaded:   bool,
-    depth:    usize   // How far the Node is within the tree.
+    depth:    usize,  // How far the Node is within the tree.
+    groups:   Vec<String>,
+    mode:     Option<ProcessMode>,  // Overrides the node's `process_mode()` if set.
+    disabled: bool,
+    unscaled: bool,
+    orphaned: bool,  // Whether this node is freed once nothing references it.
+    rng:      Cell<Option<u64>>,
+    cache:    RefCell<HashMap<NodePath, (RID, u64)>>,  // Resolved paths along with the tree's structure version at the time.
+    on_exit:  RefCell<Vec<Box<dyn FnOnce()>>>,
+    renaming: Option<CancelHandle>,  // The pending rename scheduled via `set_name_deferred()`, if any.
+    data:     HashMap<TypeId, Box<dyn Any>>,
+    links:    Vec<PersistentConnection>
 }
 
 impl NodeBase {
@@ -79,7 +133,18 @@ impl NodeBase {
             children: Vec::new(),
             status:   Rc::new(Mutex::new(NodeStatus::Normal)),
             loaded:   false,
-            depth:    0
+            depth:    0,
+            groups:   Vec::new(),
+            mode:     None,
+            disabled: false,
+            unscaled: false,
+            orphaned: false,
+            rng:      Cell::new(None),
+            cache:    RefCell::new(HashMap::new()),
+            on_exit:  RefCell::new(Vec::new()),
+            renaming: None,
+            data:     HashMap::new(),
+            links:    Vec::new()
         }
     }
     
@@ -122,10 +187,15 @@ impl NodeBase {
 
     /// Sets the name of the node.
     /// If the name is not unique among the node's siblings, then it will be made into a unique name.
+    ///
+    /// If the node is a part of a `NodeTree`, then any observers registered via
+    /// `NodeTreeBase::on_node_renamed()` are notified of the rename.
     pub fn set_name(&mut self, name: &str) {
+        let old_path: Option<NodePath> = self.tree().map(|_| self.get_absolute_path_rooted());
+        
         if let (Some(parent), Some(tree)) = (self.parent, self.tree()) {
-            let     parent:    &dyn Node    = unsafe { tree.get_node(parent).unwrap_unchecked() };
-            let     siblings:  &[String]    = &parent.children().iter().map(|a| a.name().to_string()).collect::<Vec<_>>();
+            let     parent:    &dyn Node        = unsafe { tree.get_node(parent).unwrap_unchecked() };
+            let     siblings:  &HashSet<String> = &parent.children().iter().map(|a| a.name().to_string()).collect();
 
             unsafe {
                 self.set_name_unchecked(&ensure_unique_name(name, siblings));
@@ -135,6 +205,49 @@ impl NodeBase {
                 self.set_name_unchecked(name);
             }
         }
+        
+        if let Some(old_path) = old_path {
+            let new_path: NodePath = self.get_absolute_path_rooted();
+            if old_path != new_path {
+                let rid: RID = self.rid;
+                unsafe { self.tree_mut().unwrap_unchecked() }.notify_renamed(rid, old_path, new_path);
+            }
+        }
+    }
+
+    /// Sets the name of the node like `set_name()`, but defers the rename until the end of the
+    /// current frame, after every node has been processed. This avoids surprising any logic that
+    /// looked this node up by name earlier in the frame.
+    /// The rename is scheduled via `NodeTreeBase::call_deferred()`, so it runs in order with any
+    /// other deferred callbacks and before any queued frees.
+    ///
+    /// # Note
+    /// The name is still made unique among the node's siblings once the rename is applied, and any
+    /// observers registered via `NodeTreeBase::on_node_renamed()` are notified then.
+    /// Only the last deferred rename within a frame is applied, and if this node leaves the tree
+    /// before the end of the frame, then the rename is canceled.
+    ///
+    /// # Panics
+    /// Panics if this Node is not connected to a `NodeTree`.
+    pub fn set_name_deferred(&mut self, name: &str) {
+        if self.tree.is_none() {
+            panic!("Cannot defer the renaming of a node that is not a part of a NodeTree!");
+        }
+
+        // Only a single rename is kept pending, so that renaming a node every frame does not
+        // accumulate anything on the node.
+        if let Some(pending) = self.renaming.take() {
+            pending.cancel();
+        }
+
+        let rid:    RID          = self.rid;
+        let name:   String       = name.to_string();
+        let handle: CancelHandle = unsafe { self.tree_mut().unwrap_unchecked() }.call_deferred(move |tree| {
+            if let Some(node) = tree.get_node_mut(rid) {
+                node.set_name(&name);
+            }
+        });
+        self.renaming = Some(handle);
     }
 
     /// Registers this node as a singleton.
@@ -150,27 +263,180 @@ impl NodeBase {
         }
     }
 
+    /// Gets a `Tp<T>` pointer to the singleton registered under the given name.
+    /// Returns `None` if there is no such singleton, or if it is not of the type `T`.
+    ///
+    /// # Panics
+    /// Panics if this Node is not connected to a `NodeTree`.
+    pub fn singleton<T: Node>(&self, name: &str) -> TreeOption<'_, Tp<'_, T>> {
+        let tree: *mut dyn NodeTree = match self.tree {
+            Some(tree) => tree,
+            None       => panic!("Cannot get a singleton from a node that is not a part of a NodeTree!")
+        };
+
+        unsafe {
+            match (*tree).get_node_rid(name.to_string(), None) {
+                Some(rid) => Tp::new(tree, self.rid, rid).ok(),
+                None      => TreeOption::new(tree, self.rid, None)
+            }
+        }
+    }
+
+    /// Gets a `Tp<T>` pointer to the singleton registered under the given name.
+    /// See `singleton()` for a non-panicking version.
+    ///
+    /// # Panics
+    /// Panics if this Node is not connected to a `NodeTree`, if there is no such singleton, or if
+    /// it is not of the type `T`.
+    pub fn expect_singleton<T: Node>(&self, name: &str) -> Tp<'_, T> {
+        self.singleton(name).expect(&format!("Expected a singleton named \"{}\" of type `{}`", name, std::any::type_name::<T>()))
+    }
+
+    /// Adds this node to the given group.
+    /// Groups are simple tags which allow for nodes to be operated on collectively, such as via
+    /// `NodeTreeBase::free_group()`.
+    pub fn add_to_group(&mut self, group: &str) {
+        if !self.is_in_group(group) {
+            self.groups.push(group.to_string());
+        }
+    }
+
+    /// Removes this node from the given group.
+    /// Returns whether the node was a part of the group.
+    pub fn remove_from_group(&mut self, group: &str) -> bool {
+        match self.groups.iter().position(|g| g == group) {
+            Some(idx) => {
+                self.groups.remove(idx);
+                true
+            },
+            None => false
+        }
+    }
+
+    /// Returns whether this node is a part of the given group.
+    pub fn is_in_group(&self, group: &str) -> bool {
+        self.groups.iter().any(|g| g == group)
+    }
+
+    /// Gets the groups that this node is a part of, in the order that they were added.
+    pub fn groups(&self) -> &[String] {
+        &self.groups
+    }
+
+    /// Overrides the process mode returned by this node's `process_mode()` function at runtime.
+    /// Passing `None` removes the override.
+    /// Unlike the process mode returned by the node's type, this is saved along with the node.
+    pub fn set_process_mode_override(&mut self, mode: Option<ProcessMode>) {
+        self.mode = mode;
+        
+        let rid: RID = self.rid;
+        if let Some(tree) = self.tree_mut() {
+            tree.invalidate_process_mode(rid);
+        }
+    }
+
+    /// Gets the process mode override set via `set_process_mode_override()`, if any.
+    pub fn process_mode_override(&self) -> Option<ProcessMode> {
+        self.mode
+    }
+
     /// Adds a child to the node, automatically renaming it if its name is not unique in the
     /// node's children vector.
     ///
     /// # Note
-    /// `_ready()` will automatically be propogated through the added child node.
+    /// The whole child scene is attached before any `ready()` functions are called, which are then
+    /// called from the bottom up. See `NodeTreeBase::build_scene()` for the exact ordering.
     ///
     /// # Panics
     /// Panics if this Node is not connected to a `NodeTree`.
     pub fn add_child<I: Instanceable>(&mut self, child: I) {
-        child.iterate(|parent, node, is_owner| {
-            if let Some(parent) = parent {
-                unsafe {
-                    let parent: &mut dyn Node = &mut *parent;
-                    parent.add_child_from_ptr(node, is_owner, false);
-                }
-            } else {
-                unsafe {
-                    self.add_child_from_ptr(node, is_owner, false);
-                }
-            }
-        });
+        if self.tree.is_none() {
+            panic!("Cannot add a child to a node that is not in a `NodeTree`!");
+        }
+
+        let rid: RID = self.rid;
+        unsafe {
+            self.tree_mut().unwrap_unchecked().build_scene_under(rid, child);
+        }
+    }
+
+    /// Adds a child to the node like `add_child()`, but defers the `ready()` functions of the child
+    /// scene until the end of the current frame.
+    /// Returns a `TpDyn` pointer to the new child, which may be used to configure it before it is
+    /// ready.
+    ///
+    /// # Note
+    /// The child scene is not processed until it is ready. If this is called outside of a frame,
+    /// then the child is readied at the end of the next frame.
+    ///
+    /// # Panics
+    /// Panics if this Node is not connected to a `NodeTree`.
+    pub fn add_child_deferred_ready<I: Instanceable>(&mut self, child: I) -> TreeResult<'_, TpDyn<'_>> {
+        if self.tree.is_none() {
+            panic!("Cannot add a child to a node that is not in a `NodeTree`!");
+        }
+
+        let rid:       RID = self.rid;
+        let child_rid: RID = unsafe { self.tree_mut().unwrap_unchecked() }.build_scene_under_deferred(rid, child);
+        unsafe {
+            TpDyn::new(self.tree.unwrap_unchecked(), rid, child_rid)
+        }
+    }
+
+    /// Adds a node to this node's parent, placing it immediately after this node in the parent's
+    /// children.
+    /// Returns a `TpDyn` pointer to the new sibling, or `Err` if this node has no parent.
+    ///
+    /// # Note
+    /// The sibling is moved into place before any `ready()` functions are called.
+    ///
+    /// # Panics
+    /// Panics if this Node is not connected to a `NodeTree`.
+    pub fn add_sibling<I: Instanceable>(&mut self, sibling: I) -> TreeResult<'_, TpDyn<'_>> {
+        self.add_sibling_at(sibling, 1)
+    }
+
+    /// Adds a node to this node's parent, placing it immediately before this node in the parent's
+    /// children.
+    /// Returns a `TpDyn` pointer to the new sibling, or `Err` if this node has no parent.
+    ///
+    /// # Note
+    /// The sibling is moved into place before any `ready()` functions are called.
+    ///
+    /// # Panics
+    /// Panics if this Node is not connected to a `NodeTree`.
+    pub fn add_sibling_before<I: Instanceable>(&mut self, sibling: I) -> TreeResult<'_, TpDyn<'_>> {
+        self.add_sibling_at(sibling, 0)
+    }
+
+    /// Adds a sibling to this node, placing it at the given offset from this node's index in its
+    /// parent's children.
+    fn add_sibling_at<I: Instanceable>(&mut self, sibling: I, offset: usize) -> TreeResult<'_, TpDyn<'_>> {
+        if self.tree.is_none() {
+            panic!("Cannot add a sibling to a node that is not in a `NodeTree`!");
+        }
+
+        let tree_raw: *mut dyn NodeTree = unsafe { self.tree.unwrap_unchecked() };
+        let Some(parent_rid) = self.parent else {
+            return unsafe { TreeResult::new(tree_raw, self.rid, Err("Cannot add a sibling to a node with no parent".to_string())) };
+        };
+        
+        let rid:  RID               = self.rid;
+        let tree: &mut NodeTreeBase = unsafe { self.tree_mut().unwrap_unchecked() };
+        
+        // Wire in the sibling, move it next to this node, and only then ready it.
+        let history:     Vec<RID>      = tree.enter_tree_phase(parent_rid, sibling);
+        let sibling_rid: RID           = history[0];
+        let parent:      &mut dyn Node = unsafe { tree.get_node_mut(parent_rid).unwrap_unchecked() };
+        
+        parent.children.retain(|&child| child != sibling_rid);
+        let idx: usize = parent.children.iter().position(|&child| child == rid).unwrap_or(parent.children.len() - 1) + offset;
+        parent.children.insert(idx, sibling_rid);
+        
+        tree.ready_phase(&history);
+        unsafe {
+            TpDyn::new(tree_raw, rid, sibling_rid)
+        }
     }
 
     /// Adds a child to the node via a passed in pointer, automatically renaming it if its
@@ -185,6 +451,8 @@ impl NodeBase {
     ///
     /// # Note
     /// `_ready()` will automatically be propogated through the added child node.
+    /// `child_added()` is not called, as the child's scene may not be fully attached yet; callers
+    /// are expected to go through `NodeTreeBase::notify_children_added()` once it is.
     ///
     /// # Safety
     /// Cannot guarantee that the raw pointer that is passed in is valid.
@@ -197,9 +465,77 @@ impl NodeBase {
         }
 
         // Ensure that the child's name within the context of this node's children is unique.
-        let names_of_children: &[String] = &self.children().iter().map(|c| c.name().to_string()).collect::<Vec<_>>();
-        let child_name:        &str      = unsafe { &*child_ptr }.name();
-        let unique_name:       String    = ensure_unique_name(child_name, names_of_children);
+        let names_of_children: &HashSet<String> = &self.children().iter().map(|c| c.name().to_string()).collect();
+        let child_name:        &str             = unsafe { &*child_ptr }.name();
+        let unique_name:       String           = ensure_unique_name(child_name, names_of_children);
+        let child_rid:         RID              = self.attach_child(child_ptr, owner_is_self, &unique_name);
+        
+        // Call the `ready()` function for the child as long as the call to ready() is not ignored
+        // or circumvented..
+        if !ignore_ready {
+            unsafe {
+                let child: &mut dyn Node = self.tree_mut().unwrap_unchecked().get_node_mut(child_rid).unwrap_unchecked();
+                if child.has_just_loaded() {
+                    child.loaded();
+                    child.mark_as_final();
+                }
+                
+                let result: Result<(), NodeError> = child.try_ready();
+                self.tree_mut().unwrap_unchecked().report_node_error(child_rid, result);
+            }
+        }
+        child_rid
+    }
+
+    /// Adds a batch of children to this node.
+    /// Unlike calling `add_child()` repeatedly, the whole batch is attached before any `ready()`
+    /// functions are called, so every node in the batch can see its siblings from within
+    /// `ready()`. The `ready()` functions are then called from the bottom up.
+    ///
+    /// # Panics
+    /// Panics if this Node is not connected to a `NodeTree`.
+    pub fn add_children<I: Instanceable>(&mut self, children: Vec<I>) {
+        if self.tree.is_none() {
+            panic!("Cannot add a child to a node that is not in a `NodeTree`!");
+        }
+
+        // Compute the unique names of the batch against a single set of sibling names.
+        let mut names_of_children: HashSet<String> = self.children().iter().map(|c| c.name().to_string()).collect();
+        let mut attached:          Vec<RID>        = Vec::new();
+
+        for child in children {
+            child.iterate(|parent, node, is_owner| {
+                if let Some(parent) = parent {
+                    unsafe {
+                        let parent: &mut dyn Node = &mut *parent;
+                        attached.push(parent.add_child_from_ptr(node, is_owner, true));
+                    }
+                } else {
+                    let unique_name: String = ensure_unique_name(unsafe { &*node }.name(), &names_of_children);
+                    unsafe {
+                        attached.push(self.attach_child(node, is_owner, &unique_name));
+                    }
+                    names_of_children.insert(unique_name);
+                }
+            });
+        }
+
+        // Notify the parents of the batch once it is fully attached, then go through the batch
+        // backwards and run each node's `ready()` function.
+        unsafe {
+            let tree: &mut NodeTreeBase = self.tree_mut().unwrap_unchecked();
+            tree.notify_children_added(&attached);
+            tree.ready_phase(&attached);
+        }
+    }
+
+    /// Attaches a child to this node under the given name, registering it to the tree without
+    /// calling any of its hooks.
+    ///
+    /// # Safety
+    /// Cannot guarantee that the raw pointer that is passed in is valid, and assumes that this
+    /// node is connected to a `NodeTree`.
+    unsafe fn attach_child(&mut self, child_ptr: *mut dyn Node, owner_is_self: bool, unique_name: &str) -> RID {
 
         // Add the child to this node's children and connect it to its parent and owner nodes,
         // as well as the root tree structure's reference.
@@ -213,7 +549,7 @@ impl NodeBase {
             let rid:   RID           = tree.register_node(child_ptr);
             let child: &mut dyn Node = tree.get_node_mut(rid).unwrap_unchecked();
 
-            child.set_name_unchecked(&unique_name);
+            child.set_name_unchecked(unique_name);
             child.set_parent(parent_rid);
             child.set_owner(if owner_is_self { rid } else { owner_rid });
             child.set_tree(tree_raw);
@@ -224,20 +560,6 @@ impl NodeBase {
         };
         self.children.push(child_rid);
         
-        // Call the `ready()` function for the child as long as the call to ready() is not ignored
-        // or circumvented..
-        if !ignore_ready {
-            unsafe {
-                let child: &mut dyn Node = self.tree_mut().unwrap_unchecked().get_node_mut(child_rid).unwrap_unchecked();
-                if child.has_just_loaded() {
-                    child.loaded();
-                    child.mark_as_final();
-                }
-                child.ready();
-
-            }
-        }
-        
         // Print the debug information on the child to the console.
         let child: &dyn Node = unsafe { self.tree().unwrap_unchecked().get_node(child_rid).unwrap_unchecked() };
         self.post(Log::Debug(&format!("Node \"{}\" added to the scene as the child of \"{}\"! Unique ID of \"{}\" generated!", child.name(), self.name(), child.rid)));
@@ -285,23 +607,149 @@ impl NodeBase {
             )).unwrap_unchecked()
         };
 
+        // Call every terminal function before anything is disconnected, so that each removed
+        // node may still see the rest of the removed branch.
+        for &queued_rid in &connected { unsafe {
+            let queued_node: &mut dyn Node = self.tree_mut().unwrap_unchecked().get_node_mut(queued_rid).unwrap_unchecked();
+            
+            let result: Result<(), NodeError> = queued_node.try_terminal(TerminationReason::RemovedAsChild);
+            self.tree_mut().unwrap_unchecked().report_node_error(queued_rid, result);
+        }}
+        for &queued_rid in &connected { unsafe {
+            self.tree().unwrap_unchecked().get_node(queued_rid).unwrap_unchecked().exit_tree();
+        }}
+
+        // Let this node react to the child's removal while it is still attached.
+        let rid: RID = self.rid;
+        unsafe {
+            self.tree_mut().unwrap_unchecked().get_node_mut(rid).unwrap_unchecked().child_removed(connected[0]);
+        }
+
         self.children.remove(child_idx);
-        for (idx, queued_rid) in connected.into_iter().enumerate() { unsafe { 
+        for (idx, &queued_rid) in connected.iter().enumerate() { unsafe { 
             let _is_root_child: bool          = idx == 0; // TODO: Use this to save children nodes!
             let queued_node:    &mut dyn Node = self.tree_mut().unwrap_unchecked().get_node_mut(queued_rid).unwrap_unchecked();
             
-            queued_node.terminal(TerminationReason::RemovedAsChild);
             queued_node.disconnnect_parent();
             queued_node.disconnnect_owner();
             queued_node.disconnnect_tree();
-
-            self.tree_mut().unwrap_unchecked().unregister_node(queued_rid);
         }}
 
+        // A child that is freed once orphaned is kept alive for as long as it has any handles.
+        let auto_free: bool              = unsafe { self.tree().unwrap_unchecked().get_node(connected[0]).unwrap_unchecked() }.auto_free_when_orphaned();
+        let tree:      &mut NodeTreeBase = unsafe { self.tree_mut().unwrap_unchecked() };
+        if !auto_free || !tree.adopt_orphan(connected.clone()) {
+            for queued_rid in connected {
+                unsafe {
+                    tree.unregister_node(queued_rid);
+                }
+            }
+        }
+
         self.post(Log::Debug(&format!("Removed child node \"{}\" from parent node \"{}\"!", child_name, self.name())));
         true 
     }
 
+    /// Swaps the positions of two children given their names.
+    /// This affects the order in which the children are processed.
+    /// Returns false if either of the children could not be found.
+    ///
+    /// # Panics
+    /// Panics if this Node is not connected to a `NodeTree`.
+    pub fn swap_children(&mut self, a: &str, b: &str) -> bool {
+        if self.tree.is_none() {
+            panic!("Cannot swap the children of a node that is not in a `NodeTree`!");
+        }
+
+        let children: Vec<TpDyn>    = self.children();
+        let a_idx:    Option<usize> = children.iter().position(|c| c.name() == a);
+        let b_idx:    Option<usize> = children.iter().position(|c| c.name() == b);
+        match (a_idx, b_idx) {
+            (Some(a_idx), Some(b_idx)) => {
+                self.children.swap(a_idx, b_idx);
+                true
+            },
+            _ => false
+        }
+    }
+
+    /// Moves the child with the given name to the given index among this node's children.
+    /// This affects the order in which the children are processed.
+    /// Returns false if the child could not be found or if the index is out of range.
+    ///
+    /// # Panics
+    /// Panics if this Node is not connected to a `NodeTree`.
+    pub fn move_child(&mut self, name: &str, to: usize) -> bool {
+        if self.tree.is_none() {
+            panic!("Cannot move the children of a node that is not in a `NodeTree`!");
+        }
+        
+        match self.children().iter().position(|c| c.name() == name) {
+            Some(from) => self.move_child_from(from, to),
+            None       => false
+        }
+    }
+
+    /// Moves this node to the front of its siblings, so that it is processed before them.
+    /// Returns false if this node has no parent.
+    ///
+    /// # Panics
+    /// Panics if this Node is not connected to a `NodeTree`.
+    pub fn move_to_front(&mut self) -> bool {
+        self.move_among_siblings(|_| 0)
+    }
+
+    /// Moves this node to the back of its siblings, so that it is processed after them.
+    /// Returns false if this node has no parent.
+    ///
+    /// # Panics
+    /// Panics if this Node is not connected to a `NodeTree`.
+    pub fn move_to_back(&mut self) -> bool {
+        self.move_among_siblings(|num_siblings| num_siblings - 1)
+    }
+
+    /// Moves this node within its parent's children to the index given by `to`, which is passed
+    /// the number of children that the parent has.
+    fn move_among_siblings(&mut self, to: impl FnOnce(usize) -> usize) -> bool {
+        let rid:  RID               = self.rid;
+        let tree: &mut dyn NodeTree = match self.tree_mut() {
+            Some(tree) => tree,
+            None       => panic!("Cannot move a node that is not in a `NodeTree`!")
+        };
+        let Some(parent) = self.parent.and_then(|parent| tree.get_node_mut(parent)) else {
+            return false;
+        };
+
+        let from: usize = unsafe { parent.children.iter().position(|&child| child == rid).unwrap_unchecked() };
+        let to:   usize = to(parent.children.len());
+        parent.move_child_from(from, to)
+    }
+
+    /// Moves the child at index `from` to index `to`, shifting the children in between.
+    fn move_child_from(&mut self, from: usize, to: usize) -> bool {
+        if to >= self.children.len() {
+            return false;
+        }
+
+        let child: RID = self.children.remove(from);
+        self.children.insert(to, child);
+        true
+    }
+
+    /// Sorts the children of this node by a key extracted from each child.
+    /// This affects the order in which the children are processed, while their paths remain the
+    /// same. The sort is stable, and the key is only extracted once per child.
+    ///
+    /// # Panics
+    /// Panics if this Node is not connected to a `NodeTree`.
+    pub fn sort_children_by<K: Ord>(&mut self, key: impl Fn(&dyn Node) -> K) {
+        let tree: &dyn NodeTree = match self.tree {
+            Some(tree) => unsafe { &*tree },
+            None       => panic!("Cannot sort the children of a node that is not in a `NodeTree`!")
+        };
+        self.children.sort_by_cached_key(|&child| key(unsafe { tree.get_node(child).unwrap_unchecked() }));
+    }
+
     /// Returns a `Tp<T>` pointer to a child at the given index.
     /// If there is no child at the given index, or if the wrong type is given, then `Err` will be returned.
     ///
@@ -344,6 +792,49 @@ impl NodeBase {
         }
     }
 
+    /// Returns a `Tp<T>` pointer to the direct child with the given name.
+    /// Unlike `get_node()`, this does not parse a `NodePath`.
+    /// If there is no child with the given name, or if the wrong type is given, then `Err` will be
+    /// returned.
+    ///
+    /// # Panics
+    /// Panics if this Node is not connected to a `NodeTree`.
+    pub fn get_child_by_name<T: Node>(&self, name: &str) -> TreeResult<'_, Tp<'_, T>> {
+        match self.child_rid_by_name(name) {
+            Ok(child) => unsafe { Tp::new(self.tree.unwrap_unchecked(), self.rid, child) },
+            Err(err)  => unsafe { TreeResult::new(self.tree.unwrap_unchecked(), self.rid, Err(err)) }
+        }
+    }
+
+    /// Returns a `TpDyn` pointer to the direct child with the given name.
+    /// Unlike `get_node_dyn()`, this does not parse a `NodePath`.
+    /// If there is no child with the given name then `Err` will be returned.
+    ///
+    /// # Panics
+    /// Panics if this Node is not connected to a `NodeTree`.
+    pub fn get_child_by_name_dyn(&self, name: &str) -> TreeResult<'_, TpDyn<'_>> {
+        match self.child_rid_by_name(name) {
+            Ok(child) => unsafe { TpDyn::new(self.tree.unwrap_unchecked(), self.rid, child) },
+            Err(err)  => unsafe { TreeResult::new(self.tree.unwrap_unchecked(), self.rid, Err(err)) }
+        }
+    }
+
+    /// Scans this node's children for one with the given name.
+    ///
+    /// # Panics
+    /// Panics if this Node is not connected to a `NodeTree`.
+    fn child_rid_by_name(&self, name: &str) -> Result<RID, String> {
+        let tree: &dyn NodeTree = match self.tree() {
+            Some(tree) => tree,
+            None       => panic!("Cannot get a node from a node that is not a part of a NodeTree!")
+        };
+        
+        self.children.iter()
+            .copied()
+            .find(|&child| tree.get_node(child).is_some_and(|child| child.name() == name))
+            .ok_or_else(|| format!("{:?} has no child named {name:?}", self.name))
+    }
+
     /// Gets a vector of `DynTp` to describe this node's children.
     ///
     /// # Panics
@@ -355,9 +846,29 @@ impl NodeBase {
 
         self.children.iter().map(|&c| unsafe { TpDyn::new(self.tree.unwrap_unchecked(), self.rid, c).unwrap_unchecked() }).collect()
     }
+
+    /// Gets an iterator of `TpDyn` pointers to this node's children, which are only constructed
+    /// as they are iterated over. Unlike `children()`, this does not allocate.
+    ///
+    /// # Panics
+    /// Panics if this Node is not connected to a `NodeTree`.
+    pub fn children_iter(&self) -> impl Iterator<Item = TpDyn<'_>> + '_ {
+        let tree: *mut dyn NodeTree = match self.tree {
+            Some(tree) => tree,
+            None       => panic!("Cannot get children from a node that is not a part of a NodeTree!")
+        };
+        self.children.iter().map(move |&c| unsafe { TpDyn::new(tree, self.rid, c).unwrap_unchecked() })
+    }
+
+    /// Gets the `RID`s of this node's children without allocating any tree pointers.
+    /// This is preferable over `children()` for read-only iteration.
+    pub fn children_rids(&self) -> &[RID] {
+        &self.children
+    }
     
     /// Gets a `Tp<T>` or a Tree Pointer to a given `Node` via either a `NodePath`, a `&str`, or a
-    /// String (the latter two may be used to denote Singletons).
+    /// String (the latter two may be used to denote Singletons), or an already known `RID` or tree
+    /// pointer.
     /// Returns `Err` if the address is invalid or if the referenced `Node` is not of the type
     /// `T`.
     ///
@@ -385,7 +896,8 @@ impl NodeBase {
     }
 
     /// Gets a `TpDyn` or a Dynamic Tree Pointer to a given `Node` via either a `NodePath`, a `&str`, or a
-    /// String (the latter two may be used to denote Singletons).
+    /// String (the latter two may be used to denote Singletons), or an already known `RID` or tree
+    /// pointer.
     /// Returns `Err` if the address is invalid.
     ///
     /// # Usage
@@ -411,6 +923,87 @@ impl NodeBase {
         }
     }
 
+    /// Resolves a `TpDyn` to a node from a string address, automatically detecting which
+    /// addressing style is being used.
+    /// Returns `Err` if the address is invalid.
+    ///
+    /// # Precedence
+    /// The address is interpreted by the first of these rules that applies:
+    /// 1. A leading `/` denotes an absolute path, starting from the root node (`"/Root/A/B"`).
+    /// 2. A leading `~/` denotes a path relative to this node's owner (`"~/A/B"`). A lone `~`
+    ///    refers to the owner itself.
+    /// 3. A bare name without any `/` that matches a registered singleton refers to that
+    ///    singleton (`"Settings"`).
+    /// 4. Anything else is a path relative to this node (`"A/B"`, `"../Sibling"`, or a bare
+    ///    child name that is not shadowed by a singleton).
+    ///
+    /// # Panics
+    /// Panics if this Node is not connected to a `NodeTree`.
+    pub fn resolve(&self, path: &str) -> TreeResult<'_, TpDyn<'_>> {
+        let tree: &dyn NodeTree = match self.tree() {
+            Some(tree) => tree,
+            None       => panic!("Cannot resolve a node from a node that is not a part of a NodeTree!")
+        };
+        
+        let owner_path: Option<&str> = if path == "~" { Some("") } else { path.strip_prefix("~/") };
+        let node_rid:   Option<RID>  = if path.starts_with('/') {
+            tree.get_node_rid(NodePath::from_str(path), Some(self.rid))
+        } else if let Some(owner_path) = owner_path {
+            let owner: RID = self.owner.unwrap_or(self.rid);
+            tree.get_node(owner).and_then(|owner| owner.get_node_raw(NodePath::from_str(owner_path)))
+        } else {
+            let singleton: Option<RID> = if path.contains('/') { None } else { tree.get_node_rid(path.to_string(), None) };
+            singleton.or_else(|| tree.get_node_rid(NodePath::from_str(path), Some(self.rid)))
+        };
+
+        match node_rid {
+            Some(node_rid) => unsafe {
+                TpDyn::new(self.tree.unwrap_unchecked(), self.rid, node_rid)
+            },
+            None => unsafe {
+                TreeResult::new(self.tree.unwrap_unchecked(), self.rid, Err(format!("The address {path:?} could not be resolved")))
+            }
+        }
+    }
+
+    /// Gets a `TpDyn` to a given `Node` via a `NodePath`, caching the result on this node.
+    /// Subsequent lookups of the same path skip walking the tree for as long as the tree's
+    /// structure remains unchanged (see `NodeTreeBase::structure_version()`), which makes this
+    /// well suited for lookups that happen every frame.
+    /// Returns `None` if the path is invalid.
+    ///
+    /// # Note
+    /// Any node being added, removed, or renamed invalidates the cache, after which the path is
+    /// walked again on the next lookup. The result is therefore always the same as `get_node_dyn()`.
+    ///
+    /// # Panics
+    /// Panics if this Node is not connected to a `NodeTree`.
+    pub fn cached_node(&self, path: &NodePath) -> TreeOption<'_, TpDyn<'_>> {
+        let tree:    &dyn NodeTree = match self.tree() {
+            Some(tree) => tree,
+            None       => panic!("Cannot get a node from a node that is not a part of a NodeTree!")
+        };
+        let version: u64           = tree.structure_version();
+
+        let cached:   Option<RID> = self.cache.borrow().get(path).filter(|(_, cached)| *cached == version).map(|(rid, _)| *rid);
+        let node_rid: Option<RID> = match cached {
+            Some(rid) => Some(rid),
+            None      => {
+                let rid: Option<RID> = tree.get_node_rid(path.clone(), Some(self.rid));
+                match rid {
+                    Some(rid) => self.cache.borrow_mut().insert(path.clone(), (rid, version)),
+                    None      => self.cache.borrow_mut().remove(path)
+                };
+                rid
+            }
+        };
+
+        match node_rid {
+            Some(node_rid) => unsafe { TpDyn::new(self.tree.unwrap_unchecked(), self.rid, node_rid).ok() },
+            None           => unsafe { TreeOption::new(self.tree.unwrap_unchecked(), self.rid, None) }
+        }
+    }
+
     /// Gets a node's `RID` given a `NodePath` that is respective to this node as the root.
     ///
     /// # Panics
@@ -484,29 +1077,136 @@ impl NodeBase {
         self.top_down_tail(iter, new_layer)        
     }
 
+    /// Gets a list of all of this node's descendants in the reverse order of `top_down()`,
+    /// meaning that the deepest nodes come first and that every node comes before its parent.
+    /// If 'contains_self' is true, then the list will contain this node as well.
+    ///
+    /// # Note
+    /// This is NOT the order in which nodes are readied. `ready()` is called in the reverse of the
+    /// depth-first order in which a scene is attached, so a node's descendants are all readied
+    /// before it, but siblings are not grouped by depth as they are here.
+    ///
+    /// # Panics
+    /// Panics if this Node is not connected to a `NodeTree`.
+    pub fn bottom_up(&self, contains_self: bool) -> Vec<RID> {
+        let mut iter: Vec<RID> = self.top_down(contains_self);
+        iter.reverse();
+        iter
+    }
+
+    /// Gets every descendant of this node which has no children of its own, in breadth-first
+    /// order.
+    ///
+    /// # Panics
+    /// Panics if this Node is not connected to a `NodeTree`.
+    pub fn leaves(&self) -> Vec<TpDyn<'_>> {
+        if self.tree().is_none() {
+            panic!("Cannot get leaves from a node that is not a part of a NodeTree!");
+        }
+
+        let tree: &NodeTreeBase = unsafe { self.tree().unwrap_unchecked() };
+        tree.iter_bfs_from(self.rid)
+            .skip(1)
+            .filter(|node| node.childless())
+            .map(|node| unsafe { TpDyn::new(self.tree.unwrap_unchecked(), self.rid, node.rid()).unwrap_unchecked() })
+            .collect()
+    }
+
+    /// Counts the descendants of this node which satisfy the given predicate, without collecting
+    /// them like `NodeTreeBase::find_nodes()` would.
+    /// This node itself is never counted.
+    ///
+    /// # Panics
+    /// Panics if this Node is not connected to a `NodeTree`.
+    pub fn count_descendants_where(&self, predicate: impl Fn(&dyn Node) -> bool) -> usize {
+        if self.tree().is_none() {
+            panic!("Cannot count the descendants of a node that is not a part of a NodeTree!");
+        }
+
+        let tree: &NodeTreeBase = unsafe { self.tree().unwrap_unchecked() };
+        tree.iter_bfs_from(self.rid)
+            .skip(1)
+            .filter(|node| predicate(*node))
+            .count()
+    }
+
+    /// Returns whether any descendant of this node satisfies the given predicate, searching in
+    /// breadth-first order.
+    /// The search stops as soon as a match is found, and this node itself is never checked.
+    ///
+    /// # Panics
+    /// Panics if this Node is not connected to a `NodeTree`.
+    pub fn any_descendant(&self, predicate: impl Fn(&dyn Node) -> bool) -> bool {
+        if self.tree().is_none() {
+            panic!("Cannot search the descendants of a node that is not a part of a NodeTree!");
+        }
+
+        let tree: &NodeTreeBase = unsafe { self.tree().unwrap_unchecked() };
+        tree.iter_bfs_from(self.rid)
+            .skip(1)
+            .any(predicate)
+    }
+
+    /// Gets the combined bounds of this node and all of its descendants.
+    /// Only nodes which implement `Bounded` and have been registered via `register_bounded()`
+    /// are included; every other node is skipped, though its children are still visited.
+    /// Returns `None` if no bounded nodes were found.
+    ///
+    /// # Panics
+    /// Panics if this Node is not connected to a `NodeTree`.
+    pub fn subtree_bounds(&self) -> Option<Rect> {
+        if self.tree().is_none() {
+            panic!("Cannot get the bounds of a node that is not a part of a NodeTree!");
+        }
+
+        let tree: &NodeTreeBase = unsafe { self.tree().unwrap_unchecked() };
+        tree.iter_bfs_from(self.rid)
+            .filter_map(|node| bounded::as_bounded(node).map(|node| node.local_bounds()))
+            .reduce(|combined, bounds| combined.union(&bounds))
+    }
+
     /// Gets this Node's absolute `NodePath` to the root of the tree.
     ///
     /// # Panics
     /// Panics if this Node is not connected to a `NodeTree`.
     pub fn get_absolute_path(&self) -> NodePath {
-        let mut path: String = String::new();
-        self.get_absolute_path_tail(&mut path);
-        NodePath::from_str(&path)
+        let mut names: Vec<&str> = Vec::new();
+        self.get_absolute_path_tail(&mut names);
+        names.reverse();
+        NodePath::from_segments(&names)
+    }
+
+    /// Gets the absolute path of this node as an absolute `NodePath`, which can be resolved from
+    /// any node in the tree.
+    ///
+    /// # Panics
+    /// Panics if this Node is not connected to a `NodeTree`.
+    fn get_absolute_path_rooted(&self) -> NodePath {
+        let mut names: Vec<&str> = Vec::new();
+        self.get_absolute_path_tail(&mut names);
+        
+        let mut path: NodePath = NodePath::new_abs();
+        for name in names.into_iter().rev() {
+            path.add_node(name);
+        }
+        path
     }
 
     /// The recursive tail for the `get_absolute_path` function.
+    /// Collects the raw names from this node up to the root, so that names containing slashes are
+    /// kept as single segments.
     ///
     /// # Panics
     /// Panics if this Node is not connected to a `NodeTree`.
-    fn get_absolute_path_tail(&self, path: &mut String) {
+    fn get_absolute_path_tail<'a>(&'a self, names: &mut Vec<&'a str>) {
         if self.tree().is_none() {
             panic!("Cannot get nodes from a node that is not a part of a NodeTree!");
         }
 
-        *path = self.name().to_string() + &(if path.is_empty() { String::new() } else { "/".to_string() + path });
+        names.push(self.name());
         if !self.is_root() {
             unsafe {
-                self.tree().unwrap_unchecked().get_node(self.parent.unwrap_unchecked()).unwrap_unchecked().get_absolute_path_tail(path);
+                self.tree().unwrap_unchecked().get_node(self.parent.unwrap_unchecked()).unwrap_unchecked().get_absolute_path_tail(names);
             }
         }
     }
@@ -550,38 +1250,180 @@ impl NodeBase {
             panic!("Cannot free a node that is not a part of a NodeTree! Instead, simply let the unbound Node drop out of scope or use drop()!");
         }
         
-        // Call the terminal function on this node,
-        // before removing it and all it's children nodes from the NodeTree.
-        for node in self.top_down(true) {
-            let is_self: bool              = node == self.rid;
-            let tree:    &mut NodeTreeBase = unsafe { self.tree_mut().unwrap_unchecked() };
-
+        // Capture everything needed from this node up front, as this node is dropped as soon as
+        // it is unregistered from the tree.
+        let rid:     RID               = self.rid;
+        let parent:  Option<RID>       = self.parent;
+        let is_root: bool              = self.is_root();
+        let tree:    &mut NodeTreeBase = unsafe { self.tree_mut().unwrap_unchecked() };
+
+        // Call the terminal function on this node and all of its children,
+        // before removing any of them from the NodeTree.
+        let nodes: Vec<RID> = self.top_down(true);
+        for &node in &nodes {
+            unsafe {
+                let result: Result<(), NodeError> = tree.get_node_mut(node).unwrap_unchecked().try_terminal(TerminationReason::Freed); // Has to be called externally!
+                tree.report_node_error(node, result);
+            }
+        }
+        for &node in &nodes {
             unsafe {
-                tree.get_node_mut(self.rid).unwrap_unchecked().terminal(TerminationReason::Freed); // Has to be called externally!
+                tree.get_node(node).unwrap_unchecked().exit_tree();
             }
+        }
 
-            // Remove the reference of this node from its parent if it has a parent.
-            if is_self {
-                if let Some(parent) = self.parent {
-                    unsafe {
-                        let rid:       RID           = self.rid;
-                        let parent:    &mut dyn Node = self.tree_mut().unwrap_unchecked().get_node_mut(parent).unwrap_unchecked();
-                        let child_idx: usize         = parent.children.iter().position(|&c_rid| c_rid == rid).unwrap_unchecked();
+        // Remove the reference of this node from its parent if it has a parent.
+        if let Some(parent) = parent {
+            unsafe {
+                let parent: &mut dyn Node = tree.get_node_mut(parent).unwrap_unchecked();
+                parent.child_removed(rid);
+                
+                let child_idx: usize =  parent.children.iter().position(|&c_rid| c_rid == rid).unwrap_unchecked();
 
-                        parent.children.remove(child_idx);
-                    }
-                }
+                parent.children.remove(child_idx);
             }
+        }
 
+        for node in nodes {
             unsafe {
                 tree.unregister_node(node);
             }
         }
 
         // If this is the root node, terminate the NodeTree.
-        if self.is_root() {
-            self.tree_mut().unwrap().terminate();
+        if is_root {
+            tree.terminate();
+        }
+    }
+
+    /// Registers a function to be called once this node leaves the tree, either by being freed or
+    /// by being removed as a child. These are called after every `terminal()` function of the
+    /// removed branch.
+    ///
+    /// # Note
+    /// This is used by `connect!` to disconnect connections that are `owned_by` this node.
+    pub fn on_tree_exit(&self, f: impl FnOnce() + 'static) {
+        self.on_exit.borrow_mut().push(Box::new(f));
+    }
+
+    /// Declares a connection from one of this node's signals to a method of the node at `target`,
+    /// which is relative to this node. Unlike `connect!`, this connection is saved along with the
+    /// node's scene, and is re-established whenever this node enters a tree.
+    /// ```rust,ignore
+    /// register_signal::<Button, ()>("pressed", |button| &button.pressed);
+    /// register_method::<Door, ()>("open", |door, _| door.open());
+    ///
+    /// button.connect_persistent("pressed", NodePath::from_str("../Door"), "open");
+    /// ```
+    ///
+    /// # Note
+    /// The signal and method must be registered via `register_signal()` and `register_method()`.
+    /// The target is resolved each time that the signal is emitted, and a warning is posted if it
+    /// cannot be found.
+    pub fn connect_persistent(&mut self, signal: &str, target: NodePath, method: &str) {
+        let connection: PersistentConnection = PersistentConnection {
+            signal: signal.to_string(),
+            target,
+            method: method.to_string()
+        };
+        
+        if self.tree.is_some() {
+            self.establish_connection(&connection);
+        }
+        self.links.push(connection);
+    }
+
+    /// Gets the persistent connections that were declared via `connect_persistent()`, in the order
+    /// that they were declared.
+    pub fn persistent_connections(&self) -> &[PersistentConnection] {
+        &self.links
+    }
+
+    /// Establishes every persistent connection of this node.
+    /// This is called by the tree whenever this node enters it.
+    pub(crate) fn establish_persistent_connections(&self) {
+        for connection in &self.links {
+            self.establish_connection(connection);
+        }
+    }
+
+    /// Establishes a single persistent connection, posting a warning if it fails.
+    fn establish_connection(&self, connection: &PersistentConnection) {
+        let result: Result<(), String> = unsafe { connection_registry::establish(self.tree.unwrap_unchecked(), self.rid, connection) };
+        if let Err(err) = result {
+            self.post(Log::Warn(&format!("Failed to connect \"{}\" persistently: {}", connection.signal, err)));
+        }
+    }
+
+    /// Calls every function that was registered via `on_tree_exit()`, and cancels any pending
+    /// deferred rename, as this node's `RID` may be handed to another node once it is freed.
+    pub(crate) fn exit_tree(&self) {
+        if let Some(pending) = &self.renaming {
+            pending.cancel();
+        }
+
+        let exits: Vec<Box<dyn FnOnce()>> = self.on_exit.take();
+        for exit in exits {
+            exit();
+        }
+    }
+
+    /// Queues this node to be freed at the end of the current frame, after every node has been
+    /// processed. This is the safe way for a node to free itself or other nodes mid-frame.
+    ///
+    /// # Panics
+    /// Panics if this Node is not connected to a `NodeTree`.
+    pub fn queue_free(&self) {
+        let rid: RID = self.rid;
+        match self.tree_mut() {
+            Some(tree) => tree.queue_free(rid),
+            None       => panic!("Cannot queue the freeing of a node that is not a part of a NodeTree!")
+        }
+    }
+
+    /// Registers this node to have its `dependency_ready()` hook called once the given path
+    /// resolves, which may be on a later frame. This is useful in `ready()` for depending on
+    /// nodes which may not have been added yet, without relying on the order in which siblings
+    /// are added.
+    ///
+    /// # Note
+    /// The tree checks for awaited nodes at the end of every frame, so the hook is called at the
+    /// end of the current frame if the path already resolves.
+    ///
+    /// # Panics
+    /// Panics if this node is not a part of a `NodeTree`.
+    pub fn await_node(&self, path: NodePath) {
+        let rid: RID = self.rid;
+        match self.tree_mut() {
+            Some(tree) => tree.await_node(rid, path),
+            None       => panic!("Cannot await a node from a node that is not a part of a NodeTree!")
+        }
+    }
+
+    /// Expands this node into the scene that it holds if it is a `Placeholder`, replacing it in the
+    /// tree. The expansion happens at the end of the current frame, as this node is removed in
+    /// the process; see `NodeTreeBase::instantiate_placeholder()` to expand it immediately from
+    /// elsewhere.
+    /// Returns false if this node is not a placeholder or has already been expanded.
+    ///
+    /// # Panics
+    /// Panics if this node is not a part of a `NodeTree`.
+    pub fn instantiate_placeholder(&self) -> bool {
+        let rid:  RID               = self.rid;
+        let tree: &mut dyn NodeTree = match self.tree_mut() {
+            Some(tree) => tree,
+            None       => panic!("Cannot instantiate a placeholder that is not a part of a NodeTree!")
+        };
+        
+        let is_dormant: bool = tree.get_node(rid)
+            .and_then(|node| node.as_any().downcast_ref::<Placeholder>())
+            .is_some_and(|placeholder| placeholder.scene().is_some());
+        if is_dormant {
+            tree.call_deferred(move |tree| {
+                tree.instantiate_placeholder(rid);
+            });
         }
+        is_dormant
     }
 
     /// Saves this node and all of the nodes below it as a `NodeScene`, which can then be
@@ -600,6 +1442,38 @@ impl NodeBase {
         self.save_as_branch_tail()
     }
 
+    /// Reloads this node's children from a `.scn` file on disk, which is useful for quickly
+    /// iterating on a scene while the program is running.
+    /// Every existing child of this node is freed, after which the children of the loaded scene's
+    /// root are instanced under this node. This node itself is left as-is, keeping its name,
+    /// position, and `RID`.
+    /// Returns `Err` if the scene could not be loaded, in which case the children are left
+    /// untouched.
+    ///
+    /// # Panics
+    /// Panics if this Node is not connected to a `NodeTree`.
+    pub fn reload_from_scene(&mut self, path: &Path) -> TreeResult<'_, ()> {
+        if self.tree().is_none() {
+            panic!("Cannot reload a node that is not in a `NodeTree`!");
+        }
+
+        let tree_raw: *mut dyn NodeTree = unsafe { self.tree.unwrap_unchecked() };
+        let scene:    NodeScene         = match NodeScene::load(path) {
+            Ok(scene) => scene,
+            Err(err)  => return unsafe { TreeResult::new(tree_raw, self.rid, Err(format!("Failed to reload from {path:?}: {err}"))) }
+        };
+
+        for child in self.children.clone() {
+            if let Some(child) = unsafe { self.tree_mut().unwrap_unchecked() }.get_node_mut(child) {
+                child.free();
+            }
+        }
+        for child in scene.children() {
+            self.add_child(child.clone());
+        }
+        unsafe { TreeResult::new(tree_raw, self.rid, Ok(())) }
+    }
+
     /// The recursive tail function for `save_as_branch`.
     fn save_as_branch_tail(&self) -> NodeScene {
         
@@ -640,6 +1514,33 @@ impl NodeBase {
     /// This should NOT be called manually.
     pub unsafe fn set_rid(&mut self, rid: RID) {
         self.rid = rid;
+        self.reset_rng();
+    }
+
+    /// Gets this node's deterministic random number generator.
+    /// The node's stream is derived from the tree's seed (see `NodeTreeBase::set_seed()`) and
+    /// this node's `RID`, and is lazily initialized on first use.
+    ///
+    /// # Note
+    /// As the stream is tied to the node's `RID`, removing a node from the tree and then adding it
+    /// back (or otherwise changing its `RID`) will restart its stream from the new `RID`.
+    ///
+    /// # Panics
+    /// Panics if this node is not in a `NodeTree`.
+    pub fn rng(&self) -> NodeRng<'_> {
+        if self.rng.get().is_none() {
+            let seed: u64 = match self.tree() {
+                Some(tree) => tree.base().seed(),
+                None       => panic!("Cannot get the RNG of a node that is not in a `NodeTree`!")
+            };
+            self.rng.set(Some(rng::derive_stream(seed, self.rid)));
+        }
+        NodeRng::new(&self.rng)
+    }
+
+    /// Resets this node's random number stream so that it is re-derived on next use.
+    pub(crate) fn reset_rng(&self) {
+        self.rng.set(None);
     }
 
     /// Gets a reference to the owning `NodeTree` structure, which controls the entire tree.
@@ -746,6 +1647,50 @@ impl NodeBase {
         self.owner = None;
     }
 
+    /// Makes this node the root of its own scene, by making it its own owner.
+    /// Every descendant which shared this node's previous owner is moved over to be owned by this
+    /// node instead, stopping at any nested scene roots (whose branches keep their own owners).
+    /// This affects the boundaries used by `save_as_branch()` and any owner-relative lookups.
+    /// Does nothing if this node is already an owner.
+    ///
+    /// # Note
+    /// Ownership always follows the structure of the tree: after this call, every node owned by
+    /// this node is a descendant of it, and every scene root's descendants are owned either by it
+    /// or by a nested scene root.
+    ///
+    /// # Panics
+    /// Panics if this Node is not connected to a `NodeTree`.
+    pub fn make_scene_root(&mut self) {
+        if self.tree().is_none() {
+            panic!("Cannot make a node that is not in a `NodeTree` into a scene root!");
+        }
+        if self.is_owner() {
+            return;
+        }
+
+        let rid:            RID               = self.rid;
+        let previous_owner: Option<RID>       = self.owner;
+        let tree:           &mut NodeTreeBase = unsafe { self.tree_mut().unwrap_unchecked() };
+        
+        let mut moved: usize    = 0;
+        let mut queue: Vec<RID> = self.children.clone();
+        while let Some(child_rid) = queue.pop() {
+            let child: &mut dyn Node = unsafe { tree.get_node_mut(child_rid).unwrap_unchecked() };
+            if child.owner != previous_owner {
+                continue;
+            }
+
+            unsafe {
+                child.set_owner(rid);
+            }
+            queue.extend_from_slice(&child.children);
+            moved += 1;
+        }
+        
+        self.owner = Some(rid);
+        self.post(Log::Debug(&format!("Node \"{}\" is now a scene root, taking ownership of {} descendant(s)!", self.name, moved)));
+    }
+
     /// Gets a `Tp<T>` pointer to the direct parent of this node, if the node has one.
     /// Returns `Err` if there is no parent or if `T` does not match the parent's type.
     ///
@@ -790,6 +1735,124 @@ impl NodeBase {
         }
     }
 
+    /// Gets the `RID` of this node's direct parent, if the node has one.
+    pub fn parent_rid(&self) -> Option<RID> {
+        self.parent
+    }
+
+    /// Gets an iterator of `TpDyn` pointers to this node's ancestors, starting from the direct
+    /// parent and ending with the root. This node itself is not included.
+    /// The ancestors are only looked up as they are iterated over, so this does not allocate.
+    ///
+    /// # Panics
+    /// Panics if this Node is not connected to a `NodeTree`.
+    pub fn ancestors(&self) -> impl Iterator<Item = TpDyn<'_>> + '_ {
+        let tree: *mut dyn NodeTree = match self.tree {
+            Some(tree) => tree,
+            None       => panic!("Cannot get ancestors from a node that is not a part of a NodeTree!")
+        };
+        
+        std::iter::successors(self.parent, move |&rid| unsafe { (*tree).get_node(rid) }.and_then(|node| node.parent_rid()))
+            .map(move |rid| unsafe { TpDyn::new(tree, self.rid, rid).unwrap_unchecked() })
+    }
+
+    /// Computes the relative path from this node to another node, such as `../Enemies/Orc`.
+    /// The path climbs up to the nearest common ancestor of the two nodes via `..` segments, and
+    /// then descends down to the other node. The path from a node to itself is `.`.
+    /// Returns `None` if the other node is a part of a different tree.
+    ///
+    /// # Panics
+    /// Panics if this Node is not connected to a `NodeTree`.
+    pub fn path_to(&self, other: &TpDyn) -> Option<NodePath> {
+        let tree: &dyn NodeTree = match self.tree() {
+            Some(tree) => tree,
+            None       => panic!("Cannot get a path from a node that is not a part of a NodeTree!")
+        };
+        if !other.tree().is_some_and(|other_tree| std::ptr::addr_eq(tree as *const dyn NodeTree, other_tree as *const dyn NodeTree)) {
+            return None;
+        }
+
+        // Collect the chain of RIDs from each node up to the root.
+        let chain = |rid: RID| std::iter::successors(Some(rid), |&rid| tree.get_node(rid).and_then(|node| node.parent_rid())).collect::<Vec<RID>>();
+        let from: Vec<RID> = chain(self.rid);
+        let to:   Vec<RID> = chain(other.rid());
+        
+        // Find the nearest common ancestor, as the first node in the other chain that is also in
+        // this chain.
+        let (down, up): (usize, usize) = to.iter()
+            .enumerate()
+            .find_map(|(down, rid)| from.iter().position(|ancestor| ancestor == rid).map(|up| (down, up)))?;
+        
+        let mut path: NodePath = NodePath::new();
+        for _ in 0..up {
+            path.push("..");
+        }
+        for &rid in to[..down].iter().rev() {
+            path.push(tree.get_node(rid)?.name());
+        }
+        if up == 0 && down == 0 {
+            path.push(".");
+        }
+        Some(path)
+    }
+
+    /// Gets a `TpDyn` pointer to the root node of the tree that this node is a part of.
+    /// This is the last of this node's `ancestors()`, or this node itself if it is the root.
+    ///
+    /// # Panics
+    /// Panics if this Node is not connected to a `NodeTree`.
+    pub fn root_pointer(&self) -> TpDyn<'_> {
+        let tree: *mut dyn NodeTree = match self.tree {
+            Some(tree) => tree,
+            None       => panic!("Cannot get the root from a node that is not a part of a NodeTree!")
+        };
+
+        unsafe {
+            let root: RID = (*tree).root().rid();
+            TpDyn::new(tree, self.rid, root).unwrap_unchecked()
+        }
+    }
+
+    /// Gets a `TpDyn` pointer to the nearest ancestor of this node which satisfies the given
+    /// predicate, walking up from the direct parent to the root.
+    /// Returns `None` if no ancestor matches.
+    ///
+    /// # Panics
+    /// Panics if this Node is not connected to a `NodeTree`.
+    pub fn find_ancestor(&self, predicate: impl Fn(&dyn Node) -> bool) -> TreeOption<'_, TpDyn<'_>> {
+        let tree: &dyn NodeTree = match self.tree() {
+            Some(tree) => tree,
+            None       => panic!("Cannot get a node from a node that is not a part of a NodeTree!")
+        };
+
+        let mut current: Option<RID> = self.parent;
+        while let Some(rid) = current {
+            let node: &dyn Node = match tree.get_node(rid) {
+                Some(node) => node,
+                None       => break
+            };
+            if predicate(node) {
+                return unsafe { TpDyn::new(self.tree.unwrap_unchecked(), self.rid, rid).ok() };
+            }
+            current = node.parent_rid();
+        }
+        unsafe { TreeOption::new(self.tree.unwrap_unchecked(), self.rid, None) }
+    }
+
+    /// Gets a `Tp<T>` pointer to the nearest ancestor of this node which is of the type `T`,
+    /// walking up from the direct parent to the root.
+    /// Returns `None` if no ancestor is of the type `T`.
+    ///
+    /// # Panics
+    /// Panics if this Node is not connected to a `NodeTree`.
+    pub fn find_ancestor_of_type<T: Node>(&self) -> TreeOption<'_, Tp<'_, T>> {
+        let found: Option<RID> = self.find_ancestor(|node| node.as_any().is::<T>()).to_option().map(|node| node.rid());
+        match found {
+            Some(rid) => unsafe { Tp::new(self.tree.unwrap_unchecked(), self.rid, rid).ok() },
+            None      => unsafe { TreeOption::new(self.tree.unwrap_unchecked(), self.rid, None) }
+        }
+    }
+
     /// Sets the parent of this node.
     ///
     /// # Safety
@@ -806,6 +1869,26 @@ impl NodeBase {
         self.parent = None;
     }
 
+    /// Determines whether this node will have its `process()` function called this frame, given
+    /// its resolved process mode and whether the tree is paused.
+    /// Always returns false if this node is not in a tree, if the tree is terminating, if this node
+    /// is disabled, or if this node is still waiting on a deferred `ready()`.
+    pub fn is_processing(&self) -> bool {
+        let tree: &dyn NodeTree = match self.tree() {
+            Some(tree) => tree,
+            None       => return false
+        };
+        
+        if self.disabled || tree.base().is_awaiting_ready(self.rid) {
+            return false;
+        }
+
+        match (tree.status(), tree.resolve_process_mode(self.rid)) {
+            (TreeStatus::Process(process), Some(process_mode)) | (TreeStatus::QueuedTermination(process), Some(process_mode)) => process.allows(process_mode),
+            _ => false
+        }
+    }
+
     /// Gets the node's status.
     pub fn status(&self) -> NodeStatus {
         self.status.lock().unwrap().to_owned()
@@ -819,11 +1902,115 @@ impl NodeBase {
         *self.status.lock().unwrap() = status;
     }
 
+    /// Returns whether this node is disabled, in which case its `process()` function is skipped.
+    /// Its children are unaffected.
+    pub fn is_disabled(&self) -> bool {
+        self.disabled
+    }
+
+    /// Sets whether this node is disabled, in which case its `process()` function is skipped.
+    /// Nodes are disabled automatically if they panic while the tree is catching node panics.
+    pub fn set_disabled(&mut self, disabled: bool) {
+        self.disabled = disabled;
+    }
+
+    /// Returns whether this node ignores the tree's time scale, in which case it is always passed
+    /// the real delta time.
+    pub fn ignores_time_scale(&self) -> bool {
+        self.unscaled
+    }
+
+    /// Sets whether this node ignores the tree's time scale set via
+    /// `NodeTreeBase::set_time_scale()`, in which case it is always passed the real delta time.
+    pub fn set_ignores_time_scale(&mut self, ignore: bool) {
+        self.unscaled = ignore;
+    }
+
+    /// Returns whether this node is freed once nothing references it.
+    /// See `set_auto_free_when_orphaned()`.
+    pub fn auto_free_when_orphaned(&self) -> bool {
+        self.orphaned
+    }
+
+    /// Sets whether this node is freed once nothing references it anymore, giving it a reference
+    /// counted lifetime. This is off by default, in which case a node lives for exactly as long as
+    /// it is in the tree.
+    ///
+    /// # Note
+    /// A node's parent counts as a reference to it, so a node which is attached to a parent is
+    /// never freed this way. The other references are `TpStrong` handles, which are created via
+    /// `TpDyn::to_strong()`. Tree pointers (`Tp<T>` and `TpDyn`) do not count as references. See
+    /// `NodeTreeBase::reference_count()`.
+    ///
+    /// If such a node still has handles when it is removed via `remove_child()`, its branch is
+    /// detached from the tree instead of being freed. The `terminal()` functions of the branch are
+    /// called as usual, but its nodes stay readable through the handles. The branch is then freed
+    /// at the end of the first frame by which every handle has been dropped.
+    /// Freeing the node, or any of its ancestors, still frees it regardless of any handles.
+    pub fn set_auto_free_when_orphaned(&mut self, auto_free: bool) {
+        self.orphaned = auto_free;
+    }
+
+    /// Attaches a piece of typed data to this node, replacing any data of the same type that was
+    /// attached previously. This allows for composing behaviour onto existing nodes without
+    /// having to define a new node type.
+    ///
+    /// # Note
+    /// Attached data only exists at runtime; it is neither saved with the node nor cloned with it.
+    pub fn set_data<T: 'static>(&mut self, data: T) {
+        self.data.insert(TypeId::of::<T>(), Box::new(data));
+    }
+
+    /// Gets a reference to the data of type `T` attached to this node, if there is any.
+    pub fn get_data<T: 'static>(&self) -> Option<&T> {
+        self.data.get(&TypeId::of::<T>()).and_then(|data| data.downcast_ref())
+    }
+
+    /// Gets a mutable reference to the data of type `T` attached to this node, if there is any.
+    pub fn get_data_mut<T: 'static>(&mut self) -> Option<&mut T> {
+        self.data.get_mut(&TypeId::of::<T>()).and_then(|data| data.downcast_mut())
+    }
+
+    /// Removes and returns the data of type `T` attached to this node, if there is any.
+    pub fn take_data<T: 'static>(&mut self) -> Option<T> {
+        self.data.remove(&TypeId::of::<T>()).and_then(|data| data.downcast().ok()).map(|data| *data)
+    }
+
+    /// Runs this node's `process()` function immediately, regardless of its process mode or the
+    /// tree's paused state. This is how nodes with the `Manual` process mode are meant to be driven.
+    /// Errors returned from the hook are reported as they would be during a normal frame.
+    ///
+    /// # Panics
+    /// Panics if this Node is not connected to a `NodeTree`.
+    pub fn process_now(&mut self, delta: f32) {
+        if self.tree().is_none() {
+            panic!("Cannot process a node that is not in a `NodeTree`!");
+        }
+        unsafe { self.tree_mut().unwrap_unchecked() }.process_node(self.rid, delta);
+    }
+
     /// Gets the node's depth.
     pub fn depth(&self) -> usize {
         self.depth
     }
 
+    /// Gets the node's depth relative to its owner, or the root of the scene that it belongs to.
+    /// A scene's owner has a scene depth of zero.
+    ///
+    /// # Panics
+    /// Panics if this Node is not connected to a `NodeTree`.
+    pub fn scene_depth(&self) -> usize {
+        let tree: &dyn NodeTree = match self.tree() {
+            Some(tree) => tree,
+            None       => panic!("Cannot get the scene depth of a node that is not in a `NodeTree`!")
+        };
+        
+        match self.owner.and_then(|owner| tree.get_node(owner)) {
+            Some(owner) => self.depth - owner.depth(),
+            None        => self.depth
+        }
+    }
+
     /// Sets the node's depth.
     ///
     /// # Safety
@@ -867,12 +2054,12 @@ impl NodeBase {
 
     /// Returns the number of children this node has.
     pub fn num_children(&self) -> usize {
-        self.children().len()
+        self.children.len()
     }
 
     /// Returns true if this node has no children.
     pub fn childless(&self) -> bool {
-        self.num_children() == 0
+        self.children.is_empty()
     }
 
     /// Marks this node as just having been recently loaded from the disk.
@@ -899,17 +2086,26 @@ impl NodeBase {
 
 impl std::fmt::Debug for NodeBase {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        
+        // Show the path of each referenced node alongside its RID if the node is in a tree.
+        let describe = |rid: RID| -> String {
+            match self.tree().and_then(|tree| tree.path_of_rid(rid)) {
+                Some(path) => format!("{path} ({rid})"),
+                None       => rid.to_string()
+            }
+        };
+
         f.write_str(&format!("Inner[{}] {{ ", self.name))?;
         
-        if let Some(parent) = &self.parent {
-            f.write_str(&format!("Parent: {}, ", parent/*.name()*/))?;
+        if let Some(parent) = self.parent {
+            f.write_str(&format!("Parent: {}, ", describe(parent)))?;
         }
-        if let Some(owner) = &self.owner {
-            f.write_str(&format!("Owner: {}, ", owner/*.name()*/))?;
+        if let Some(owner) = self.owner {
+            f.write_str(&format!("Owner: {}, ", describe(owner)))?;
         }
 
         f.write_str(&format!("Connected to Tree: {}, ", self.tree.is_some()))?;
-        f.write_str(&format!("Children: {:?}, ", &self.children))?;
+        f.write_str(&format!("Children: [{}], ", self.children.iter().map(|&child| describe(child)).collect::<Vec<_>>().join(", ")))?;
         f.write_str(&format!("Depth: {} ", self.depth))?;
         f.write_str("}")?;
 
@@ -919,6 +2115,11 @@ impl std::fmt::Debug for NodeBase {
 
 impl Clone for NodeBase {
     fn clone(&self) -> Self {
-        Self::new(self.name.clone())
+        let mut base: NodeBase = Self::new(self.name.clone());
+                base.groups    = self.groups.clone();
+                base.mode      = self.mode;
+                base.orphaned  = self.orphaned;
+                base.links     = self.links.clone();
+        base
     }
 }
@@ -25,7 +25,7 @@
 use std::ops::{ Deref, DerefMut, self };
 use std::mem;
 
-use crate::traits::exportable::{ Voidable, Exportable };
+use crate::traits::exportable::{ Voidable, Exportable, ExportError, ValueKind };
 
 
 /*
@@ -309,6 +309,17 @@ impl <T: Exportable> Exportable for ExportableField<T> {
     fn from_value(value: toml_edit::Value) -> Option<Self> where Self: Sized {
         Some(Self::new(T::from_value(value)?))
     }
+
+    fn expected_kind() -> Option<ValueKind> where Self: Sized { T::expected_kind() }
+    fn expected_len()  -> Option<usize>     where Self: Sized { T::expected_len() }
+
+    fn try_from_value(value: toml_edit::Value) -> Result<Self, ExportError> where Self: Sized {
+        T::try_from_value(value).map(Self::new)
+    }
+
+    fn to_document_fragment(&self) -> toml_edit::Item {
+        self.0.to_document_fragment()
+    }
 }
 
 impl <T: ops::AddAssign + Exportable> ops::AddAssign for ExportableField<T> {
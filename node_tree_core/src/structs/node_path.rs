@@ -44,6 +44,8 @@
 //! More examples of valid path types can be found under the documentation of `NodePath`.
 
 use std::fmt;
+use std::mem;
+use std::str::Chars;
 use std::collections::VecDeque;
 
 use crate::traits::node_getter::NodeGetter;
@@ -86,12 +88,48 @@ impl PathSeg {
 
     /// Converts this back to a string.
     pub fn to_string(&self) -> String {
+        self.as_str().to_string()
+    }
+
+    /// Gets this as a string slice.
+    pub fn as_str(&self) -> &str {
         match self {
-            Self::Node(str) => str.to_string(),
-            Self::This      => ".".to_string(),
-            Self::Parent    => "..".to_string(),
+            Self::Node(str) => str,
+            Self::This      => ".",
+            Self::Parent    => "..",
+        }
+    }
+
+    /// Gets this as a string with any slashes or backslashes escaped, as it would be written in a
+    /// path string.
+    pub fn to_escaped(&self) -> String {
+        self.as_str().replace('\\', "\\\\").replace('/', "\\/")
+    }
+}
+
+/// Splits a path string into its segments on every `/` that is not escaped by a backslash.
+/// Escaped slashes (`\/`) and backslashes (`\\`) are unescaped, while any other backslash is kept
+/// as is.
+fn split_escaped(str: &str) -> Vec<String> {
+    let mut segments: Vec<String> = Vec::new();
+    let mut current:  String      = String::new();
+    let mut chars:    Chars       = str.chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.clone().next() {
+                Some(next @ ('/' | '\\')) => {
+                    current.push(next);
+                    chars.next();
+                },
+                _ => current.push(c)
+            },
+            '/' => segments.push(mem::take(&mut current)),
+            _   => current.push(c)
         }
     }
+    segments.push(current);
+    segments
 }
 
 
@@ -117,6 +155,13 @@ impl PathSeg {
 /// ```rust, ignore
 /// let root: NodePath = nodepath!("/root");
 /// ```
+///
+/// # Escaping
+/// Node names may contain slashes, which must be escaped with a backslash within path strings
+/// (`a\/b` refers to a single node named `a/b`). A literal backslash is likewise written as
+/// `\\`, while any other backslash is kept as is. Formatting a path escapes its node names in the
+/// same way, so that it can always be parsed back into the same path.
+/// Alternatively, `NodePath::from_segments()` may be used to build a path from raw node names.
 #[derive(Clone, Default, Hash, PartialEq, Eq)]
 pub struct NodePath {
     path: VecDeque<PathSeg>,
@@ -146,8 +191,10 @@ impl NodePath {
     /// ```text
     /// "node_a/node_b/node_c/target_node"
     /// ```
+    ///
+    /// Any slashes within a node's name must be escaped as `\/`.
     pub fn from_str(str: &str) -> NodePath {
-        let mut path: VecDeque<PathSeg> = str.split('/').map(PathSeg::parse).collect();
+        let mut path: VecDeque<PathSeg> = split_escaped(str).iter().map(|seg| PathSeg::parse(seg)).collect();
         let     abs:  bool              = path.front().map(|f| f.is_empty_identifier()).unwrap_or(false);
         
         if abs {
@@ -162,13 +209,18 @@ impl NodePath {
         np
     }
 
-    /// Converts the path to a string, consuming it.
-    pub fn to_string(mut self) -> String {
-        let mut out: String = String::new();
-        while let Some(segment) = self.pop_front() {
-            out += &(segment.to_string() + "/");
-        }
-        out.get(0..(out.len() - 1)).unwrap().to_string()
+    /// Creates a new relative NodePath from raw segments, without any parsing or unescaping of
+    /// slashes. Each segment is taken as a node name as is, aside from `.` and `..`.
+    /// ```rust,ignore
+    /// let path: NodePath = NodePath::from_segments(&["Menu", "Save/Load"]);
+    /// ```
+    pub fn from_segments(segments: &[&str]) -> NodePath {
+        let mut np: NodePath = NodePath {
+            path: segments.iter().map(|seg| PathSeg::parse(seg)).collect(),
+            abs:  false
+        };
+        np.scan();
+        np
     }
 
     /// Adds a node to the back of the path.
@@ -178,6 +230,42 @@ impl NodePath {
         self.scan();
     }
 
+    /// Pushes a segment to the back of the path.
+    /// The segment may be a node name, `.`, or `..`.
+    #[inline]
+    pub fn push(&mut self, segment: &str) {
+        self.add_node(segment);
+    }
+
+    /// Creates a new path by appending another path to the end of this one.
+    /// If the other path is absolute, then it is returned as is.
+    pub fn join(&self, other: &NodePath) -> NodePath {
+        if other.abs {
+            return other.clone();
+        }
+
+        let mut joined: NodePath = self.clone();
+        joined.path.extend(other.path.iter().cloned());
+        joined
+    }
+
+    /// Gets the path without its last segment.
+    /// Returns `None` if the path is empty.
+    pub fn parent(&self) -> Option<NodePath> {
+        if self.path.is_empty() {
+            return None;
+        }
+
+        let mut parent: NodePath = self.clone();
+        parent.path.pop_back();
+        Some(parent)
+    }
+
+    /// Gets the last segment of the path, if there is one.
+    pub fn file_name(&self) -> Option<&str> {
+        self.path.back().map(|seg| seg.as_str())
+    }
+
     /// Pops the front-most node off the path and returns it, if there is one.
     /// If the path is empty, then this returns None.
     #[inline]
@@ -218,6 +306,18 @@ impl NodeGetter for NodePath {
     }
 }
 
+impl fmt::Display for NodePath {
+    
+    /// Formats the path as `a/b/c`, with a leading slash if the path is absolute.
+    /// Slashes and backslashes within node names are escaped.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.abs {
+            f.write_str("/")?;
+        }
+        f.write_str(&self.path.iter().map(|seg| seg.to_escaped()).collect::<Vec<_>>().join("/"))
+    }
+}
+
 impl fmt::Debug for NodePath {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut path: String = self.path.iter().map(|node| "/".to_owned() + &node.to_string()).collect();
@@ -32,9 +32,9 @@ use std::hash::{ self, Hash, Hasher };
 
 use toml_edit as toml;
 
-use crate::structs::rid::RID;
+use crate::structs::{ rid::RID, node_path::NodePath, node_tree_base::ProcessMode, placeholder::Placeholder };
 use crate::traits::{ node::Node, instanceable::Instanceable };
-use crate::services::node_registry::{ self, FieldMap, SFieldMap };
+use crate::services::{ node_registry::{ self, FieldMap, SFieldMap }, connection_registry::PersistentConnection };
 
 
 /*
@@ -46,6 +46,50 @@ use crate::services::node_registry::{ self, FieldMap, SFieldMap };
 /// A comment placed at the root of every .scn file.
 const SCN_COMMENT: &str = "# This scene file was generated automatically via node_tree.\n# If you wish to modify it, ensure that children are in front of their parents.\n\n";
 
+/// The magic bytes at the start of every gzip stream.
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+
+
+/// Describes a single difference between two `NodeScene`s, as returned by `NodeScene::diff()`.
+/// Each difference is located by the path of the node that it applies to, relative to the parent
+/// of the scene's root - i.e. the root of the scene itself is referred to by its own name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SceneDiff {
+    
+    /// A node is present in the other scene, but not in this one.
+    NodeAdded { path: NodePath },
+
+    /// A node is present in this scene, but not in the other one.
+    NodeRemoved { path: NodePath },
+
+    /// The root nodes of the two scenes have different names.
+    RootRenamed { old: String, new: String },
+
+    /// A node's type differs between the two scenes.
+    TypeChanged { path: NodePath, old: String, new: String },
+
+    /// A node is the owner of its scene in only one of the two scenes.
+    OwnershipChanged { path: NodePath, is_owner: bool },
+
+    /// A node has the same children in both scenes, but in a different order.
+    ChildrenReordered { path: NodePath },
+
+    /// A node belongs to different groups in the two scenes.
+    /// The groups are sorted, as the order that they were joined in is not significant.
+    GroupsChanged { path: NodePath, old: Vec<String>, new: Vec<String> },
+
+    /// A node's process mode override differs between the two scenes.
+    ProcessModeChanged { path: NodePath, old: Option<ProcessMode>, new: Option<ProcessMode> },
+
+    /// A node's persistent connections differ between the two scenes, including their order.
+    ConnectionsChanged { path: NodePath, old: Vec<PersistentConnection>, new: Vec<PersistentConnection> },
+
+    /// An exported field's value differs between the two scenes.
+    /// A missing field is represented by `None`, while values are represented in their `toml`
+    /// form.
+    FieldChanged { path: NodePath, field: String, old: Option<String>, new: Option<String> }
+}
+
 
 /// A recursive structure that allows for the storage, saving, and loading of a dormant scene of nodes.
 /// The root node is what every node in the scene will have its owner set to.
@@ -75,6 +119,28 @@ impl NodeScene {
         }
     }
 
+    /// Wraps a single, freshly constructed node as a `NodeScene`.
+    /// The resulting scene can then be built upon node-by-node with `append()` and
+    /// `append_as_owner()`:
+    /// ```rust,ignore
+    /// let mut scene: NodeScene = NodeScene::from_node(Menu::new());
+    /// scene.append(NodeScene::from_node(MenuItem::new()));
+    /// scene.append(NodeScene::from_node(MenuItem::new()));
+    /// ```
+    ///
+    /// # Note
+    /// The node is taken as-is, so it should not be a node that was previously part of a
+    /// `NodeTree`. Use `NodeBase::save_as_branch()` to capture a node that lives in a tree.
+    pub fn from_node<N: Node>(node: N) -> Self {
+        Self::new(node)
+    }
+
+    /// Wraps a single, freshly constructed dynamic node as a `NodeScene`.
+    /// See `NodeScene::from_node()` for more information.
+    pub fn from_node_dyn(node: Box<dyn Node>) -> Self {
+        Self::new_dyn(node)
+    }
+
     /// Loads a `NodeScene` from a string.
     pub fn load_from_str(document: &str) -> Result<Self, String> {
         let document: toml::DocumentMut = document.parse().map_err(|err| format!("{err}"))?;
@@ -86,21 +152,41 @@ impl NodeScene {
         for (key, node_data) in document.iter() {
 
             // Deserialize the node's metadata.
-            let node_data: &toml::Table       = node_data.as_table().ok_or(format!("Failed to parse {}'s data", key))?;
-            let metadata:  &toml::InlineTable = node_data.get("metadata").map(|nd| nd.as_inline_table()).flatten().ok_or(format!("Failed to parse {}'s metadata", key))?;
-            let type_name: String             = metadata.get("type_name").map(|tn| tn.as_str().map(|s| s.to_string())).flatten().ok_or(format!("Failed to parse {}'s type name", key))?;
-            let is_owner:  bool               = metadata.get("is_owner").map(|tn| tn.as_bool()).flatten().ok_or(format!("Failed to parse {}'s ownership status", key))?;
-            let parent:    Option<RID>        = metadata.get("parent").map(|p| p.as_integer().map(|rid| rid as RID)).flatten();
+            let node_data: &toml::Table        = node_data.as_table().ok_or(format!("Failed to parse {}'s data", key))?;
+            let metadata:  &toml::InlineTable  = node_data.get("metadata").map(|nd| nd.as_inline_table()).flatten().ok_or(format!("Failed to parse {}'s metadata", key))?;
+            let type_name: String              = metadata.get("type_name").map(|tn| tn.as_str().map(|s| s.to_string())).flatten().ok_or(format!("Failed to parse {}'s type name", key))?;
+            let is_owner:  bool                = metadata.get("is_owner").map(|tn| tn.as_bool()).flatten().ok_or(format!("Failed to parse {}'s ownership status", key))?;
+            let parent:    Option<RID>         = metadata.get("parent").map(|p| p.as_integer().map(|rid| rid as RID)).flatten();
+            let groups:    Vec<String>         = match metadata.get("groups") {
+                Some(groups) => groups.as_array()
+                    .and_then(|groups| groups.iter().map(|group| group.as_str().map(|g| g.to_string())).collect())
+                    .ok_or(format!("Failed to parse {}'s groups", key))?,
+                None => Vec::new()
+            };
+            let mode:      Option<ProcessMode> = match metadata.get("process_mode") {
+                Some(mode) => Some(mode.as_str().ok_or(format!("Failed to parse {}'s process mode", key))?.parse()?),
+                None       => None
+            };
+            let connections: Vec<PersistentConnection> = match metadata.get("connections") {
+                Some(connections) => connections.as_array()
+                    .and_then(|connections| connections.iter().map(|connection| {
+                        let connection: &toml::InlineTable = connection.as_inline_table()?;
+                        let field = |name: &str| connection.get(name).and_then(|value| value.as_str());
+                        Some(PersistentConnection {
+                            signal: field("signal")?.to_string(),
+                            target: NodePath::from_str(field("target")?),
+                            method: field("method")?.to_string()
+                        })
+                    }).collect())
+                    .ok_or(format!("Failed to parse {}'s connections", key))?,
+                None => Vec::new()
+            };
 
             // Deserialize the node data back into its respective type.
             let node_fields: Option<SFieldMap> = node_data.into_iter()
                 .filter(|(field, _)| *field != "metadata")
-                .map(|(field, value)| {
-                    match value {
-                        toml::Item::Value(value) => Some((field.into(), value.to_owned())),
-                        _                        => None
-                    }
-                }).collect();
+                .map(|(field, value)| value.clone().into_value().ok().map(|value| (field.into(), value)))
+                .collect();
 
             let mut node: Box<dyn Node> = node_registry::deserialize(&type_name, node_fields.ok_or("Could not parse node fields".to_string())?)?;
             let (name, local_rid): (&str, RID) = key.split_once('_')
@@ -111,6 +197,13 @@ impl NodeScene {
                 node.set_name_unchecked(name);
                 node.set_rid(local_rid);
             }
+            for group in &groups {
+                node.add_to_group(group);
+            }
+            node.set_process_mode_override(mode);
+            for connection in connections {
+                node.connect_persistent(&connection.signal, connection.target, &connection.method);
+            }
 
             
             // Append the node to the scene.
@@ -177,6 +270,10 @@ impl NodeScene {
     }
 
     /// Loads a `NodeScene` from a `.scn` file.
+    ///
+    /// # Note
+    /// Compressed scenes are not supported. Files starting with the gzip magic bytes are rejected
+    /// with a descriptive error rather than being parsed as `toml`.
     pub fn load(path: &Path) -> Result<Self, String> {
         
         // Ensure that the file described is a scene file.
@@ -192,6 +289,11 @@ impl NodeScene {
         
         file.read_to_end(&mut buffer).map_err(|err| format!("{err}"))?;
         drop(file);
+
+        // Compressed scenes cannot be read yet, so report them clearly rather than as a parse error.
+        if buffer.starts_with(&GZIP_MAGIC) {
+            return Err("Attempted to load a gzip-compressed scene, which is not supported".to_string());
+        }
         
         // Attempt to parse the file as a table.
         let document: String = String::from_utf8(buffer).map_err(|err| format!("{err}"))?;
@@ -221,6 +323,21 @@ impl NodeScene {
             if let Some(parent_rid) = parent.map(|p| p.rid()) {
                 document[&node_key]["metadata"]["parent"] = (parent_rid as i64).into();
             }
+            if !node.groups().is_empty() {
+                document[&node_key]["metadata"]["groups"] = toml::Array::from_iter(node.groups()).into();
+            }
+            if let Some(mode) = node.process_mode_override() {
+                document[&node_key]["metadata"]["process_mode"] = mode.as_str().into();
+            }
+            if !node.persistent_connections().is_empty() {
+                document[&node_key]["metadata"]["connections"] = toml::Array::from_iter(node.persistent_connections().iter().map(|connection| {
+                    let mut table: toml::InlineTable = toml::InlineTable::new();
+                            table.insert("signal", connection.signal.as_str().into());
+                            table.insert("target", connection.target.to_string().into());
+                            table.insert("method", connection.method.as_str().into());
+                    table
+                })).into();
+            }
 
             // Save the fields.
             let node_fields: FieldMap = node.save_from_owned();
@@ -228,7 +345,7 @@ impl NodeScene {
                 if unsafe { value.is_ghost_export() } {
                     continue;
                 }
-                document[&node_key][&field_name.to_string()] = toml::Item::Value(value.to_value());
+                document[&node_key][&field_name.to_string()] = value.to_document_fragment();
             }
         });
 
@@ -251,6 +368,49 @@ impl NodeScene {
         Ok(())
     }
 
+    /// Gets the total number of nodes within the scene, including the root.
+    /// This only walks the scene's representation, so it can be used to reject overly large
+    /// scenes (such as those loaded from untrusted sources) before they are instanced.
+    pub fn node_count(&self) -> usize {
+        1 + self.children.iter().map(|child| child.node_count()).sum::<usize>()
+    }
+
+    /// Gets the depth of the deepest node within the scene, where the root has a depth of `0`.
+    /// Like `node_count()`, this only walks the scene's representation.
+    pub fn max_depth(&self) -> usize {
+        self.children.iter().map(|child| child.max_depth() + 1).max().unwrap_or(0)
+    }
+
+    /// Checks that every node type within the scene has a registered deserializer, so that the
+    /// scene can be saved and loaded back without failing part way through.
+    /// Returns the type names without a deserializer, in the order that they were found and
+    /// without duplicates.
+    ///
+    /// # Note
+    /// Nodes declared via `class!` are registered automatically, although generic nodes are not.
+    pub fn validate_types(&self) -> Result<(), Vec<String>> {
+        let mut unknown: Vec<String> = Vec::new();
+        self.collect_unknown_types(&mut unknown);
+
+        if unknown.is_empty() {
+            Ok(())
+        } else {
+            Err(unknown)
+        }
+    }
+
+    /// Recursively collects the type names within the scene that have no registered deserializer.
+    fn collect_unknown_types(&self, unknown: &mut Vec<String>) {
+        let type_name: String = unsafe { &*self.this }.name_as_type();
+        if !node_registry::is_registered(&type_name) && !unknown.contains(&type_name) {
+            unknown.push(type_name);
+        }
+
+        for child in &self.children {
+            child.collect_unknown_types(unknown);
+        }
+    }
+
     /// Recursively builds a hash that represents the scene layout.
     /// This will NOT check node fields, but will only compare the shape, ownership, and types
     /// present throughout a scene tree.
@@ -280,6 +440,119 @@ impl NodeScene {
         }
     }
 
+    /// Compares this scene to another, returning a list of every difference between the two.
+    /// Nodes are compared by their type, name, ownership, the order of their children, their
+    /// exported fields, and the groups, process mode override and persistent connections that are
+    /// saved along with them. Children are matched between the scenes by their names.
+    ///
+    /// Returns an empty list if the scenes are equal.
+    pub fn diff(&self, other: &NodeScene) -> Vec<SceneDiff> {
+        let mut diffs: Vec<SceneDiff> = Vec::new();
+        let     this:  &dyn Node      = unsafe { &*self.this };
+        let     that:  &dyn Node      = unsafe { &*other.this };
+
+        if this.name() != that.name() {
+            diffs.push(SceneDiff::RootRenamed { old: this.name().to_string(), new: that.name().to_string() });
+        }
+
+        self.diff_tail(other, NodePath::from_str(this.name()), &mut diffs);
+        diffs
+    }
+
+    /// The recursive tail for the `diff` function.
+    fn diff_tail(&self, other: &NodeScene, path: NodePath, diffs: &mut Vec<SceneDiff>) {
+        let this: &dyn Node = unsafe { &*self.this };
+        let that: &dyn Node = unsafe { &*other.this };
+        
+        // Compare the node itself.
+        if this.name_as_type() != that.name_as_type() {
+            diffs.push(SceneDiff::TypeChanged { path: path.clone(), old: this.name_as_type(), new: that.name_as_type() });
+        }
+        if self.is_owner != other.is_owner {
+            diffs.push(SceneDiff::OwnershipChanged { path: path.clone(), is_owner: other.is_owner });
+        }
+
+        // Compare the node's persisted state.
+        let mut this_groups: Vec<String> = this.groups().to_vec();
+        let mut that_groups: Vec<String> = that.groups().to_vec();
+                this_groups.sort();
+                that_groups.sort();
+
+        if this_groups != that_groups {
+            diffs.push(SceneDiff::GroupsChanged { path: path.clone(), old: this_groups, new: that_groups });
+        }
+        if this.process_mode_override() != that.process_mode_override() {
+            diffs.push(SceneDiff::ProcessModeChanged { path: path.clone(), old: this.process_mode_override(), new: that.process_mode_override() });
+        }
+        if this.persistent_connections() != that.persistent_connections() {
+            diffs.push(SceneDiff::ConnectionsChanged {
+                path: path.clone(),
+                old:  this.persistent_connections().to_vec(),
+                new:  that.persistent_connections().to_vec()
+            });
+        }
+
+        // Compare the exported fields.
+        let     this_fields: HashMap<String, toml::Value> = Self::exported_fields(this);
+        let     that_fields: HashMap<String, toml::Value> = Self::exported_fields(that);
+        let mut field_names: Vec<&String>                 = this_fields.keys().chain(that_fields.keys()).collect();
+                field_names.sort();
+                field_names.dedup();
+
+        for field in field_names {
+            let old: Option<&toml::Value> = this_fields.get(field);
+            let new: Option<&toml::Value> = that_fields.get(field);
+            let eq:  bool                 = match (old, new) {
+                (Some(old), Some(new)) => values_eq(old, new),
+                _                      => false
+            };
+
+            if !eq {
+                diffs.push(SceneDiff::FieldChanged {
+                    path:  path.clone(),
+                    field: field.clone(),
+                    old:   old.map(|value| value.to_string()),
+                    new:   new.map(|value| value.to_string())
+                });
+            }
+        }
+
+        // Compare the children by name.
+        let child_name = |scene: &NodeScene| unsafe { &*scene.this }.name().to_string();
+        let this_names: Vec<String> = self.children.iter().map(child_name).collect();
+        let that_names: Vec<String> = other.children.iter().map(child_name).collect();
+
+        for (child, name) in self.children.iter().zip(&this_names) {
+            let child_path: NodePath = path.join(&NodePath::from_str(name));
+            match other.children.iter().zip(&that_names).find(|(_, other_name)| *other_name == name) {
+                Some((other_child, _)) => child.diff_tail(other_child, child_path, diffs),
+                None                   => diffs.push(SceneDiff::NodeRemoved { path: child_path })
+            }
+        }
+        for name in that_names.iter().filter(|name| !this_names.contains(name)) {
+            diffs.push(SceneDiff::NodeAdded { path: path.join(&NodePath::from_str(name)) });
+        }
+
+        let this_common: Vec<&String> = this_names.iter().filter(|name| that_names.contains(name)).collect();
+        let that_common: Vec<&String> = that_names.iter().filter(|name| this_names.contains(name)).collect();
+        if this_common != that_common {
+            diffs.push(SceneDiff::ChildrenReordered { path });
+        }
+    }
+
+    /// Gets a node's exported fields in their `toml` form.
+    fn exported_fields(node: &dyn Node) -> HashMap<String, toml::Value> {
+        node.save_from_owned()
+            .into_iter()
+            .filter(|(_, value)| !unsafe { value.is_ghost_export() })
+            .map(|(field, value)| {
+                let mut value: toml::Value = value.to_value();
+                value.decor_mut().clear();
+                (field.to_string(), value)
+            })
+            .collect()
+    }
+
     /// Appends a `NodeScene` as a child.
     pub fn append(&mut self, mut child: NodeScene) {
         child.is_owner = false; // Have this only be applied for single nodes, not whole node scenes!
@@ -293,6 +566,54 @@ impl NodeScene {
         self.children.push(child);
     }
 
+    /// Wraps this scene in a `Placeholder` node, so that its nodes are not instanced along with the
+    /// rest of the scene that it is a part of. The placeholder can then be expanded into the real
+    /// scene on demand via `NodeBase::instantiate_placeholder()`, which is useful for streaming in
+    /// parts of large trees. See `Placeholder` for more information.
+    pub fn as_placeholder(self) -> NodeScene {
+        let     is_owner: bool      = self.is_owner;
+        let mut scene:    NodeScene = NodeScene::new(Placeholder::new(self));
+                scene.is_owner      = is_owner;
+        scene
+    }
+
+    /// Gets the name of the scene's root node.
+    pub fn root_name(&self) -> String {
+        unsafe { &*self.this }.name().to_string()
+    }
+
+    /// Sets the name of the scene's root node, without checking for uniqueness.
+    pub(crate) fn set_root_name(&mut self, name: &str) {
+        unsafe {
+            (*self.this).set_name_unchecked(name);
+        }
+    }
+
+    /// Gets the full type name of the scene's root node, as given by `name_as_type()`.
+    /// This may be used to check what kind of scene was loaded before instancing it.
+    pub fn root_type_name(&self) -> String {
+        unsafe { &*self.this }.name_as_type()
+    }
+
+    /// Creates a new instance of the scene's root node as the type `T`.
+    /// Returns `None` if the root node is not of the type `T`.
+    ///
+    /// # Note
+    /// Only the root node itself is instanced, without any of its children. To check the root's
+    /// type before instancing the entire scene, use `root_type_name()` instead.
+    pub fn instance_as<T: Node>(&self) -> Option<Box<T>> {
+        let root: &dyn Node = unsafe { &*self.this };
+        if !root.as_any().is::<T>() {
+            return None;
+        }
+
+        let mut instance: Box<dyn Node> = root.clone_as_instance();
+        unsafe {
+            instance.set_name_unchecked(root.name());
+        }
+        Some(unsafe { Box::from_raw(Box::into_raw(instance) as *mut T) })
+    }
+
     /// Returns this `NodeScene` instance's associated node.
     /// 
     /// # Safety
@@ -321,6 +642,20 @@ impl NodeScene {
     }
 }
 
+/// Compares two `toml` values semantically, ignoring formatting and the order of table entries.
+fn values_eq(a: &toml::Value, b: &toml::Value) -> bool {
+    match (a, b) {
+        (toml::Value::String(a),      toml::Value::String(b))      => a.value() == b.value(),
+        (toml::Value::Integer(a),     toml::Value::Integer(b))     => a.value() == b.value(),
+        (toml::Value::Float(a),       toml::Value::Float(b))       => a.value() == b.value(),
+        (toml::Value::Boolean(a),     toml::Value::Boolean(b))     => a.value() == b.value(),
+        (toml::Value::Datetime(a),    toml::Value::Datetime(b))    => a.value() == b.value(),
+        (toml::Value::Array(a),       toml::Value::Array(b))       => a.len() == b.len() && a.iter().zip(b.iter()).all(|(a, b)| values_eq(a, b)),
+        (toml::Value::InlineTable(a), toml::Value::InlineTable(b)) => a.len() == b.len() && a.iter().all(|(key, a)| b.get(key).is_some_and(|b| values_eq(a, b))),
+        _                                                          => false
+    }
+}
+
 impl Clone for NodeScene {
     fn clone(&self) -> Self {
         let cloned_node = unsafe {
@@ -345,6 +680,15 @@ impl Clone for NodeScene {
     }
 }
 
+impl PartialEq for NodeScene {
+    
+    /// Two scenes are equal if there are no differences between them as described by
+    /// `NodeScene::diff()`.
+    fn eq(&self, other: &Self) -> bool {
+        self.diff(other).is_empty()
+    }
+}
+
 impl hash::Hash for NodeScene {
     fn hash<H: hash::Hasher>(&self, state: &mut H) {
         self.internal_structural_hash(state)
@@ -44,13 +44,26 @@
 //! }
 //! ```
 
-use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::mem;
+use std::rc::{ Rc, Weak };
+use std::cell::Cell;
+use std::panic::{ self, AssertUnwindSafe };
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::path::Path;
 use std::time::{ Duration, Instant };
 
 use crate::traits::{ node::Node, node_tree::NodeTree, node_getter::NodeGetter, instanceable::Instanceable };
+use crate::services::resource_cache::ResourceCache;
 use super::logger::*;
-use super::node_base::NodeStatus;
-use super::rid::{ RID, RIDHolder };
+use super::input::InputEvent;
+use super::node_path::NodePath;
+use super::node_scene::NodeScene;
+use super::placeholder::Placeholder;
+use super::node_base::{ NodeStatus, NodeError };
+use super::rid::{ RID, RIDHolder, RidStats };
+use super::tree_option::TreeOption;
+use super::tree_pointer::{ Tp, TpDyn };
 
 
 /*
@@ -63,12 +76,63 @@ use super::rid::{ RID, RIDHolder };
 /// You may wish to have some nodes be active always, be pausible, or only run when the program is
 /// paused.
 /// `Inherit` is for nodes whose behaviour is inherited from parent nodes.
-#[derive(Debug, Clone, Copy, PartialEq)]
+/// `Manual` is for nodes which are never processed by the tree, and instead have their `process()`
+/// function driven explicitly via `NodeBase::process_now()`.
+///
+/// # Note
+/// Unlike a disabled node, a `Manual` node is still traversed during processing, so its children are
+/// processed as usual. Children which `Inherit` their process mode skip over a `Manual` parent and
+/// inherit from the next ancestor instead.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ProcessMode {
     Inherit,
     Always,
     Pausable,
     Inverse,
+    Manual,
+}
+
+impl ProcessMode {
+
+    /// Gets the name of the process mode, as it is written in saved scenes.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ProcessMode::Inherit  => "Inherit",
+            ProcessMode::Always   => "Always",
+            ProcessMode::Pausable => "Pausable",
+            ProcessMode::Inverse  => "Inverse",
+            ProcessMode::Manual   => "Manual"
+        }
+    }
+}
+
+impl std::str::FromStr for ProcessMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Inherit"  => Ok(ProcessMode::Inherit),
+            "Always"   => Ok(ProcessMode::Always),
+            "Pausable" => Ok(ProcessMode::Pausable),
+            "Inverse"  => Ok(ProcessMode::Inverse),
+            "Manual"   => Ok(ProcessMode::Manual),
+            _          => Err(format!("Unknown process mode {s:?}"))
+        }
+    }
+}
+
+/// Determines whether a node is processed before or after its children within a frame.
+/// `ParentFirst` is the default, which allows parents to prepare state for their children.
+/// `ChildrenFirst` allows parents to aggregate the state of their children in the same frame,
+/// such as for computing a container's size from the sizes of its children.
+///
+/// # Note
+/// This also applies to the order in which `terminal()` is called when the tree exits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ProcessOrder {
+    #[default]
+    ParentFirst,
+    ChildrenFirst
 }
 
 /// Determines the tree's current behaviour.
@@ -115,6 +179,26 @@ pub enum TreeProcess {
     Paused
 }
 
+impl TreeProcess {
+    
+    /// Determines whether a node with the given resolved process mode runs its `process()`
+    /// function under this tree process state.
+    ///
+    /// # Panics
+    /// Panics if the process mode is `Inherit`, as it should have been resolved beforehand.
+    pub fn allows(&self, process_mode: ProcessMode) -> bool {
+        match (self, process_mode) {
+            (_, ProcessMode::Inherit)                     => panic!("Inherited process mode not set!"),
+            (_, ProcessMode::Always)                      => true,
+            (TreeProcess::Running, ProcessMode::Pausable) => true,
+            (TreeProcess::Running, ProcessMode::Inverse)  => false,
+            (TreeProcess::Paused,  ProcessMode::Pausable) => false,
+            (TreeProcess::Paused,  ProcessMode::Inverse)  => true,
+            (_, ProcessMode::Manual)                      => false
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum NodeIdentity {
     UniqueName(String),
@@ -132,6 +216,100 @@ impl NodeIdentity {
     }
 }
 
+/// Determines how the `NodeTree` reacts to a `NodeError` returned from a node's hooks.
+/// Either way, the error is logged on behalf of the node that returned it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NodeErrorPolicy {
+    
+    /// The error is logged as a warning and the tree continues to run.
+    #[default]
+    Continue,
+
+    /// The error is logged as a panic and the tree is terminated.
+    Terminate
+}
+
+/// Determines what happens to a node whose `process()` function panicked, if the `NodeTree` has
+/// been set to catch node panics via `NodeTreeBase::set_catch_node_panics()`.
+/// Either way, the panic is logged as a warning on behalf of the node and the frame continues.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NodePanicPolicy {
+
+    /// The node is disabled, meaning that its `process()` function will no longer be called until
+    /// it is re-enabled via `NodeBase::set_disabled()`.
+    #[default]
+    Disable,
+
+    /// The node is queued to be freed at the end of the frame.
+    Free,
+
+    /// The node is left as-is, and will be processed again next frame.
+    Continue
+}
+
+/// A callback which is notified whenever a node in the tree is renamed.
+/// It is given the node's `RID`, along with its old and new absolute paths.
+pub struct RenameObserver(Box<dyn FnMut(RID, NodePath, NodePath)>);
+
+impl fmt::Debug for RenameObserver {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("RenameObserver")
+    }
+}
+
+/// A callback which is notified whenever the tree's status changes.
+/// It is given the old and new statuses.
+pub struct StatusObserver(Box<dyn FnMut(TreeStatus, TreeStatus)>);
+
+impl fmt::Debug for StatusObserver {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("StatusObserver")
+    }
+}
+
+/// A callback which is called once the tree has terminated.
+pub struct TerminateCallback(Box<dyn FnOnce(&mut NodeTreeBase)>);
+
+impl fmt::Debug for TerminateCallback {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("TerminateCallback")
+    }
+}
+
+/// A callback which is scheduled to be called at the end of a frame, via either
+/// `NodeTreeBase::call_deferred()` or `NodeTreeBase::after()`.
+struct ScheduledCall {
+    callback: Box<dyn FnOnce(&mut NodeTreeBase)>,
+    wait:     f32,  // The remaining scaled time until the callback is due.
+    canceled: Rc<Cell<bool>>
+}
+
+impl fmt::Debug for ScheduledCall {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&format!("ScheduledCall {{ wait: {}, canceled: {} }}", self.wait, self.canceled.get()))
+    }
+}
+
+/// A handle to a callback that was scheduled via `NodeTreeBase::call_deferred()` or
+/// `NodeTreeBase::after()`, which can be used to cancel the callback before it is called.
+/// Clones of a handle refer to the same callback.
+#[derive(Debug, Clone)]
+pub struct CancelHandle(Rc<Cell<bool>>);
+
+impl CancelHandle {
+    
+    /// Cancels the callback, so that it is never called.
+    /// Does nothing if the callback has already been called.
+    pub fn cancel(&self) {
+        self.0.set(true);
+    }
+
+    /// Returns whether the callback was canceled.
+    pub fn is_canceled(&self) -> bool {
+        self.0.get()
+    }
+}
+
 /// Cites the reason for while a Node has its termination function called.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum TerminationReason {
@@ -151,12 +329,32 @@ pub enum TerminationReason {
 /// large scale programs or games.
 #[derive(Debug)]
 pub struct NodeTreeBase {
-    logger:     Logger,
-    nodes:      RIDHolder<*mut dyn Node>,
-    identity:   HashMap<RID, NodeIdentity>,
-    singletons: HashMap<String, RID>,
-    status:     TreeStatus,
-    last_frame: Instant
+    logger:        Logger,
+    nodes:         RIDHolder<*mut dyn Node>,
+    identity:      HashMap<RID, NodeIdentity>,
+    singletons:    HashMap<String, RID>,
+    status:        TreeStatus,
+    last_frame:    Instant,
+    input_handled: bool,
+    error_policy:  NodeErrorPolicy,
+    catch_panics:  bool,
+    panic_policy:  NodePanicPolicy,
+    on_renamed:    Vec<RenameObserver>,
+    on_status:     Vec<StatusObserver>,
+    on_terminate:  Vec<TerminateCallback>,
+    free_queue:    Vec<RID>,
+    ready_queue:   Vec<Vec<RID>>,  // Scenes whose ready phase was deferred to the end of the frame.
+    scheduled:     Vec<ScheduledCall>,
+    dependencies:  Vec<(RID, NodePath)>,  // Nodes awaiting another node, along with the path relative to them.
+    seed:          u64,
+    time_scale:    f32,
+    frame:         u64,
+    slow_frame:    Option<Duration>,
+    mode_cache:    Option<Vec<Option<ProcessMode>>>,  // Each node's own process mode indexed by RID, if caching is enabled.
+    structure:     u64,
+    resources:     ResourceCache,
+    references:    HashMap<RID, Weak<()>>,  // The token shared by the live `TpStrong` handles to each node.
+    orphans:       Vec<Vec<RID>>  // Branches removed from their parents that are kept alive by handles, from the top down.
 }
 
 impl NodeTreeBase {
@@ -172,12 +370,32 @@ impl NodeTreeBase {
 
         // Create the NodeTreeBase.
         let node_tree: NodeTreeBase = NodeTreeBase {
-            logger:     Logger::new(logger_verbosity),
+            logger:        Logger::new(logger_verbosity),
             nodes,
-            identity:   HashMap::new(),
-            singletons: HashMap::new(),
-            status:     TreeStatus::Process(TreeProcess::Running),
-            last_frame: Instant::now()
+            identity:      HashMap::new(),
+            singletons:    HashMap::new(),
+            status:        TreeStatus::Process(TreeProcess::Running),
+            last_frame:    Instant::now(),
+            input_handled: false,
+            error_policy:  NodeErrorPolicy::default(),
+            catch_panics:  false,
+            panic_policy:  NodePanicPolicy::default(),
+            on_renamed:    Vec::new(),
+            on_status:     Vec::new(),
+            on_terminate:  Vec::new(),
+            free_queue:    Vec::new(),
+            ready_queue:   Vec::new(),
+            scheduled:     Vec::new(),
+            dependencies:  Vec::new(),
+            seed:          0,
+            time_scale:    1.0,
+            frame:         0,
+            slow_frame:    None,
+            mode_cache:    None,
+            structure:     0,
+            resources:     ResourceCache::new(),
+            references:    HashMap::new(),
+            orphans:       Vec::new()
         };
         
         node_tree
@@ -231,13 +449,118 @@ impl NodeTreeBase {
         });
 
         // Go through the initialization history backwards and run each node's `ready()` function.
-        for rid in initialization_history.into_iter().rev() {
-            let node: &mut dyn Node = unsafe { self.get_node_mut(rid).unwrap_unchecked() };
-            if node.has_just_loaded() {
-                node.loaded();
-                node.mark_as_final();
+        self.notify_children_added(&initialization_history);
+        self.ready_phase(&initialization_history);
+    }
+
+    /// Instances a scene as a child of the root node, returning the `RID` of the scene's root.
+    /// This only returns once every phase of the scene's construction has completed.
+    ///
+    /// # Build Phases
+    /// Every scene that is added to the tree - whether it is the tree's initial scene, or one that is
+    /// added via `add_child()` or `add_children()` - is built in the following order:
+    /// 1. **Enter Tree**: Every node in the scene is wired into the tree from the top down. Each
+    ///    node is given its `RID`, its parent, its owner and a unique name. No hooks are called
+    ///    while the scene is being wired in. Once it is complete, each parent's `child_added()`
+    ///    is called for its new children, in the same top-down order.
+    /// 2. **Ready**: Each node's persistent connections are established, then each node's
+    ///    `loaded()` (if it was loaded from disk) and `ready()` functions are called from the
+    ///    bottom up, meaning that children are always ready before their parents, and that the
+    ///    owner of a scene is always the last of the scene to be ready.
+    ///
+    /// As such, every node of a scene can see every other node of that scene from within its
+    /// `ready()` function.
+    pub fn build_scene<I: Instanceable>(&mut self, scene: I) -> RID {
+        self.build_scene_under(Self::ROOT_RID, scene)
+    }
+
+    /// Instances a scene as a child of the node with the given `RID`, going through each of the
+    /// build phases described in `build_scene()`.
+    pub(crate) fn build_scene_under<I: Instanceable>(&mut self, parent: RID, scene: I) -> RID {
+        let history: Vec<RID> = self.enter_tree_phase(parent, scene);
+        self.ready_phase(&history);
+        history[0]
+    }
+
+    /// Instances a scene as a child of the node with the given `RID` like `build_scene_under()`,
+    /// but defers the scene's ready phase until the end of the current frame.
+    /// The scene's nodes are not processed until they are ready.
+    pub(crate) fn build_scene_under_deferred<I: Instanceable>(&mut self, parent: RID, scene: I) -> RID {
+        let history: Vec<RID> = self.enter_tree_phase(parent, scene);
+        let rid:     RID      = history[0];
+        self.ready_queue.push(history);
+        rid
+    }
+
+    /// Runs the ready phase of every scene whose ready phase was deferred, in the order that they
+    /// were added.
+    fn flush_ready_queue(&mut self) {
+        while !self.ready_queue.is_empty() {
+            let queued: Vec<Vec<RID>> = mem::take(&mut self.ready_queue);
+            for history in queued {
+                self.ready_phase(&history);
+            }
+        }
+    }
+
+    /// Returns whether the node with the given `RID` is still waiting on its deferred `ready()`.
+    pub(crate) fn is_awaiting_ready(&self, rid: RID) -> bool {
+        self.ready_queue.iter().any(|history| history.contains(&rid))
+    }
+
+    /// The wiring phase of a scene's construction.
+    /// Attaches each node of the scene to the tree from the top down without calling any hooks,
+    /// returning the `RID`s of the attached nodes in the order that they were attached.
+    pub(crate) fn enter_tree_phase<I: Instanceable>(&mut self, parent: RID, scene: I) -> Vec<RID> {
+        let mut history: Vec<RID> = Vec::new();
+        let     parent:  *mut dyn Node = self.get_node_mut_raw(parent).expect("Cannot build a scene under an invalid node!");
+
+        scene.iterate(|scene_parent, node, is_owner| {
+            let parent: &mut dyn Node = unsafe { &mut *scene_parent.unwrap_or(parent) };
+            history.push(unsafe { parent.add_child_from_ptr(node, is_owner, true) });
+        });
+        
+        self.notify_children_added(&history);
+        history
+    }
+
+    /// Calls `child_added()` on the parent of each of the given nodes, in the order that they were
+    /// attached.
+    /// This is only called once the whole scene has been attached, so that each parent can see the
+    /// complete subtree of its new child.
+    pub(crate) fn notify_children_added(&mut self, history: &[RID]) {
+        for &rid in history {
+            let Some(parent) = self.get_node(rid).and_then(|node| node.parent_rid()) else {
+                continue;
+            };
+            if let Some(parent) = self.get_node_mut(parent) {
+                parent.child_added(rid);
+            }
+        }
+    }
+
+    /// The ready phase of a scene's construction.
+    /// Runs each node's `loaded()` (if applicable) and `ready()` functions from the bottom up,
+    /// given the `RID`s of the nodes in the order that they were attached.
+    pub(crate) fn ready_phase(&mut self, history: &[RID]) {
+        for &rid in history {
+            if let Some(node) = self.get_node(rid) {
+                node.establish_persistent_connections();
+            }
+        }
+        
+        for &rid in history.iter().rev() {
+            if let Some(node) = self.get_node_mut(rid) {
+                if node.has_just_loaded() {
+                    node.loaded();
+                    unsafe {
+                        node.mark_as_final();
+                    }
+                }
+                
+                let result: Result<(), NodeError> = node.try_ready();
+                self.report_node_error(rid, result);
             }
-            node.ready();
         }
     }
 
@@ -257,6 +580,23 @@ impl NodeTreeBase {
         let delta:   f32      = elapsed.as_secs_f32();
         self.last_frame       = now;
 
+        self.process_with_delta(delta)
+    }
+
+    /// Runs the process behaviour of the Node Tree for a single frame using the given delta time
+    /// rather than the time that has actually passed since the last frame.
+    /// This is useful for deterministic simulations and for testing.
+    /// This returns the `TreeStatus`
+    pub fn process_with_delta(&mut self, delta: f32) -> TreeStatus {
+
+        // Return early if the tree is no longer active.
+        if !self.status.is_active() {
+            return self.status;
+        }
+        
+        let started: Option<Instant> = self.slow_frame.map(|_| Instant::now());
+        self.frame += 1;
+
         // Reset the prior frame's node statuses.
         for node in self.get_nodes_mut(&self.root().top_down(true)) {
             unsafe {
@@ -267,15 +607,129 @@ impl NodeTreeBase {
         // Process the node tree recursively.
         self.process_tail(Self::ROOT_RID, delta, ProcessMode::Pausable);
 
+        // Call any scheduled callbacks that are due, then ready any scenes that were added with a
+        // deferred ready phase during this frame, free any nodes that were queued to be freed or
+        // are no longer referenced, and finally notify any nodes whose dependencies have become
+        // available.
+        self.flush_scheduled(delta * self.time_scale);
+        self.flush_ready_queue();
+        self.flush_free_queue();
+        self.flush_orphans();
+        self.flush_dependencies();
+
+        // Warn about the frame if it went over budget.
+        if let (Some(started), Some(threshold)) = (started, self.slow_frame) {
+            let elapsed: Duration = started.elapsed();
+            if elapsed > threshold {
+                self.logger.post_manual(
+                    SystemCall::Named("NodeTree".to_string()),
+                    Log::Warn(&format!(
+                            "Frame {} took {:.3}ms, exceeding the slow frame threshold of {:.3}ms!",
+                            self.frame, elapsed.as_secs_f64() * 1000.0, threshold.as_secs_f64() * 1000.0
+                    )));
+            }
+        }
+
         // Check the tree's status.
         match self.status {
-            TreeStatus::QueuedTermination(_) => self.status = TreeStatus::Terminating,
-            TreeStatus::Terminating          => self.status = TreeStatus::Terminated,
+            TreeStatus::QueuedTermination(_) => self.set_status(TreeStatus::Terminating),
+            TreeStatus::Terminating          => self.mark_terminated(),
             _                                => ()
         }
         self.status
     }
 
+    /// Repeatedly processes frames with the given delta time until the predicate is satisfied,
+    /// running at most `max_frames` frames.
+    /// Returns whether the predicate was satisfied, or `false` if the frame limit was reached or
+    /// the tree stopped running before that could happen.
+    ///
+    /// # Note
+    /// The predicate is checked before every frame, so no frames are run if it is already
+    /// satisfied.
+    pub fn step_until(&mut self, max_frames: u64, delta: f32, predicate: impl Fn(&NodeTreeBase) -> bool) -> bool {
+        for _ in 0..max_frames {
+            if predicate(self) {
+                return true;
+            }
+            if !self.status.is_active() {
+                return false;
+            }
+            self.process_with_delta(delta);
+        }
+        predicate(self)
+    }
+
+    /// Dispatches an input event through the tree from the top down, calling each node's
+    /// `input()` hook until the event is marked as handled.
+    /// Returns whether a node handled the event.
+    ///
+    /// # Note
+    /// Does nothing if the tree is no longer active.
+    pub fn feed_input(&mut self, event: InputEvent) -> bool {
+        if !self.status.is_active() {
+            return false;
+        }
+
+        self.input_handled = false;
+        for rid in self.root().top_down(true) {
+            if let Some(node) = self.get_node_mut(rid) {
+                node.input(&event);
+            }
+            if self.input_handled {
+                break;
+            }
+        }
+        self.input_handled
+    }
+
+    /// Marks the input event that is currently being dispatched as handled, which prevents it
+    /// from propagating to any further nodes.
+    pub fn set_input_as_handled(&mut self) {
+        self.input_handled = true;
+    }
+
+    /// Advances the tree by a single frame of the given length, in seconds.
+    /// This is the same as `process_with_delta()`, and is intended as the entry point for
+    /// applications whose main loop is owned by something else, such as a windowing library.
+    ///
+    /// # Integration
+    /// The tree never measures time or blocks on its own when driven this way, so the host loop
+    /// is free to decide when frames happen. A typical integration forwards events as they arrive
+    /// and ticks the tree once per redraw:
+    /// ```rust,ignore
+    /// let mut tree: Box<TreeSimple> = TreeSimple::new(scene, LoggerVerbosity::NoDebug);
+    /// let mut last: Instant         = Instant::now();
+    ///
+    /// event_loop.run(move |event, target| match event {
+    ///     Event::WindowEvent { event: WindowEvent::KeyboardInput { event, .. }, .. } => {
+    ///         tree.handle_event(InputEvent::Key { code: event.physical_key as u32, pressed: event.state.is_pressed() });
+    ///     },
+    ///     Event::AboutToWait => {
+    ///         let now: Instant = Instant::now();
+    ///         if tree.tick(now.duration_since(last).as_secs_f32()).has_terminated() {
+    ///             target.exit();
+    ///         }
+    ///         last = now;
+    ///     },
+    ///     _ => ()
+    /// });
+    /// ```
+    ///
+    /// # Note
+    /// Mixing this with `process()` is not recommended, as `process()` measures the time since
+    /// it was last called, and would include the time spent in any ticks in between.
+    pub fn tick(&mut self, delta: f32) -> TreeStatus {
+        self.process_with_delta(delta)
+    }
+
+    /// Passes an event from the host application's event loop to the tree.
+    /// This is the same as `feed_input()`, and returns whether a node handled the event.
+    /// See `tick()` for a typical integration.
+    pub fn handle_event(&mut self, event: InputEvent) -> bool {
+        self.feed_input(event)
+    }
+
     /// Gets a reference to the Root node.
     pub fn root(&self) -> &dyn Node {
         unsafe {
@@ -354,6 +808,25 @@ impl NodeTreeBase {
             .filter_map(|rid| self.nodes.retrieve(*rid).map(|node| unsafe { &mut **node })).collect::<Vec<_>>()
     }
 
+    /// Calls the given closure with a mutable reference to the node with the given `RID` along
+    /// with the tree itself, so that the node may be mutated while also calling methods on the
+    /// tree. Returns the closure's result, or `None` if the `RID` is invalid.
+    /// ```rust, ignore
+    /// tree.with_node(rid, |node, tree| {
+    ///     node.set_name("Player");
+    ///     tree.queue_free(enemy);
+    /// });
+    /// ```
+    ///
+    /// # Note
+    /// The node remains a part of the tree for the duration of the closure, so the closure must
+    /// neither free the node (use `queue_free()` instead) nor obtain a second mutable reference to
+    /// it through the tree.
+    pub fn with_node<R>(&mut self, rid: RID, f: impl FnOnce(&mut dyn Node, &mut NodeTreeBase) -> R) -> Option<R> {
+        let node: *mut dyn Node = self.get_node_mut_raw(rid)?;
+        Some(f(unsafe { &mut *node }, self))
+    }
+
     /// Calls to this function results in the program terminating.
     /// This doesn't terminate the program itself, rather it just queues the program for
     /// self-termination.
@@ -361,62 +834,776 @@ impl NodeTreeBase {
     /// This does nothing if termination has already been queued.
     pub fn queue_termination(&mut self) {
         match self.status {
-            TreeStatus::Process(process) => self.status = TreeStatus::QueuedTermination(process),
+            TreeStatus::Process(process) => self.set_status(TreeStatus::QueuedTermination(process)),
             _                            => ()
         }
     }
 
     /// Immediately terminates the program without running any termination behaviours.
+    /// Callbacks registered via `on_terminate()` are still called.
     pub fn terminate(&mut self) {
-        self.status = TreeStatus::Terminated;
+        self.mark_terminated();
+    }
+
+    /// Registers a callback which is called once the tree's status changes to `Terminated`,
+    /// whether that is after a queued termination or an immediate `terminate()`.
+    /// Callbacks are called in the order that they were registered, and each is only ever called
+    /// once. This is suited for tree-wide teardown, such as saving state or flushing the log.
+    pub fn on_terminate(&mut self, callback: impl FnOnce(&mut NodeTreeBase) + 'static) {
+        self.on_terminate.push(TerminateCallback(Box::new(callback)));
+    }
+
+    /// Schedules a callback to be called at the end of the current frame, after every node has
+    /// been processed. Callbacks are called in the order that they were scheduled, and any that
+    /// are scheduled from within a deferred callback are called at the end of the next frame.
+    ///
+    /// The returned `CancelHandle` can be used to cancel the callback before it is called, such
+    /// as when the node that it refers to has been freed.
+    ///
+    /// # Note
+    /// No node is borrowed while deferred callbacks run, so they are the safe place for structural
+    /// changes that would otherwise invalidate the frame in progress: renaming nodes, adding,
+    /// removing, moving or reparenting children, and freeing nodes. Queued frees are applied after
+    /// every deferred callback has run.
+    ///
+    /// A callback that captures a node's `RID` must not outlive that node, as freed RIDs are handed
+    /// out to new nodes. Either cancel the callback once the node leaves the tree (see
+    /// `NodeBase::on_tree_exit()`), or look the node up by its `NodePath` instead.
+    pub fn call_deferred(&mut self, callback: impl FnOnce(&mut NodeTreeBase) + 'static) -> CancelHandle {
+        self.after(0.0, callback)
+    }
+
+    /// Schedules a callback to be called once the given amount of seconds have passed, as measured
+    /// by the delta time of each frame multiplied by the tree's time scale. The callback is called
+    /// at the end of the first frame by which the time has elapsed, counting the frame in which it
+    /// was scheduled.
+    ///
+    /// The returned `CancelHandle` can be used to cancel the callback before it is called, such
+    /// as when the node that it refers to has been freed.
+    pub fn after(&mut self, seconds: f32, callback: impl FnOnce(&mut NodeTreeBase) + 'static) -> CancelHandle {
+        let canceled: Rc<Cell<bool>> = Rc::new(Cell::new(false));
+        self.scheduled.push(ScheduledCall {
+            callback: Box::new(callback),
+            wait:     seconds,
+            canceled: canceled.clone()
+        });
+        CancelHandle(canceled)
+    }
+
+    /// Advances every scheduled callback by the given scaled delta time, calling those which are
+    /// due and dropping those which were canceled.
+    fn flush_scheduled(&mut self, delta: f32) {
+        let scheduled: Vec<ScheduledCall> = mem::take(&mut self.scheduled);
+        let mut pending: Vec<ScheduledCall> = Vec::new();
+        
+        for mut call in scheduled {
+            if call.canceled.get() {
+                continue;
+            }
+
+            call.wait -= delta;
+            if call.wait <= 0.0 {
+                (call.callback)(self);
+            } else {
+                pending.push(call);
+            }
+        }
+
+        // Callbacks scheduled by the callbacks above are kept for the next frame.
+        pending.append(&mut self.scheduled);
+        self.scheduled = pending;
+    }
+
+    /// Sets the tree's status to `Terminated` and calls each of the termination callbacks.
+    fn mark_terminated(&mut self) {
+        self.set_status(TreeStatus::Terminated);
+        while !self.on_terminate.is_empty() {
+            let callbacks: Vec<TerminateCallback> = mem::take(&mut self.on_terminate);
+            for TerminateCallback(callback) in callbacks {
+                callback(self);
+            }
+        }
+    }
+
+    /// Sets how the tree reacts to a `NodeError` returned from a node's hooks.
+    pub fn set_error_policy(&mut self, policy: NodeErrorPolicy) {
+        self.error_policy = policy;
+    }
+
+    /// Gets how the tree reacts to a `NodeError` returned from a node's hooks.
+    pub fn error_policy(&self) -> NodeErrorPolicy {
+        self.error_policy
+    }
+
+    /// Queues the node with the given `RID` to be freed at the end of the current frame.
+    /// Queuing the same node more than once has no additional effect.
+    pub fn queue_free(&mut self, rid: RID) {
+        if !self.free_queue.contains(&rid) {
+            self.free_queue.push(rid);
+        }
+    }
+
+    /// Gets the number of references to the node with the given `RID`, which is the number of live
+    /// `TpStrong` handles to it, plus one if it is attached to a parent.
+    /// Tree pointers (`Tp<T>` and `TpDyn`) do not count as references.
+    pub fn reference_count(&self, rid: RID) -> usize {
+        let parent: bool = self.get_node(rid).is_some_and(|node| node.parent_rid().is_some());
+        self.strong_count(rid) + parent as usize
+    }
+
+    /// Gets the number of live `TpStrong` handles to the node with the given `RID`.
+    fn strong_count(&self, rid: RID) -> usize {
+        self.references.get(&rid).map(Weak::strong_count).unwrap_or(0)
+    }
+
+    /// Gets the token that is shared by every `TpStrong` handle to the node with the given `RID`,
+    /// creating a new one if no handles are alive.
+    pub(crate) fn strong_token(&mut self, rid: RID) -> Rc<()> {
+        if let Some(token) = self.references.get(&rid).and_then(Weak::upgrade) {
+            return token;
+        }
+
+        let token: Rc<()> = Rc::new(());
+        self.references.insert(rid, Rc::downgrade(&token));
+        token
+    }
+
+    /// Returns whether the given token belongs to the handles of the node with the given `RID`.
+    /// This is false for the handles of a freed node, even if its `RID` was handed out again.
+    pub(crate) fn holds_strong_token(&self, rid: RID, token: &Rc<()>) -> bool {
+        self.references.get(&rid).is_some_and(|weak| std::ptr::eq(weak.as_ptr(), Rc::as_ptr(token)))
+    }
+
+    /// Keeps a branch that was just removed from its parent alive for as long as its root has any
+    /// `TpStrong` handles, rather than freeing it.
+    /// Returns false if the branch has no handles, in which case it should be freed as usual.
+    pub(crate) fn adopt_orphan(&mut self, branch: Vec<RID>) -> bool {
+        if self.strong_count(branch[0]) == 0 {
+            return false;
+        }
+        self.orphans.push(branch);
+        true
+    }
+
+    /// Frees every orphaned branch whose root no longer has any `TpStrong` handles.
+    fn flush_orphans(&mut self) {
+        let orphans: Vec<Vec<RID>> = mem::take(&mut self.orphans);
+        for branch in orphans {
+            if self.strong_count(branch[0]) > 0 {
+                self.orphans.push(branch);
+                continue;
+            }
+
+            for rid in branch {
+                unsafe {
+                    self.unregister_node(rid);
+                }
+            }
+        }
+    }
+
+    /// Registers the node with the given `RID` to have its `dependency_ready()` hook called once
+    /// the given path resolves. Relative paths are resolved from the awaiting node.
+    /// See `NodeBase::await_node()`.
+    pub fn await_node(&mut self, rid: RID, path: NodePath) {
+        self.dependencies.push((rid, path));
+    }
+
+    /// Expands the `Placeholder` node with the given `RID` into the scene that it holds.
+    /// The placeholder is removed, and the scene is added in its place under the same name and at
+    /// the same index among its siblings, with its nodes readied immediately.
+    /// Returns the `RID` of the scene's root node, or `None` if the `RID` does not reference a
+    /// placeholder that has yet to be expanded or if the placeholder is the root of the tree.
+    ///
+    /// # Note
+    /// This must not be called from within the placeholder itself, as it is removed from the tree.
+    /// Use `NodeBase::instantiate_placeholder()` instead, which expands the placeholder at the end
+    /// of the frame.
+    pub fn instantiate_placeholder(&mut self, rid: RID) -> Option<RID> {
+        let placeholder: &mut dyn Node = self.get_node_mut(rid)?;
+        let parent_rid:  RID           = placeholder.parent_rid()?;
+        let name:        String        = placeholder.name().to_string();
+        let mut scene:   NodeScene     = placeholder.as_any_mut().downcast_mut::<Placeholder>()?.take_scene()?;
+        scene.set_root_name(&name);
+
+        let parent: &mut dyn Node = unsafe { self.get_node_mut(parent_rid).unwrap_unchecked() };
+        let index:  usize         = unsafe { parent.children_rids().iter().position(|&child| child == rid).unwrap_unchecked() };
+        parent.remove_child(&name);
+
+        let scene_rid: RID = self.build_scene_under(parent_rid, scene);
+        unsafe { self.get_node_mut(parent_rid).unwrap_unchecked() }.move_child(&name, index);
+        Some(scene_rid)
+    }
+
+    /// Notifies every awaiting node whose dependency now resolves.
+    /// Dependencies of nodes that are no longer in the tree are dropped.
+    fn flush_dependencies(&mut self) {
+        let awaiting: Vec<(RID, NodePath)> = mem::take(&mut self.dependencies);
+        for (rid, path) in awaiting {
+            if self.get_node(rid).is_none() {
+                continue;
+            }
+            
+            if self.get_node_rid(path.clone(), Some(rid)).is_some() {
+                unsafe { self.get_node_mut(rid).unwrap_unchecked() }.dependency_ready(path);
+            } else {
+                self.dependencies.push((rid, path));
+            }
+        }
+    }
+
+    /// Queues every node in the given group to be freed at the end of the current frame.
+    /// See `NodeBase::add_to_group()`.
+    pub fn free_group(&mut self, group: &str) {
+        self.free_where(|node| node.is_in_group(group));
+    }
+
+    /// Queues every node that matches the given predicate to be freed at the end of the current
+    /// frame.
+    ///
+    /// # Note
+    /// Matching nodes are collected before anything is queued, so the predicate always sees the
+    /// tree as it was at the time of the call.
+    pub fn free_where(&mut self, predicate: impl Fn(&dyn Node) -> bool) {
+        let matching: Vec<RID> = self.root().top_down(true).into_iter()
+            .filter(|&rid| self.get_node(rid).map(&predicate).unwrap_or(false))
+            .collect();
+
+        for rid in matching {
+            self.queue_free(rid);
+        }
+    }
+
+    /// Gets the `RID`s of every node in the given group, in top-down order.
+    pub fn nodes_in_group(&self, group: &str) -> Vec<RID> {
+        self.root().top_down(true).into_iter()
+            .filter(|&rid| self.get_node(rid).map(|node| node.is_in_group(group)).unwrap_or(false))
+            .collect()
+    }
+
+    /// Removes every node from the tree except for the root, such as when transitioning between
+    /// levels. Every removed node has its `terminal()` function called with the reason
+    /// `RemovedAsChild`, from the top down.
+    ///
+    /// # Note
+    /// Any singletons, queued frees, deferred readies and pending dependencies referring to the
+    /// removed nodes are dropped, as is every scheduled callback, since freed RIDs are handed out
+    /// again to new nodes. The root node and the tree's status are left untouched. The tree can be
+    /// immediately reused, such as by adding a new scene to the root.
+    pub fn clear(&mut self) {
+        let names: Vec<String> = self.root().children().iter().map(|child| child.name().to_string()).collect();
+        for name in names {
+            self.root_mut().remove_child(&name);
+        }
+
+        self.free_queue.retain(|&rid| rid == Self::ROOT_RID);
+        self.ready_queue.iter_mut().for_each(|history| history.retain(|&rid| rid == Self::ROOT_RID));
+        self.ready_queue.retain(|history| !history.is_empty());
+        self.scheduled.clear();
+        self.dependencies.retain(|&(rid, _)| rid == Self::ROOT_RID);
+    }
+
+    /// Replaces the entire tree with a new scene, such as when switching from a main menu to the
+    /// game itself. Every node in the tree has its `terminal()` function called with the reason
+    /// `Freed` from the top down, after which the new scene is installed as the root and built as
+    /// it would be on the tree's construction.
+    ///
+    /// # Note
+    /// Every singleton, queued free, and deferred ready of the old tree is dropped, and any branches
+    /// kept alive by `TpStrong` handles after being removed are freed. The tree's status and any
+    /// callbacks registered on the tree itself are left untouched.
+    /// As every node of the old tree is dropped, this must not be called from within a node's
+    /// hooks. Call this between frames instead.
+    pub fn replace_root<I: Instanceable>(&mut self, scene: I) {
+        let outer: *mut dyn NodeTree = self.root_mut().tree_mut().expect("The root node is not connected to its NodeTree!");
+        let nodes: Vec<RID>          = self.root().top_down(true);
+
+        for &rid in &nodes {
+            let result: Result<(), NodeError> = unsafe { self.get_node_mut(rid).unwrap_unchecked() }.try_terminal(TerminationReason::Freed);
+            self.report_node_error(rid, result);
+        }
+        for &rid in &nodes {
+            unsafe { self.get_node(rid).unwrap_unchecked() }.exit_tree();
+        }
+
+        // Orphaned branches belong to the old tree as well, regardless of any handles to them.
+        for rid in mem::take(&mut self.orphans).into_iter().flatten() {
+            unsafe {
+                self.unregister_node(rid);
+            }
+        }
+
+        // Unregister the root last so that its RID is the first to be handed out again.
+        for &rid in nodes.iter().rev() {
+            unsafe {
+                self.unregister_node(rid);
+            }
+        }
+        self.free_queue.clear();
+        self.ready_queue.clear();
+        self.scheduled.clear();
+        self.dependencies.clear();
+
+        unsafe {
+            self.initialize(outer, scene);
+        }
+    }
+
+    /// Renames the root node of the tree, which changes the absolute path of every node.
+    pub fn rename_root(&mut self, name: &str) {
+        self.root_mut().set_name(name);
+    }
+
+    /// Frees every queued node.
+    /// Nodes which have a queued ancestor are skipped, as they are freed along with the ancestor's
+    /// subtree.
+    fn flush_free_queue(&mut self) {
+        let queued: Vec<RID> = mem::take(&mut self.free_queue);
+        let roots:  Vec<RID> = queued.iter()
+            .copied()
+            .filter(|&rid| {
+                let mut parent: Option<RID> = self.get_node(rid).and_then(|node| node.parent_rid());
+                while let Some(ancestor) = parent {
+                    if queued.contains(&ancestor) {
+                        return false;
+                    }
+                    parent = self.get_node(ancestor).and_then(|node| node.parent_rid());
+                }
+                true
+            })
+            .collect();
+
+        for rid in roots {
+            if let Some(node) = self.get_node_mut(rid) {
+                node.free();
+            }
+        }
+    }
+
+    /// Sets the threshold above which a frame is considered slow, in which case a warning is
+    /// posted to the log with the frame's number and duration. Passing `None` disables this.
+    /// The duration of a frame includes the processing of every node, along with the freeing of
+    /// any nodes queued to be freed.
+    pub fn set_slow_frame_threshold(&mut self, threshold: Option<Duration>) {
+        self.slow_frame = threshold;
+    }
+
+    /// Gets the threshold above which a frame is considered slow, if any.
+    pub fn slow_frame_threshold(&self) -> Option<Duration> {
+        self.slow_frame
+    }
+
+    /// Gets the number of frames that the tree has processed so far.
+    pub fn frame_count(&self) -> u64 {
+        self.frame
+    }
+
+    /// Sets the scale that is applied to the delta time passed to every node's `process()`
+    /// function, where `1.0` is real time. This can be used for slow-motion (below `1.0`),
+    /// fast-forwarding (above `1.0`), or freezing time (`0.0`) without pausing the tree.
+    /// Nodes may opt out of this via `NodeBase::set_ignores_time_scale()`, such as for UI that
+    /// should keep running in real time.
+    ///
+    /// # Note
+    /// Only the delta time is scaled; the number of frames processed stays the same. Deltas
+    /// passed explicitly to `process_with_delta()` are scaled as well, while deltas passed to
+    /// `NodeBase::process_now()` are not.
+    ///
+    /// # Panics
+    /// Panics if the scale is negative or not a number.
+    pub fn set_time_scale(&mut self, scale: f32) {
+        if scale.is_nan() || scale < 0.0 {
+            panic!("The time scale must be a non-negative number, but got {scale}!");
+        }
+        self.time_scale = scale;
+    }
+
+    /// Gets the scale that is applied to the delta time passed to every node's `process()`
+    /// function.
+    pub fn time_scale(&self) -> f32 {
+        self.time_scale
+    }
+
+    /// Gets statistics on the tree's node `RID`s, which may be used to monitor how fragmented the
+    /// tree has become after many nodes have been added and freed.
+    /// Please see `RidStats` on why `RID`s are never compacted.
+    pub fn rid_stats(&self) -> RidStats {
+        self.nodes.stats()
+    }
+
+    /// Loads the scene at the given path via the tree's `ResourceCache`, so that a scene which is
+    /// instanced many times is only read and parsed once.
+    /// A cached scene is reloaded if its file has been modified since it was cached.
+    /// Returns `Err` if the scene could not be loaded.
+    /// ```rust,ignore
+    /// let scene: Rc<NodeScene> = self.tree_mut().unwrap().load_scene_cached(Path::new("enemy.scn"))?;
+    /// self.add_child(NodeScene::clone(&scene));
+    /// ```
+    ///
+    /// # Note
+    /// The tree, and therefore its cache, is confined to the thread that it runs on.
+    pub fn load_scene_cached(&mut self, path: &Path) -> Result<Rc<NodeScene>, String> {
+        self.resources.load_scene(path)
+    }
+
+    /// Removes every scene from the tree's `ResourceCache`, so that they are loaded from disk
+    /// again the next time that they are requested.
+    pub fn clear_cache(&mut self) {
+        self.resources.clear();
+    }
+
+    /// Gets the tree's `ResourceCache`, which holds every scene loaded via `load_scene_cached()`.
+    pub fn resource_cache(&self) -> &ResourceCache {
+        &self.resources
+    }
+
+    /// Gets the tree's structure version, which changes whenever a node is added, removed, or
+    /// renamed. Anything derived from the layout of the tree (such as a resolved path) is
+    /// still valid as long as this has not changed.
+    pub fn structure_version(&self) -> u64 {
+        self.structure
+    }
+
+    /// Marks the structure of the tree as having changed, invalidating any cached lookups.
+    pub(crate) fn mark_structure_changed(&mut self) {
+        self.structure = self.structure.wrapping_add(1);
+        if let Some(cache) = &mut self.mode_cache {
+            cache.clear();
+        }
+    }
+
+    /// Sets the master seed which every node's random number stream is derived from.
+    /// This restarts the stream of every node currently in the tree.
+    pub fn set_seed(&mut self, seed: u64) {
+        self.seed = seed;
+        for node in self.nodes.iter() {
+            unsafe {
+                (**node).reset_rng();
+            }
+        }
+    }
+
+    /// Gets the master seed which every node's random number stream is derived from.
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Sets whether a panic within a node's `process()` function is caught, rather than unwinding
+    /// through the entire tree. A caught panic is logged with the node's path, after which the
+    /// node is dealt with according to the tree's `NodePanicPolicy`, and the rest of the tree
+    /// continues to be processed. This is disabled by default.
+    ///
+    /// # Soundness
+    /// A node that panicked may have been left in an inconsistent state partway through an
+    /// update, which is why the default policy disables it. Only the `process()` function of
+    /// each node is guarded; all other hooks still unwind. Note that the panic hook will still
+    /// print the panic message to `stderr` as normal.
+    pub fn set_catch_node_panics(&mut self, catch: bool) {
+        self.catch_panics = catch;
+    }
+
+    /// Gets whether a panic within a node's `process()` function is caught.
+    pub fn catches_node_panics(&self) -> bool {
+        self.catch_panics
+    }
+
+    /// Sets what happens to a node whose `process()` function panicked, if panics are caught.
+    pub fn set_node_panic_policy(&mut self, policy: NodePanicPolicy) {
+        self.panic_policy = policy;
+    }
+
+    /// Gets what happens to a node whose `process()` function panicked, if panics are caught.
+    pub fn node_panic_policy(&self) -> NodePanicPolicy {
+        self.panic_policy
+    }
+
+    /// Logs a panic that was caught within a node's hook, before dealing with the node according
+    /// to the tree's `NodePanicPolicy`.
+    fn report_node_panic(&mut self, rid: RID, msg: &str) {
+        let policy: NodePanicPolicy = self.panic_policy;
+        let Some(node) = self.get_node_mut(rid) else {
+            return;
+        };
+
+        node.post(Log::Warn(&format!("Caught a panic in `process()`: {msg}")));
+        match policy {
+            NodePanicPolicy::Disable  => node.set_disabled(true),
+            NodePanicPolicy::Free     => self.queue_free(rid),
+            NodePanicPolicy::Continue => ()
+        }
+    }
+
+    /// Logs the error of a failed hook on behalf of the node with the given `RID`, and then
+    /// either continues or terminates the tree depending on the tree's `NodeErrorPolicy`.
+    /// Does nothing if the hook succeeded.
+    pub(crate) fn report_node_error(&mut self, rid: RID, result: Result<(), NodeError>) {
+        let Err(err) = result else {
+            return;
+        };
+
+        let msg: String = format!("A hook returned an error: {err}");
+        if let Some(node) = self.get_node(rid) {
+            match self.error_policy {
+                NodeErrorPolicy::Continue  => node.post(Log::Warn(&msg)),
+                NodeErrorPolicy::Terminate => node.post(Log::Panic(&msg))
+            }
+        }
+    }
+
+    /// Registers a callback which is called whenever a node in the tree is renamed via
+    /// `NodeBase::set_name()`.
+    /// The callback is given the node's `RID`, along with its old and new absolute paths, which
+    /// allows any system that caches paths to keep them up to date.
+    pub fn on_node_renamed(&mut self, observer: impl FnMut(RID, NodePath, NodePath) + 'static) {
+        self.on_renamed.push(RenameObserver(Box::new(observer)));
+    }
+
+    /// Notifies every rename observer that the node with the given `RID` was renamed.
+    pub(crate) fn notify_renamed(&mut self, rid: RID, old_path: NodePath, new_path: NodePath) {
+        self.mark_structure_changed();
+        
+        // Take the observers out so that they may register further observers without aliasing.
+        let mut observers: Vec<RenameObserver> = mem::take(&mut self.on_renamed);
+        for RenameObserver(observer) in &mut observers {
+            observer(rid, old_path.clone(), new_path.clone());
+        }
+        
+        observers.append(&mut self.on_renamed);
+        self.on_renamed = observers;
+    }
+
+    /// Registers a callback which is called whenever the tree's status changes, including when
+    /// the tree is paused or unpaused.
+    /// The callback is given the old and new statuses, in that order. Setting the status to the
+    /// one that it already is, such as by pausing an already paused tree, does not count as a
+    /// change.
+    pub fn on_status_change(&mut self, observer: impl FnMut(TreeStatus, TreeStatus) + 'static) {
+        self.on_status.push(StatusObserver(Box::new(observer)));
+    }
+
+    /// Sets the tree's status and notifies every status observer if it changed.
+    fn set_status(&mut self, status: TreeStatus) {
+        let old: TreeStatus = mem::replace(&mut self.status, status);
+        if old == status {
+            return;
+        }
+
+        for StatusObserver(observer) in &mut self.on_status {
+            observer(old, status);
+        }
+    }
+
+    /// Pauses the tree, which affects which nodes are processed depending on their `ProcessMode`.
+    /// # Note
+    /// This does nothing if the tree is terminating or has terminated.
+    pub fn pause(&mut self) {
+        match self.status {
+            TreeStatus::Process(_)           => self.set_status(TreeStatus::Process(TreeProcess::Paused)),
+            TreeStatus::QueuedTermination(_) => self.set_status(TreeStatus::QueuedTermination(TreeProcess::Paused)),
+            _                                => ()
+        }
+    }
+
+    /// Unpauses the tree.
+    /// # Note
+    /// This does nothing if the tree is terminating or has terminated.
+    pub fn unpause(&mut self) {
+        match self.status {
+            TreeStatus::Process(_)           => self.set_status(TreeStatus::Process(TreeProcess::Running)),
+            TreeStatus::QueuedTermination(_) => self.set_status(TreeStatus::QueuedTermination(TreeProcess::Running)),
+            _                                => ()
+        }
+    }
+
+    /// Determines if the tree is currently paused.
+    pub fn is_paused(&self) -> bool {
+        match self.status {
+            TreeStatus::Process(process) | TreeStatus::QueuedTermination(process) => process == TreeProcess::Paused,
+            _                                                                     => false
+        }
+    }
+
+    /// Gets the tree's current status.
+    pub fn status(&self) -> TreeStatus {
+        self.status
+    }
+
+    /// Resolves the process mode of a node by walking up its ancestors until a process mode other
+    /// than `Inherit` is found. `Manual` ancestors are skipped over, as they are not inherited.
+    /// A root node with the `Inherit` process mode resolves to `Pausable`.
+    /// Returns `None` if the `RID` is invalid.
+    pub fn resolve_process_mode(&self, rid: RID) -> Option<ProcessMode> {
+        let mut node: &dyn Node = self.get_node(rid)?;
+        if Self::process_mode_of(node) == ProcessMode::Manual {
+            return Some(ProcessMode::Manual);
+        }
+
+        loop {
+            match Self::process_mode_of(node) {
+                ProcessMode::Inherit | ProcessMode::Manual => match node.parent_dyn().to_option() {
+                    Some(parent) => node = self.get_node(parent.rid())?,
+                    None         => return Some(ProcessMode::Pausable)
+                },
+                process_mode => return Some(process_mode)
+            }
+        }
+    }
+
+    /// Gets the process mode of a node, taking its process mode override into account.
+    fn process_mode_of(node: &dyn Node) -> ProcessMode {
+        node.process_mode_override().unwrap_or_else(|| node.process_mode())
+    }
+
+    /// Sets whether each node's own process mode is cached between frames, rather than calling
+    /// its `process_mode()` function every frame. This is disabled by default, as the lookup
+    /// costs about as much as calling a `process_mode()` function that returns a constant, even on
+    /// trees of tens of thousands of nodes. It is only worth enabling if some nodes compute their
+    /// process mode in a way that is expensive.
+    /// The cache is invalidated whenever the structure of the tree changes or a node's process
+    /// mode override is set.
+    ///
+    /// # Note
+    /// Only enable this if the `process_mode()` function of each node always returns the same
+    /// value, as otherwise changes to it will go unnoticed.
+    pub fn set_process_mode_caching(&mut self, enabled: bool) {
+        self.mode_cache = if enabled { Some(Vec::new()) } else { None };
+    }
+
+    /// Returns whether the process modes of nodes are cached.
+    /// See `set_process_mode_caching()`.
+    pub fn is_caching_process_modes(&self) -> bool {
+        self.mode_cache.is_some()
+    }
+
+    /// Removes the cached process mode of the node with the given `RID`, if there is one.
+    pub(crate) fn invalidate_process_mode(&mut self, rid: RID) {
+        if let Some(entry) = self.mode_cache.as_mut().and_then(|cache| cache.get_mut(rid as usize)) {
+            *entry = None;
+        }
+    }
+
+    /// Gets the process mode of the node with the given `RID`, going through the cache if it is
+    /// enabled.
+    fn cached_process_mode_of(&mut self, rid: RID) -> ProcessMode {
+        let index: usize = rid as usize;
+        if let Some(mode) = self.mode_cache.as_ref().and_then(|cache| cache.get(index).copied().flatten()) {
+            return mode;
+        }
+        
+        let mode: ProcessMode = Self::process_mode_of(self.get_node(rid).unwrap());
+        if let Some(cache) = &mut self.mode_cache {
+            if cache.len() <= index {
+                cache.resize(index + 1, None);
+            }
+            cache[index] = Some(mode);
+        }
+        mode
+    }
+
+    /// Runs the `process()` function of the node with the given `RID` immediately, regardless of
+    /// its process mode or the tree's paused state. This is intended for nodes with the `Manual`
+    /// process mode. Errors returned from the hook are reported as they would be during a normal
+    /// frame, and disabled nodes are skipped.
+    /// Returns `false` if the `RID` is invalid.
+    pub fn process_node(&mut self, rid: RID, delta: f32) -> bool {
+        let Some(node) = self.get_node_mut(rid) else {
+            return false;
+        };
+        if node.is_disabled() {
+            return true;
+        }
+
+        let result: Result<(), NodeError> = node.try_process(delta);
+        self.report_node_error(rid, result);
+        true
     }
 
     /// The recursive tail-end of the process function which traverses down the node tree.
     fn process_tail(&mut self, node_rid: RID, delta: f32, inherited_process_mode: ProcessMode) {
-        let status: TreeStatus    = self.status;
-        let node:   &mut dyn Node = self.get_node_mut(node_rid).unwrap();
+        let mut process_mode:  ProcessMode  = self.cached_process_mode_of(node_rid);
+        let     process_order: ProcessOrder = self.get_node(node_rid).unwrap().process_order();
         
         // Determine the process mode.
-        let mut process_mode: ProcessMode = node.process_mode();
         if process_mode == ProcessMode::Inherit {
             process_mode = inherited_process_mode;
         }
+
+        // The children are gathered before the node is processed, so that children which are added
+        // during the node's `process()` are first processed in the next frame regardless of the
+        // process order. Any reordering of the existing children is still respected.
+        let mut children: Vec<RID> = self.get_node(node_rid).unwrap().children_rids().to_vec();
+        if process_order == ProcessOrder::ParentFirst {
+            self.process_single(node_rid, delta, process_mode);
+            if self.status == TreeStatus::Terminated {
+                return;
+            }
+
+            let current: Vec<RID> = self.get_node(node_rid).map(|node| node.children_rids().to_vec()).unwrap_or_default();
+            if current != children {
+                let existing: HashSet<RID> = children.into_iter().collect();
+                children = current.into_iter().filter(|child| existing.contains(child)).collect();
+            }
+        }
+
+        // Go through each of the children and process them, perpetuating the recursive cycle.
+        // Manual nodes do not pass their process mode down to their children.
+        let child_mode: ProcessMode = if process_mode == ProcessMode::Manual { inherited_process_mode } else { process_mode };
+        for child_node in children {
+            
+            // Skip any children that were removed or freed while their parent or siblings were processed.
+            if self.get_node(child_node).and_then(|child| child.parent_rid()) != Some(node_rid) {
+                continue;
+            }
+            
+            self.process_tail(child_node, delta, child_mode);
+            if self.status == TreeStatus::Terminated {
+                return;
+            }
+        }
+
+        if process_order == ProcessOrder::ChildrenFirst {
+            self.process_single(node_rid, delta, process_mode);
+        }
+    }
+
+    /// Processes a single node with its resolved process mode, without touching its children.
+    fn process_single(&mut self, node_rid: RID, delta: f32, process_mode: ProcessMode) {
+        let     status:       TreeStatus     = self.status;
+        let     catch_panics: bool           = self.catch_panics;
+        let     time_scale:   f32            = self.time_scale;
+        let     awaiting:     bool           = !self.ready_queue.is_empty() && self.is_awaiting_ready(node_rid);
+        let mut caught:       Option<String> = None;
+        let     node:         &mut dyn Node  = self.get_node_mut(node_rid).unwrap();
+        let     node_delta:   f32            = if node.ignores_time_scale() { delta } else { delta * time_scale };
         
         // Depending on the tree's status and the node's process mode, abide by the processing
         // rules.
-        match status {
+        let result: Result<(), NodeError> = match status {
             TreeStatus::Process(process) | TreeStatus::QueuedTermination(process) => {
-                match process {
-                    TreeProcess::Running => {
-                        match process_mode {
-                            ProcessMode::Inherit  => panic!("Inherited process mode not set!"),
-                            ProcessMode::Always   => node.process(delta),
-                            ProcessMode::Pausable => node.process(delta),
-                            ProcessMode::Inverse  => ()
-                        }
-                    },
-
-                    TreeProcess::Paused => {
-                        match process_mode {
-                            ProcessMode::Inherit  => panic!("Inherited process mode not set!"),
-                            ProcessMode::Always   => node.process(delta),
-                            ProcessMode::Pausable => (),
-                            ProcessMode::Inverse  => node.process(delta)
+                if awaiting || !process.allows(process_mode) || node.is_disabled() {
+                    Ok(())
+                } else if catch_panics {
+                    match panic::catch_unwind(AssertUnwindSafe(|| node.try_process(node_delta))) {
+                        Ok(result)   => result,
+                        Err(payload) => {
+                            caught = Some(payload.downcast_ref::<&str>().map(|msg| msg.to_string())
+                                .or_else(|| payload.downcast_ref::<String>().cloned())
+                                .unwrap_or_else(|| "unknown panic".to_string()));
+                            Ok(())
                         }
                     }
+                } else {
+                    node.try_process(node_delta)
                 }
             }
             
-            TreeStatus::Terminating => node.terminal(TerminationReason::TreeExit),
-            TreeStatus::Terminated  => ()
-        }
-
-        // Go through each of the children and process them, perpetuating the recursive cycle.
-        for child_node in node.children().into_iter().map(|c| c.rid()).collect::<Vec<_>>() {
-            self.process_tail(child_node, delta, process_mode);
-            if self.status == TreeStatus::Terminated {
-                break;
-            }
+            TreeStatus::Terminating => node.try_terminal(TerminationReason::TreeExit),
+            TreeStatus::Terminated  => Ok(())
+        };
+        
+        self.report_node_error(node_rid, result);
+        if let Some(msg) = caught {
+            self.report_node_panic(node_rid, &msg);
         }
     }
 
@@ -431,6 +1618,7 @@ impl NodeTreeBase {
     pub unsafe fn register_node(&mut self, node: *mut dyn Node) -> RID {
         let rid: RID = self.nodes.push(node);
         self.identity.insert(rid, NodeIdentity::NodePath);
+        self.mark_structure_changed();
         rid
     }
 
@@ -457,6 +1645,8 @@ impl NodeTreeBase {
         // Unregister this node from the tree.
         let node: Option<*mut dyn Node> = self.nodes.take(rid);
         self.identity.remove(&rid);
+        self.references.remove(&rid);
+        self.mark_structure_changed();
         node.map(|ptr| Box::from_raw(ptr))
     }
     
@@ -477,12 +1667,62 @@ impl NodeTreeBase {
         Some(true)
     }
 
+    /// Gets a `Tp<T>` pointer to the singleton registered under the given name.
+    /// Returns `None` if there is no such singleton, or if it is not of the type `T`.
+    ///
+    /// # Note
+    /// Any failures that occur through the returned pointer are reported on behalf of the root
+    /// node.
+    pub fn get_singleton<T: Node>(&self, name: &str) -> TreeOption<'_, Tp<'_, T>> {
+        let tree: *mut dyn NodeTree = self.root().tree().expect("The root node is not connected to its NodeTree!").as_dyn_raw() as *mut dyn NodeTree;
+        unsafe {
+            match self.singletons.get(name) {
+                Some(&rid) => Tp::new(tree, Self::ROOT_RID, rid).ok(),
+                None       => TreeOption::new(tree, Self::ROOT_RID, None)
+            }
+        }
+    }
+
+    /// Gets a `Tp<T>` pointer to the singleton registered under the given name.
+    /// See `get_singleton()` for a non-panicking version.
+    ///
+    /// # Panics
+    /// Panics if there is no such singleton, or if it is not of the type `T`.
+    pub fn expect_singleton<T: Node>(&self, name: &str) -> Tp<'_, T> {
+        self.get_singleton(name).expect(&format!("Expected a singleton named \"{}\" of type `{}`", name, std::any::type_name::<T>()))
+    }
+
     /// Gets a node's RID via either an absolute path or a name if it is valid, or None if it is
     /// not.
     pub fn get_node_rid<P: NodeGetter>(&self, absolute_path: P, caller: Option<RID>) -> Option<RID> {
         absolute_path.get_from(self, caller)
     }
 
+    /// Gets the absolute path of the node with the given `RID` by walking up through its parents.
+    /// Returns `None` if the `RID` does not reference a node within this tree.
+    pub fn path_of_rid(&self, rid: RID) -> Option<NodePath> {
+        let mut names: Vec<&str>         = Vec::new();
+        let mut node:  Option<&dyn Node> = Some(self.get_node(rid)?);
+
+        while let Some(current) = node {
+            names.push(current.name());
+            node = current.parent_rid().and_then(|parent| self.get_node(parent));
+        }
+
+        let mut path: NodePath = NodePath::new_abs();
+        for name in names.into_iter().rev() {
+            path.push(name);
+        }
+        Some(path)
+    }
+
+    /// Gets the `RID` of the node at the given path.
+    /// Relative paths are resolved from the root node.
+    /// Returns `None` if no node exists at the given path.
+    pub fn rid_of_path(&self, path: &NodePath) -> Option<RID> {
+        path.get_from(self, Some(Self::ROOT_RID))
+    }
+
     /// Gets the node's identity.
     /// The node's identity determines if the Node is accessible directly by name, or if it
     /// requires a NodePath to access.
@@ -501,6 +1741,12 @@ impl NodeTreeBase {
         self.logger.set_default_footer_on_panic(msg);
     }
 
+    /// Replaces the default crash report that is printed upon a panic with a custom handler,
+    /// which is given the context of the panic.
+    pub fn set_panic_handler(&mut self, handler: impl Fn(&PanicContext) + 'static) {
+        self.logger.set_panic_handler(handler);
+    }
+
     /// Posts a new message to the log.
     pub fn post(&mut self, calling: RID, log: Log) {
         let ptr: *mut NodeTreeBase = self;
@@ -515,6 +1761,124 @@ impl NodeTreeBase {
     pub fn get_log(&self) -> &str {
         self.logger.to_str()
     }
+
+    /// Counts the logs that were posted to the tree's log by their level.
+    /// The counts are cumulative over the lifetime of the tree, and do not include logs that were
+    /// filtered out by the tree's `LoggerVerbosity`.
+    pub fn status_summary(&self) -> LogSummary {
+        self.logger.summary()
+    }
+
+    /// Exports every log at or above the given level as a string, which is useful for writing
+    /// filtered logs to a file. The logs are filtered by their recorded level rather than by their
+    /// text.
+    /// If `color` is set, each log is wrapped in the ANSI colour codes used for the console;
+    /// otherwise the output is plain text.
+    pub fn export_log(&self, min_level: LogLevel, color: bool) -> String {
+        self.logger.export(min_level, color)
+    }
+
+    /// Lazily iterates over every node in the tree in breadth-first order, starting from the root.
+    pub fn iter_bfs(&self) -> BreadthFirst<'_> {
+        self.iter_bfs_from(Self::ROOT_RID)
+    }
+
+    /// Lazily iterates over the node with the given `RID` and all of its descendants in
+    /// breadth-first order.
+    pub fn iter_bfs_from(&self, rid: RID) -> BreadthFirst<'_> {
+        BreadthFirst {
+            tree:  self,
+            queue: VecDeque::from([rid])
+        }
+    }
+
+    /// Gets every node in the tree which satisfies the given predicate, in breadth-first order.
+    pub fn find_nodes(&self, predicate: impl Fn(&dyn Node) -> bool) -> Vec<TpDyn<'_>> {
+        let tree: &dyn NodeTree = self.root().tree().expect("The root node is not connected to its NodeTree!");
+        self.iter_bfs()
+            .filter(|node| predicate(*node))
+            .filter_map(|node| tree.node_from_rid(node.rid()).to_option())
+            .collect()
+    }
+
+    /// Gets the first node in the tree which satisfies the given predicate, searching in
+    /// breadth-first order.
+    /// The search stops as soon as a match is found.
+    pub fn find_node(&self, predicate: impl Fn(&dyn Node) -> bool) -> TreeOption<'_, TpDyn<'_>> {
+        let tree:  &dyn NodeTree = self.root().tree().expect("The root node is not connected to its NodeTree!");
+        let found: Option<RID>   = self.iter_bfs().find(|node| predicate(*node)).map(|node| node.rid());
+        
+        match found {
+            Some(rid) => tree.node_from_rid(rid),
+            None      => unsafe {
+                let tree_raw: *mut dyn NodeTree = tree.as_dyn_raw() as *mut dyn NodeTree;
+                TreeOption::new(tree_raw, Self::ROOT_RID, None)
+            }
+        }
+    }
+
+    /// Exports the entire tree as a Graphviz `digraph`, which can be rendered via the `dot`
+    /// tool.
+    /// Each node is labeled as `name : type`, with edges going from parent to child.
+    /// Singletons are drawn as filled boxes and scene owners are drawn with a double outline.
+    pub fn dump_graphviz(&self) -> String {
+        fn escape(s: &str) -> String {
+            s.replace('\\', "\\\\").replace('"', "\\\"")
+        }
+
+        let mut out: String = "digraph NodeTree {\n    node [shape=ellipse];\n".to_string();
+        let mut edges: String = String::new();
+
+        for node in self.get_all_valid_nodes(&self.root().top_down(true)) {
+            let rid:   RID    = node.rid();
+            let label: String = escape(&format!("{} : {}", node.name(), node.name_as_type()));
+
+            let mut style: Vec<&str> = Vec::new();
+            if let Some(NodeIdentity::UniqueName(_)) = self.identity.get(&rid) {
+                style.push("shape=box, style=filled, fillcolor=lightblue");
+            }
+            if node.is_owner() {
+                style.push("peripheries=2");
+            }
+
+            if style.is_empty() {
+                out += &format!("    n{} [label=\"{}\"];\n", rid, label);
+            } else {
+                out += &format!("    n{} [label=\"{}\", {}];\n", rid, label, style.join(", "));
+            }
+
+            for child in node.children() {
+                edges += &format!("    n{} -> n{};\n", rid, child.rid());
+            }
+        }
+
+        out += &edges;
+        out += "}\n";
+        out
+    }
+}
+
+
+/// A lazy breadth-first iterator over the nodes of a `NodeTreeBase`.
+/// Created via `NodeTreeBase::iter_bfs()`.
+#[derive(Debug)]
+pub struct BreadthFirst<'a> {
+    tree:  &'a NodeTreeBase,
+    queue: VecDeque<RID>
+}
+
+impl <'a> Iterator for BreadthFirst<'a> {
+    type Item = &'a dyn Node;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(rid) = self.queue.pop_front() {
+            if let Some(node) = self.tree.get_node(rid) {
+                self.queue.extend(node.children_rids());
+                return Some(node);
+            }
+        }
+        None
+    }
 }
 
 
@@ -530,6 +1894,12 @@ impl NodeGetter for String {
     }
 }
 
+impl NodeGetter for RID {
+    fn get_from(&self, tree: &NodeTreeBase, _caller: Option<RID>) -> Option<RID> {
+        tree.nodes.retrieve(*self).map(|_| *self)
+    }
+}
+
 
 /// Initializes the base `NodeTreeBase` field in a `NodeTree` inherited object.
 ///
@@ -0,0 +1,153 @@
+//===================================================================================================================================================================================//
+//
+//  /$$$$$$$  /$$                               /$$                 /$$       /$$                    
+// | $$__  $$| $$                              | $$                | $$      | $$                    
+// | $$  \ $$| $$  /$$$$$$   /$$$$$$$  /$$$$$$ | $$$$$$$   /$$$$$$ | $$  /$$$$$$$  /$$$$$$   /$$$$$$ 
+// | $$$$$$$/| $$ |____  $$ /$$_____/ /$$__  $$| $$__  $$ /$$__  $$| $$ /$$__  $$ /$$__  $$ /$$__  $$
+// | $$____/ | $$  /$$$$$$$| $$      | $$$$$$$$| $$  \ $$| $$  \ $$| $$| $$  | $$| $$$$$$$$| $$  \__/
+// | $$      | $$ /$$__  $$| $$      | $$_____/| $$  | $$| $$  | $$| $$| $$  | $$| $$_____/| $$      
+// | $$      | $$|  $$$$$$$|  $$$$$$$|  $$$$$$$| $$  | $$|  $$$$$$/| $$|  $$$$$$$|  $$$$$$$| $$      
+// |__/      |__/ \_______/ \_______/ \_______/|__/  |__/ \______/ |__/ \_______/ \_______/|__/      
+//
+//===================================================================================================================================================================================//
+
+//?
+//? Created by LunaticWyrm467 and others.
+//? 
+//? All code is licensed under the MIT license.
+//? Feel free to reproduce, modify, and do whatever.
+//?
+//!
+//! Provides the `Placeholder` node, which stands in for a scene that has not been instanced yet.
+//! 
+
+use std::any::Any;
+use std::ops::{ Deref, DerefMut };
+
+use super::{ node_base::NodeBase, node_scene::NodeScene };
+use crate::traits::{ node::{ Node, NodeAbstract }, registered::Registered, exportable::Exportable };
+use crate::services::node_registry::{ FieldMap, SFieldMap, SCHEMA_VERSION_KEY };
+
+
+/// A lightweight node which holds onto a scene without instancing it, until it is expanded via
+/// `NodeBase::instantiate_placeholder()` or `NodeTreeBase::instantiate_placeholder()`.
+/// Placeholders are created through `NodeScene::as_placeholder()`, and take on the name of the
+/// scene's root node.
+///
+/// # Note
+/// The placeholder owns the scene's nodes while it is dormant, and hands them over to the tree once
+/// it is expanded. When expanded, the placeholder is removed and the scene is added in its place,
+/// under the same name and at the same index among its siblings, as the owner of its own nodes.
+///
+/// Saving a dormant placeholder (such as via `NodeBase::save_as_branch()`) saves the placeholder
+/// itself with its scene embedded as a string, so it remains dormant when loaded back in.
+#[derive(Debug, Clone)]
+pub struct Placeholder {
+    base:  NodeBase,
+    scene: Option<NodeScene>
+}
+
+impl Placeholder {
+    
+    /// Creates a new placeholder for the given scene.
+    pub fn new(mut scene: NodeScene) -> Self {
+        scene.is_owner = true;
+        Placeholder {
+            base:  NodeBase::new(scene.root_name()),
+            scene: Some(scene)
+        }
+    }
+
+    /// Returns the scene held by this placeholder, if it has not been expanded yet.
+    pub fn scene(&self) -> Option<&NodeScene> {
+        self.scene.as_ref()
+    }
+
+    /// Takes the scene out of this placeholder, leaving it empty.
+    pub(crate) fn take_scene(&mut self) -> Option<NodeScene> {
+        self.scene.take()
+    }
+}
+
+impl NodeAbstract for Placeholder {
+    fn base(&self) -> &NodeBase {
+        &self.base
+    }
+
+    fn base_mut(&mut self) -> &mut NodeBase {
+        &mut self.base
+    }
+
+    fn as_dyn(&self) -> &dyn Node {
+        self
+    }
+
+    fn as_dyn_mut(&mut self) -> &mut dyn Node {
+        self
+    }
+
+    fn as_dyn_raw(&self) -> *const dyn Node {
+        self as *const dyn Node
+    }
+
+    fn as_dyn_raw_mut(&mut self) -> *mut dyn Node {
+        self as *mut dyn Node
+    }
+
+    fn to_dyn_box(self) -> Box<dyn Node> {
+        Box::new(self)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn clone_as_instance(&self) -> Box<dyn Node> {
+        Box::new(self.clone())
+    }
+
+    fn name_as_type(&self) -> String {
+        std::any::type_name::<Self>().to_string()
+    }
+}
+
+impl Deref for Placeholder {
+    type Target = NodeBase;
+    fn deref(&self) -> &Self::Target {
+        &self.base
+    }
+}
+
+impl DerefMut for Placeholder {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.base
+    }
+}
+
+impl Registered for Placeholder {
+    fn load_from_owned(mut owned_state: SFieldMap) -> Result<Self, String> where Self: Sized {
+        let scene: String = owned_state.remove("scene")
+            .and_then(String::from_value)
+            .ok_or("corrupt save data; `scene` missing or invalid".to_string())?;
+        
+        Ok(Placeholder::new(NodeScene::load_from_str(&scene)?))
+    }
+
+    fn save_from_owned(&self) -> FieldMap {
+        let scene: String = self.scene.as_ref()
+            .map(|scene| scene.save_to_str().unwrap_or_else(|err| panic!("Failed to save a placeholder's scene: {err}")))
+            .unwrap_or_default();
+        
+        let mut map: FieldMap = FieldMap::new();
+        map.insert("scene".into(), Box::new(scene));
+        map.insert(SCHEMA_VERSION_KEY.into(), Box::new(Self::schema_version()));
+        map
+    }
+}
+
+impl Node for Placeholder {}
+
@@ -22,19 +22,58 @@
 //! A system that allows for the efficient storage of procedurally tagged items.
 //! 
 
-use std::collections::{ hash_map::{ Iter, IterMut, Values, ValuesMut }, HashMap };
-
 
 /// Describes an RID type.
+/// As this is a plain `u64`, it can already be displayed, parsed via `str::parse()`, and stored
+/// in an exported field.
+///
+/// # Note
+/// RIDs are NOT stable! They are handed out as nodes are added to the tree and are recycled as
+/// nodes are freed, so the same node will likely have a different RID between runs (or even after
+/// being removed and re-added). Only use them for references within a single session, such as
+/// for debugging tools. To persist a reference to a node across sessions, store a `NodePath`
+/// instead.
 pub type RID = u64;
 
 
-/// Holds a hashmap with automatically managed keys or RIDs (reference IDs).
-#[derive(Debug, Clone, Default)]
+/// Describes how densely a `RIDHolder`'s RIDs are packed.
+/// A large number of free slots relative to the capacity means that many items have been removed
+/// since the holder's peak.
+///
+/// # Note
+/// RIDs cannot be compacted, as anything referencing an item by its RID (such as a `Tp<T>`) would
+/// silently start referencing a different item. Free slots are instead reused as new items are
+/// added, so the capacity only ever grows to the holder's peak number of items.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct RidStats {
+    
+    /// The number of items currently held.
+    pub live: usize,
+
+    /// The number of RIDs that have been handed out, which is the highest number of items that
+    /// were ever held at once.
+    pub capacity: usize,
+
+    /// The number of RIDs that are free to be reused.
+    pub free_slots: usize
+}
+
+
+/// Holds items under automatically managed keys or RIDs (reference IDs).
+/// Items are stored in a slot map, where each RID is the index of its item's slot. Freed slots
+/// are kept in a free list and reused, so registering, removing and retrieving items are all
+/// O(1) without any hashing.
+#[derive(Debug, Clone)]
 pub struct RIDHolder<T> {
-    data:    HashMap<RID, T>,
-    highest: RID,
-    vacant:  Vec<RID>
+    slots:  Vec<Option<T>>,
+    vacant: Vec<RID>,
+    len:    usize
+}
+
+impl <T> Default for RIDHolder<T> {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl <T> RIDHolder<T> {
@@ -42,94 +81,101 @@ impl <T> RIDHolder<T> {
     /// Creates an empty RID holder structure.
     pub fn new() -> Self {
         RIDHolder {
-            data:    HashMap::new(),
-            highest: 0,
-            vacant:  Vec::new()
+            slots:  Vec::new(),
+            vacant: Vec::new(),
+            len:    0
         }
     }
 
     /// Creates a new RID holder from a vector, where each index of each item is its RID.
     pub fn from_vec(slice: Vec<T>) -> Self {
-        let highest: RID = (slice.len() - 1) as RID;
         RIDHolder {
-            data:    slice.into_iter().enumerate().map(|(rid, item)| (rid as RID, item)).collect(),
-            highest,
-            vacant:  Vec::new()
+            len:    slice.len(),
+            slots:  slice.into_iter().map(Some).collect(),
+            vacant: Vec::new()
         }
     }
 
     /// Adds a new item to the holder, registering it under the returned ID.
     pub fn push(&mut self, item: T) -> RID {
-        let rid: RID = match self.vacant.pop() {
-            Some(id) => id,
-            None     => {
-                let id: RID = self.highest;
-                self.highest += 1;
-                id
+        self.len += 1;
+        match self.vacant.pop() {
+            Some(rid) => {
+                self.slots[rid as usize] = Some(item);
+                rid
+            },
+            None => {
+                self.slots.push(Some(item));
+                (self.slots.len() - 1) as RID
             }
-        };
-
-        self.data.insert(rid, item);
-        rid
+        }
     }
 
     /// Retrieves an item's reference via an RID.
     #[inline]
     pub fn retrieve(&self, rid: RID) -> Option<&T> {
-        self.data.get(&rid)
+        self.slots.get(rid as usize)?.as_ref()
     }
     
     /// Retrieves an item's mutable reference via an RID.
     #[inline]
     pub fn modify(&mut self, rid: RID) -> Option<&mut T> {
-        self.data.get_mut(&rid)
+        self.slots.get_mut(rid as usize)?.as_mut()
     }
     
     /// Removes an item from the collection by the passed RID.
     /// Returns the item.
     pub fn take(&mut self, rid: RID) -> Option<T> {
-        match self.data.remove(&rid) {
-            None       => None,
-            Some(item) => {
-                self.vacant.push(rid);
-                Some(item)
-            }
-        }
+        let item: T = self.slots.get_mut(rid as usize)?.take()?;
+        self.vacant.push(rid);
+        self.len -= 1;
+        Some(item)
     }
 
-    /// Returns an iter for each of the items.
+    /// Returns an iter for each of the items, in order of their RIDs.
     #[inline]
-    pub fn iter(&self) -> Values<RID, T> {
-        self.data.values()
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.slots.iter().flatten()
     }
     
-    /// Returns a mutable iter for each of the items.
+    /// Returns a mutable iter for each of the items, in order of their RIDs.
     #[inline]
-    pub fn iter_mut(&mut self) -> ValuesMut<RID, T> {
-        self.data.values_mut()
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.slots.iter_mut().flatten()
     }
 
-    /// Returns an iter for each of the RID and item pairs.
+    /// Returns an iter for each of the RID and item pairs, in order of their RIDs.
+    /// RIDs are yielded by value, as they are not stored alongside the items.
     #[inline]
-    pub fn iter_enumerated(&self) -> Iter<RID, T> {
-        self.data.iter()
+    pub fn iter_enumerated(&self) -> impl Iterator<Item = (RID, &T)> {
+        self.slots.iter().enumerate().filter_map(|(rid, item)| Some((rid as RID, item.as_ref()?)))
     }
     
-    /// Returns a mutable iter for each of the RID and item pairs.
+    /// Returns a mutable iter for each of the RID and item pairs, in order of their RIDs.
+    /// RIDs are yielded by value, as they are not stored alongside the items.
     #[inline]
-    pub fn iter_mut_enumerated(&mut self) -> IterMut<RID, T> {
-        self.data.iter_mut()
+    pub fn iter_mut_enumerated(&mut self) -> impl Iterator<Item = (RID, &mut T)> {
+        self.slots.iter_mut().enumerate().filter_map(|(rid, item)| Some((rid as RID, item.as_mut()?)))
     }
 
     /// Returns the number of elements in the container.
     #[inline]
     pub fn len(&self) -> usize {
-        self.data.len()
+        self.len
     }
 
     /// Returns whether this container is empty.
     #[inline]
     pub fn is_empty(&self) -> bool {
-        self.data.is_empty()
+        self.len == 0
+    }
+
+    /// Returns statistics on how densely the RIDs of this container are packed.
+    pub fn stats(&self) -> RidStats {
+        RidStats {
+            live:       self.len,
+            capacity:   self.slots.len(),
+            free_slots: self.vacant.len()
+        }
     }
 }
@@ -0,0 +1,116 @@
+//===================================================================================================================================================================================//
+//
+//  /$$   /$$                 /$$                 /$$$$$$$                     
+// | $$$ | $$                | $$                | $$__  $$                    
+// | $$$$| $$  /$$$$$$   /$$$$$$$  /$$$$$$       | $$  \ $$ /$$$$$$$   /$$$$$$ 
+// | $$ $$ $$ /$$__  $$ /$$__  $$ /$$__  $$      | $$$$$$$/| $$__  $$ /$$__  $$
+// | $$  $$$$| $$  \ $$| $$  | $$| $$$$$$$$      | $$__  $$| $$  \ $$| $$  \ $$
+// | $$\  $$$| $$  | $$| $$  | $$| $$_____/      | $$  \ $$| $$  | $$| $$  | $$
+// | $$ \  $$|  $$$$$$/|  $$$$$$$|  $$$$$$$      | $$  | $$| $$  | $$|  $$$$$$$
+// |__/  \__/ \______/  \_______/ \_______/      |__/  |__/|__/  |__/ \____  $$
+//                                                                    /$$  \ $$
+//                                                                   |  $$$$$$/
+//                                                                    \______/ 
+//
+//===================================================================================================================================================================================//
+
+//?
+//? Created by LunaticWyrm467 and others.
+//? 
+//? All code is licensed under the MIT license.
+//? Feel free to reproduce, modify, and do whatever.
+//?
+
+//!
+//! Provides the `NodeRng` type, a small deterministic pseudo-random number generator which is
+//! handed out to each node via `NodeBase::rng()`.
+//!
+//! Each node's stream is derived from the tree's master seed (see `NodeTreeBase::set_seed()`)
+//! and the node's `RID`, meaning that the same scene will produce the same sequence of numbers
+//! across runs.
+//! 
+
+use std::cell::Cell;
+
+use super::rid::RID;
+
+
+/// Mixes a value via the SplitMix64 finalizer.
+#[inline]
+fn mix(mut z: u64) -> u64 {
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+    z ^ (z >> 31)
+}
+
+/// Derives the initial state of a node's stream from the tree's master seed and the node's `RID`.
+pub(crate) fn derive_stream(seed: u64, rid: RID) -> u64 {
+    mix(seed ^ mix(rid.wrapping_add(0x9E3779B97F4A7C15)))
+}
+
+
+/// A deterministic pseudo-random number generator belonging to a single node.
+/// This is a SplitMix64 generator, which is fast and statistically sound for simulations and
+/// games, but is NOT cryptographically secure.
+#[derive(Debug)]
+pub struct NodeRng<'a> {
+    state: &'a Cell<Option<u64>>
+}
+
+impl <'a> NodeRng<'a> {
+
+    /// The amount that the state is advanced by per number generated.
+    const GAMMA: u64 = 0x9E3779B97F4A7C15;
+    
+    /// Creates a generator over the given stream state.
+    /// An uninitialized state is treated as a zeroed stream.
+    pub(crate) fn new(state: &'a Cell<Option<u64>>) -> Self {
+        NodeRng { state }
+    }
+
+    /// Generates a random `u64`.
+    pub fn next_u64(&mut self) -> u64 {
+        let state: u64 = self.state.get().unwrap_or_default().wrapping_add(Self::GAMMA);
+        self.state.set(Some(state));
+        mix(state)
+    }
+
+    /// Generates a random `u32`.
+    pub fn next_u32(&mut self) -> u32 {
+        (self.next_u64() >> 32) as u32
+    }
+
+    /// Generates a random `f64` within the range `[0, 1)`.
+    pub fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 * (1.0 / (1u64 << 53) as f64)
+    }
+
+    /// Generates a random `f32` within the range `[0, 1)`.
+    pub fn next_f32(&mut self) -> f32 {
+        (self.next_u32() >> 8) as f32 * (1.0 / (1u32 << 24) as f32)
+    }
+
+    /// Generates a random `bool`.
+    pub fn next_bool(&mut self) -> bool {
+        self.next_u64() >> 63 == 1
+    }
+
+    /// Generates a random number within the range `[0, bound)`.
+    ///
+    /// # Panics
+    /// Panics if `bound` is zero.
+    pub fn below(&mut self, bound: u64) -> u64 {
+        if bound == 0 {
+            panic!("Cannot generate a number below zero!");
+        }
+        ((self.next_u64() as u128 * bound as u128) >> 64) as u64
+    }
+
+    /// Fills the given buffer with random bytes.
+    pub fn fill_bytes(&mut self, dest: &mut [u8]) {
+        for chunk in dest.chunks_mut(8) {
+            let bytes: [u8; 8] = self.next_u64().to_le_bytes();
+            chunk.copy_from_slice(&bytes[..chunk.len()]);
+        }
+    }
+}
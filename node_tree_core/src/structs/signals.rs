@@ -27,7 +27,11 @@
 //! 
 
 use std::mem;
-use std::sync::{ Arc, Mutex, MutexGuard };
+use std::collections::VecDeque;
+use std::sync::{ Arc, Weak, Mutex, MutexGuard };
+
+#[cfg(feature = "async")]
+use std::{ future::Future, pin::Pin, task::{ Context, Poll, Waker } };
 
 use toml_edit as toml;
 
@@ -46,6 +50,14 @@ enum ConnectionType {
 type MutableArc<T>   = Arc<Mutex<T>>;
 type EventHandler<T> = RIDHolder<(*mut dyn FnMut(&T), ConnectionType)>;
 
+/// The most recently emitted parameters of a signal, which are replayed to late listeners.
+#[derive(Debug)]
+struct Replay<T> {
+    capacity: usize,
+    values:   VecDeque<T>,
+    clone:    fn(&T) -> T
+}
+
 
 /// A type used to define a signal in a Node.
 /// A signal is a special event handler which can have listener hooks or connections, of which will
@@ -63,7 +75,8 @@ type EventHandler<T> = RIDHolder<(*mut dyn FnMut(&T), ConnectionType)>;
 /// ```
 #[derive(Debug)]
 pub struct Signal<T> {
-    hooks: MutableArc<EventHandler<T>>
+    hooks:  MutableArc<EventHandler<T>>,
+    replay: Option<Mutex<Replay<T>>>
 }
 
 impl <T> Signal<T> {
@@ -71,7 +84,29 @@ impl <T> Signal<T> {
     /// Creates a new Signal.
     pub fn new() -> Self {
         Signal {
-            hooks: Arc::new(Mutex::new(RIDHolder::new()))
+            hooks:  Arc::new(Mutex::new(RIDHolder::new())),
+            replay: None
+        }
+    }
+
+    /// Creates a new Signal which remembers the parameters of its last `n` emissions, which are
+    /// replayed to listeners that connect via `connect_replay()`. This is useful for signals that
+    /// describe a current value or state, which late listeners need to catch up on.
+    /// ```rust, ignore
+    /// class! {
+    ///     dec Thermometer;
+    ///
+    ///     pub sig on_temperature(celsius: f32);
+    ///
+    ///     hk _init() {
+    ///         self.on_temperature = Signal::with_replay(1);
+    ///     }
+    /// }
+    /// ```
+    pub fn with_replay(n: usize) -> Self where T: Clone {
+        Signal {
+            hooks:  Arc::new(Mutex::new(RIDHolder::new())),
+            replay: Some(Mutex::new(Replay { capacity: n, values: VecDeque::with_capacity(n), clone: T::clone }))
         }
     }
     
@@ -125,14 +160,49 @@ impl <T> Signal<T> {
 
         self.hooks.lock().unwrap().push((callback_raw, ConnectionType::Once))
     }
+
+    /// Creates a connection like `connect()`, but first calls the closure with each of the
+    /// parameters remembered by a signal created via `with_replay()`, from oldest to newest.
+    /// For signals without a replay history, this is the same as `connect()`.
+    ///
+    /// Returns the RID of the connection.
+    ///
+    /// # Note
+    /// The history is copied out before it is replayed, and the connection is only made once the
+    /// replay has finished. The closure may therefore emit this signal while it is being replayed
+    /// to, although it will not receive those emissions.
+    ///
+    /// # Safety
+    /// The same requirements as `connect()` apply.
+    pub unsafe fn connect_replay<'a>(&self, mut callback: impl FnMut(&T) + 'a) -> RID {
+        let history: Vec<T> = match &self.replay {
+            Some(replay) => {
+                let replay: MutexGuard<Replay<T>> = replay.lock().unwrap();
+                replay.values.iter().map(replay.clone).collect()
+            },
+            None => Vec::new()
+        };
+
+        for parameters in &history {
+            callback(parameters);
+        }
+        self.connect(callback)
+    }
+
+    /// Returns how many of its most recent emissions this signal remembers for replaying.
+    /// This is zero for signals that were not created via `with_replay()`.
+    pub fn replay_capacity(&self) -> usize {
+        self.replay.as_ref().map_or(0, |replay| replay.lock().unwrap().capacity)
+    }
     
     /// Emits the signal, calling all connected hooks.
-    pub fn emit<E: Element<T>>(&self, parameters: E) {
+    /// Returns the number of hooks that were called, which is zero if nobody is listening.
+    pub fn emit<E: Element<T>>(&self, parameters: E) -> usize {
         let mut hooks:           MutexGuard<EventHandler<T>> = self.hooks.lock().unwrap();
         let mut removed_signals: Vec<RID>                    = Vec::with_capacity(hooks.len());
         let     parameters:      &T                          = parameters.as_inner();
 
-        for (&rid, &(hook, mode)) in hooks.iter_enumerated() {
+        for (rid, &(hook, mode)) in hooks.iter_enumerated() {
             unsafe {
                 (*hook)(parameters);
             }
@@ -143,9 +213,35 @@ impl <T> Signal<T> {
             }
         }
 
+        let invoked: usize = hooks.len();
         for idx in removed_signals.into_iter().rev() {
             hooks.take(idx);
         }
+        drop(hooks);
+
+        // Remember the parameters for any listeners that connect later on.
+        if let Some(replay) = &self.replay {
+            let mut replay: MutexGuard<Replay<T>> = replay.lock().unwrap();
+            if replay.capacity > 0 {
+                if replay.values.len() == replay.capacity {
+                    replay.values.pop_front();
+                }
+                let value: T = (replay.clone)(parameters);
+                replay.values.push_back(value);
+            }
+        }
+        invoked
+    }
+
+    /// Gets the number of hooks that are currently connected to this signal.
+    /// This can be used to skip computing expensive parameters when nobody is listening:
+    /// ```rust, ignore
+    /// if self.on_update.listener_count() > 0 {
+    ///     self.on_update.emit(expensive_computation());
+    /// }
+    /// ```
+    pub fn listener_count(&self) -> usize {
+        self.hooks.lock().unwrap().len()
     }
 
     /// Disconnects a connection given its RID.
@@ -153,11 +249,121 @@ impl <T> Signal<T> {
     pub fn disconnect(&self, rid: RID) -> bool {
         self.hooks.lock().unwrap().take(rid).is_some()
     }
+
+    /// Creates a function which disconnects a connection given its RID once called.
+    /// The function does not keep the signal alive, and does nothing if the signal has since been
+    /// dropped.
+    ///
+    /// # Note
+    /// This must not be called while this signal is being emitted, such as by freeing a node
+    /// within a listener. Use `queue_free()` in that case instead.
+    pub fn disconnector(&self, rid: RID) -> impl FnOnce() + 'static where T: 'static {
+        let hooks: Weak<Mutex<EventHandler<T>>> = Arc::downgrade(&self.hooks);
+        move || {
+            if let Some(hooks) = hooks.upgrade() {
+                hooks.lock().unwrap().take(rid);
+            }
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+impl <T: Clone + 'static> Signal<T> {
+
+    /// Creates a future which resolves to the parameters of the next emission of this signal.
+    /// Internally, this is a one-time connection which is disconnected if the future is dropped
+    /// before it resolves.
+    /// ```rust, ignore
+    /// let damage: u32 = self.on_damaged.next().await;
+    /// ```
+    ///
+    /// # Note
+    /// Signals are emitted from within the tree's frames, so the future must be polled on the
+    /// same thread that runs the tree, between or during its frames. It will never resolve if the
+    /// signal is dropped first.
+    pub fn next(&self) -> SignalFuture<T> {
+        let state:  Arc<Mutex<FutureState<T>>> = Arc::new(Mutex::new(FutureState { value: None, fired: false, waker: None }));
+        let shared: Arc<Mutex<FutureState<T>>> = state.clone();
+        let rid:    RID                        = unsafe {
+            self.connect_once(move |value: &T| {
+                let mut state: MutexGuard<FutureState<T>> = shared.lock().unwrap();
+                state.value = Some(value.clone());
+                state.fired = true;
+                if let Some(waker) = state.waker.take() {
+                    waker.wake();
+                }
+            })
+        };
+
+        SignalFuture {
+            state,
+            hooks: Arc::downgrade(&self.hooks),
+            rid
+        }
+    }
+}
+
+/// The shared state between a `SignalFuture` and its connection.
+#[cfg(feature = "async")]
+#[derive(Debug)]
+struct FutureState<T> {
+    value: Option<T>,
+    fired: bool,
+    waker: Option<Waker>
+}
+
+/// A future which resolves to the parameters of a signal's next emission.
+/// This is created via `Signal::next()`.
+#[cfg(feature = "async")]
+#[derive(Debug)]
+pub struct SignalFuture<T> {
+    state: Arc<Mutex<FutureState<T>>>,
+    hooks: Weak<Mutex<EventHandler<T>>>,
+    rid:   RID
+}
+
+#[cfg(feature = "async")]
+impl <T> Future for SignalFuture<T> {
+    type Output = T;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut state: MutexGuard<FutureState<T>> = self.state.lock().unwrap();
+        match state.value.take() {
+            Some(value) => Poll::Ready(value),
+            None        => {
+                state.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
 }
 
+#[cfg(feature = "async")]
+impl <T> Drop for SignalFuture<T> {
+    fn drop(&mut self) {
+        
+        // Only disconnect if the signal has not yet been emitted, as the connection is otherwise
+        // already gone (and its RID may have been reused).
+        if self.state.lock().unwrap().fired {
+            return;
+        }
+        if let Some(hooks) = self.hooks.upgrade() {
+            hooks.lock().unwrap().take(self.rid);
+        }
+    }
+}
+
+/// Cloning a signal creates a new signal without any connections or history, although it keeps
+/// the replay capacity of the original.
 impl <T> Clone for Signal<T> {
     fn clone(&self) -> Self {
-        Self::new()
+        Signal {
+            hooks:  Arc::new(Mutex::new(RIDHolder::new())),
+            replay: self.replay.as_ref().map(|replay| {
+                let replay: MutexGuard<Replay<T>> = replay.lock().unwrap();
+                Mutex::new(Replay { capacity: replay.capacity, values: VecDeque::new(), clone: replay.clone })
+            })
+        }
     }
 }
 
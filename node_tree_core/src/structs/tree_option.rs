@@ -34,6 +34,7 @@ use std::option::{ Iter, IterMut };
 
 use crate::traits::node_tree::NodeTree;
 use super::rid::RID;
+use super::node_path::NodePath;
 use super::logger::Log;
 use super::tree_result::TreeResult;
 
@@ -158,6 +159,18 @@ impl <'a, T> TreeOption<'a, T> {
         }
     }
 
+    /// Converts this to an `Option<T>` like `to_option()`, but posts a warning with the given
+    /// message to the log if this is a `None` value.
+    ///
+    /// This is a middle ground between silently discarding the absence of a value and panicking
+    /// via `expect()`, which suits lookups that are allowed to fail.
+    pub fn ok_or_log(self, msg: &str) -> Option<T> {
+        if self.object.is_none() {
+            self.warn(msg);
+        }
+        self.object
+    }
+
     /// Returns the contained `Some` value or a provided default.
     ///
     /// Arguments passed to `unwrap_or` are eagerly evaluated; if you are passing
@@ -483,11 +496,22 @@ impl <'a, T> TreeOption<'a, T> {
     }
 
     /// Marks a failed operation with a panic on the log, and panics the main thread.
+    /// The logged message includes the absolute path of the owning node, so that it is clear
+    /// where the failed lookup came from.
     fn fail(&self, msg: &str) -> ! {
-        unsafe { (*self.tree).get_node(self.owner).unwrap_unchecked() }.post(Log::Panic(msg));
+        let tree:  &dyn NodeTree = unsafe { &*self.tree };
+        let owner: NodePath      = unsafe { tree.path_of_rid(self.owner).unwrap_unchecked() };
+        unsafe { tree.get_node(self.owner).unwrap_unchecked() }.post(Log::Panic(&format!("{msg} (owned by \"{owner}\")")));
         println!("\n[RUST TRACE]");
         panic!();
     }
+
+    /// Posts a warning to the log on behalf of the owning node, if it still exists.
+    fn warn(&self, msg: &str) {
+        if let Some(owner) = unsafe { (*self.tree).get_node(self.owner) } {
+            owner.post(Log::Warn(msg));
+        }
+    }
     
     /// Creates a `TreeOption` with all of the calling `TreeOption`'s metadata attached and the
     /// given optional item as its value.
@@ -23,15 +23,19 @@
 
 //!
 //! Provides the `Tp<T>` smart pointer which allows access to Nodes in the `NodeTree`.
-//! Also provides the `TpDyn` alternative to allow easy access to dynamic values.
+//! Also provides the `TpDyn` alternative to allow easy access to dynamic values, along with the
+//! `TpStrong` handle which counts as a reference to a node.
 //! 
 
 use std::ops::{ Deref, DerefMut };
+use std::rc::Rc;
 use std::any::Any;
 use std::marker::PhantomData;
+use std::hash::{ Hash, Hasher };
 
-use crate::traits::{ node::Node, node_tree::NodeTree };
+use crate::traits::{ node::Node, node_tree::NodeTree, node_getter::NodeGetter };
 use super::rid::RID;
+use super::node_tree_base::NodeTreeBase;
 use super::logger::Log;
 use super::tree_result::TreeResult;
 
@@ -52,7 +56,11 @@ use super::tree_result::TreeResult;
 /// # `Deref` and `DerefMut`
 /// The Tree Pointer implements `Deref` and `DerefMut`, which automatically call the panicking
 /// versions of `get()` and `get_mut()`.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+///
+/// # Equality and Hashing
+/// Two tree pointers are equal and hash identically if they reference the same node in the same
+/// `NodeTree`, regardless of which node created them.
+#[derive(Debug, Clone, Copy)]
 pub struct Tp<'a, T: Node> {
     tree:   *mut dyn NodeTree,
     owner:  RID,
@@ -120,6 +128,12 @@ impl <'a, T: Node> Tp<'a, T> {
         }
     }
 
+    /// Creates a `TpStrong` handle to the node, which counts as a reference to it.
+    /// See `NodeBase::set_auto_free_when_orphaned()`.
+    pub fn to_strong(&self) -> TpStrong {
+        TpStrong::new(unsafe { (*self.tree).base_mut() }, self.node)
+    }
+
     /// Determines if the `Node` this pointer is pointing to is valid.
     pub fn is_valid(&self) -> bool {
         match unsafe { &*self.tree }.get_node(self.node) {
@@ -216,6 +230,30 @@ impl <'a, T: Node> Tp<'a, T> {
     }
 }
 
+impl <'a, T: Node> PartialEq for Tp<'a, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.node == other.node && self.tree as *const () == other.tree as *const ()
+    }
+}
+
+impl <'a, T: Node> Eq for Tp<'a, T> {}
+
+impl <'a, T: Node> Hash for Tp<'a, T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.node.hash(state);
+        (self.tree as *const ()).hash(state);
+    }
+}
+
+impl <'a, T: Node> NodeGetter for Tp<'a, T> {
+    fn get_from(&self, tree: &NodeTreeBase, caller: Option<RID>) -> Option<RID> {
+        if !std::ptr::eq(unsafe { (*self.tree).base() }, tree) {
+            return None;
+        }
+        self.node.get_from(tree, caller)
+    }
+}
+
 impl <'a, T: Node> Deref for Tp<'a, T> {
     type Target = T;
     fn deref(&self) -> &Self::Target {
@@ -250,7 +288,11 @@ impl <'a, T: Node> DerefMut for Tp<'a, T> {
 /// # `Deref` and `DerefMut`
 /// The Tree Pointer implements `Deref` and `DerefMut`, which automatically call the panicking
 /// versions of `get()` and `get_mut()`.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+///
+/// # Equality and Hashing
+/// Two tree pointers are equal and hash identically if they reference the same node in the same
+/// `NodeTree`, regardless of which node created them.
+#[derive(Debug, Clone, Copy)]
 pub struct TpDyn<'a> {
     owner:  RID,
     node:   RID,
@@ -293,6 +335,29 @@ impl <'a> TpDyn<'a> {
         }
     }
 
+    /// Downcasts this to a type-coerced pointer.
+    /// Unlike `to()`, this returns a descriptive error which names both the node's actual type and
+    /// the requested type on a mismatch.
+    pub fn cast<T: Node>(self) -> TreeResult<'a, Tp<'a, T>> {
+        let node: Option<&dyn Node> = unsafe { &*self.tree }.get_node_raw(self.node).map(|n| unsafe { &*n });
+        match node {
+            Some(node) if !self.is::<T>() => unsafe {
+                let msg: String = format!(
+                    "Cannot cast node \"{}\" of type `{}` to `{}`",
+                    node.name(), node.name_as_type(), std::any::type_name::<T>()
+                );
+                TreeResult::new(self.tree, self.owner, Err(msg))
+            },
+            _ => self.to()
+        }
+    }
+
+    /// Creates a `TpStrong` handle to the node, which counts as a reference to it.
+    /// See `NodeBase::set_auto_free_when_orphaned()`.
+    pub fn to_strong(&self) -> TpStrong {
+        TpStrong::new(unsafe { (*self.tree).base_mut() }, self.node)
+    }
+
     /// Determines if this pointer references a specific type.
     pub fn is<T: Node>(&self) -> bool {
         let node: Option<&dyn Node> = unsafe { &*self.tree }.get_node_raw(self.node).map(|n| unsafe { &*n });
@@ -363,6 +428,30 @@ impl <'a> TpDyn<'a> {
     }
 }
 
+impl <'a> PartialEq for TpDyn<'a> {
+    fn eq(&self, other: &Self) -> bool {
+        self.node == other.node && self.tree as *const () == other.tree as *const ()
+    }
+}
+
+impl <'a> Eq for TpDyn<'a> {}
+
+impl <'a> Hash for TpDyn<'a> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.node.hash(state);
+        (self.tree as *const ()).hash(state);
+    }
+}
+
+impl <'a> NodeGetter for TpDyn<'a> {
+    fn get_from(&self, tree: &NodeTreeBase, caller: Option<RID>) -> Option<RID> {
+        if !std::ptr::eq(unsafe { (*self.tree).base() }, tree) {
+            return None;
+        }
+        self.node.get_from(tree, caller)
+    }
+}
+
 impl <'a> Deref for TpDyn<'a> {
     type Target = dyn Node;
     fn deref(&self) -> &Self::Target {
@@ -375,3 +464,61 @@ impl <'a> DerefMut for TpDyn<'a> {
         self.get_mut()
     }
 }
+
+
+/*
+ * Tree Pointer
+ *      Strong
+ */
+
+
+/// A strong handle to a node, which counts as a reference to it for as long as the handle or any
+/// of its clones are alive. Nodes which are set to be freed once orphaned are kept alive by these
+/// handles; see `NodeBase::set_auto_free_when_orphaned()`.
+///
+/// Unlike tree pointers, handles do not borrow from the tree, so they may be stored, such as in a
+/// node's fields. They are resolved like any other `NodeGetter`:
+/// ```rust,ignore
+/// let handle: TpStrong = self.get_child_dyn(0).unwrap().to_strong();
+/// let node:   TpDyn    = self.get_node_dyn(&handle).unwrap();
+/// ```
+///
+/// # Note
+/// A handle no longer resolves once its node has been freed, even if the node's `RID` is handed
+/// out to a new node.
+#[derive(Debug, Clone)]
+pub struct TpStrong {
+    node:  RID,
+    token: Rc<()>  // Shared by every handle to the node; the tree only keeps a weak reference to it.
+}
+
+impl TpStrong {
+    
+    /// Creates a new handle to the node with the given `RID`.
+    fn new(tree: &mut NodeTreeBase, node: RID) -> Self {
+        TpStrong {
+            node,
+            token: tree.strong_token(node)
+        }
+    }
+
+    /// Gets the `RID` of the node that this handle references.
+    pub fn rid(&self) -> RID {
+        self.node
+    }
+}
+
+impl NodeGetter for TpStrong {
+    fn get_from(&self, tree: &NodeTreeBase, caller: Option<RID>) -> Option<RID> {
+        if !tree.holds_strong_token(self.node, &self.token) {
+            return None;
+        }
+        self.node.get_from(tree, caller)
+    }
+}
+
+impl NodeGetter for &TpStrong {
+    fn get_from(&self, tree: &NodeTreeBase, caller: Option<RID>) -> Option<RID> {
+        (*self).get_from(tree, caller)
+    }
+}
@@ -32,6 +32,7 @@ use std::result::{ Iter, IterMut };
 
 use crate::traits::node_tree::NodeTree;
 use super::rid::RID;
+use super::node_path::NodePath;
 use super::logger::Log;
 use super::tree_option::TreeOption;
 
@@ -278,6 +279,21 @@ impl <'a, T> TreeResult<'a, T> {
         }
     }
 
+    /// Converts this to an `Option<T>` like `to_option()`, but posts a warning with the given
+    /// message and the error to the log if this is an `Err` value.
+    ///
+    /// This is a middle ground between silently discarding the error and panicking via
+    /// `expect()`, which suits lookups that are allowed to fail.
+    pub fn ok_or_log(self, msg: &str) -> Option<T> {
+        match self.object {
+            Ok(object)   => Some(object),
+            Err(ref err) => {
+                self.warn(&format!("{msg}: {err}"));
+                None
+            }
+        }
+    }
+
     /// Returns the contained `Ok` value or a default
     ///
     /// Consumes the `self` argument then, if `Ok`, returns the contained
@@ -429,11 +445,22 @@ impl <'a, T> TreeResult<'a, T> {
     }
     
     /// Marks a failed operation with a panic on the log, and panics the main thread.
+    /// The logged message includes the absolute path of the owning node along with the error
+    /// value, so that it is clear where the failed lookup came from and why it failed.
     fn fail(&self, msg: &str, error: &str) -> ! {
-        unsafe { (*self.tree).get_node(self.owner).unwrap_unchecked() }.post(Log::Panic(&format!("{msg}: {error}")));
+        let tree:  &dyn NodeTree = unsafe { &*self.tree };
+        let owner: NodePath      = unsafe { tree.path_of_rid(self.owner).unwrap_unchecked() };
+        unsafe { tree.get_node(self.owner).unwrap_unchecked() }.post(Log::Panic(&format!("{msg}: {error} (owned by \"{owner}\")")));
         println!("\n[RUST TRACE]");
         panic!();
     }
+
+    /// Posts a warning to the log on behalf of the owning node, if it still exists.
+    fn warn(&self, msg: &str) {
+        if let Some(owner) = unsafe { (*self.tree).get_node(self.owner) } {
+            owner.post(Log::Warn(msg));
+        }
+    }
 }
 
 impl <'a, T> TreeResult<'a, &T> {
@@ -0,0 +1,108 @@
+//===================================================================================================================================================================================//
+//
+//  /$$$$$$$$                    /$$     /$$                    
+// |__  $$__/                   | $$    |__/                    
+//    | $$  /$$$$$$   /$$$$$$$ /$$$$$$   /$$ /$$$$$$$   /$$$$$$ 
+//    | $$ /$$__  $$ /$$_____/|_  $$_/  | $$| $$__  $$ /$$__  $$
+//    | $$| $$$$$$$$|  $$$$$$   | $$    | $$| $$  \ $$| $$  \ $$
+//    | $$| $$_____/ \____  $$  | $$ /$$| $$| $$  | $$| $$  | $$
+//    | $$|  $$$$$$$ /$$$$$$$/  |  $$$$/| $$| $$  | $$|  $$$$$$$
+//    |__/ \_______/|_______/    \___/  |__/|__/  |__/ \____  $$
+//                                                     /$$  \ $$
+//                                                    |  $$$$$$/
+//                                                     \______/ 
+//
+//===================================================================================================================================================================================//
+
+//?
+//? Created by LunaticWyrm467 and others.
+//? 
+//? All code is licensed under the MIT license.
+//? Feel free to reproduce, modify, and do whatever.
+//?
+
+//!
+//! Provides a small harness for testing nodes in isolation, without having to set up a `NodeTree`
+//! by hand.
+//!
+//! This module is only available with the `testing` feature enabled.
+//!
+
+use crate::structs::logger::{ LoggerVerbosity, LogLevel, LogSummary };
+use crate::structs::node_tree_base::{ NodeTreeBase, TreeStatus };
+use crate::traits::node::Node;
+use crate::trees::TreeSimple;
+
+
+/// The outcome of running a node via `run_node()`.
+#[derive(Debug)]
+pub struct NodeRun {
+
+    /// A clone of the node after it has run.
+    pub node: Box<dyn Node>,
+
+    /// Everything that was posted to the tree's log while the node ran.
+    pub log: String,
+
+    /// The status of the tree after the last frame.
+    pub status: TreeStatus
+}
+
+impl NodeRun {
+    
+    /// Attempts to downcast the node that was run to its concrete type.
+    pub fn node_as<T: Node>(&self) -> Option<&T> {
+        self.node.as_any().downcast_ref()
+    }
+
+    /// Returns whether any warnings were posted to the log.
+    pub fn has_warnings(&self) -> bool {
+        self.log.contains("| WARN |")
+    }
+
+    /// Returns whether any panics were posted to the log.
+    pub fn has_panicked(&self) -> bool {
+        self.log.contains("| PANIC! |")
+    }
+}
+
+/// Builds a tree with the given node as its root, readies it, and then processes it for the given
+/// number of frames using a fixed delta time.
+/// Processing stops early if the tree terminates.
+///
+/// # Note
+/// The returned node is a clone of the root at the end of the run, meaning that any `UniqueField`
+/// will be void.
+pub fn run_node<N: Node>(node: N, frames: usize, delta: f32) -> NodeRun {
+    let mut tree: Box<TreeSimple> = TreeSimple::new(node, LoggerVerbosity::NoDebug);
+    
+    for _ in 0..frames {
+        if tree.process_with_delta(delta) == TreeStatus::Terminated {
+            break;
+        }
+    }
+
+    NodeRun {
+        node:   tree.root().clone_as_instance(),
+        log:    tree.get_log().to_string(),
+        status: tree.status()
+    }
+}
+
+impl NodeTreeBase {
+    
+    /// Asserts that no warnings or panics have been posted to the tree's log so far.
+    /// This is useful for regression tests that should fail once a node starts warning.
+    ///
+    /// # Panics
+    /// Panics with every warning and panic that was posted if there were any.
+    pub fn assert_clean(&self) {
+        let summary: LogSummary = self.status_summary();
+        if !summary.is_clean() {
+            panic!(
+                "Expected a clean log, but {} warning(s) and {} panic(s) were posted:\n{}",
+                summary.warn, summary.panic, self.export_log(LogLevel::Warn, false)
+            );
+        }
+    }
+}
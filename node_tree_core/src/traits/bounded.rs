@@ -0,0 +1,99 @@
+//===================================================================================================================================================================================//
+//
+//  /$$$$$$$                                      /$$                 /$$
+// | $$__  $$                                    | $$                | $$
+// | $$  \ $$  /$$$$$$  /$$   /$$ /$$$$$$$   /$$$$$$$  /$$$$$$   /$$$$$$$
+// | $$$$$$$  /$$__  $$| $$  | $$| $$__  $$ /$$__  $$ /$$__  $$ /$$__  $$
+// | $$__  $$| $$  \ $$| $$  | $$| $$  \ $$| $$  | $$| $$$$$$$$| $$  | $$
+// | $$  \ $$| $$  | $$| $$  | $$| $$  | $$| $$  | $$| $$_____/| $$  | $$
+// | $$$$$$$/|  $$$$$$/|  $$$$$$/| $$  | $$|  $$$$$$$|  $$$$$$$|  $$$$$$$
+// |_______/  \______/  \______/ |__/  |__/ \_______/ \_______/ \_______/
+//
+//===================================================================================================================================================================================//
+
+//?
+//? Created by LunaticWyrm467 and others.
+//? 
+//? All code is licensed under the MIT license.
+//? Feel free to reproduce, modify, and do whatever.
+//?
+
+//!
+//! Provides the optional `Bounded` trait, which allows for nodes to describe their spatial extent.
+//!
+//! As `dyn Node` cannot be cast to another trait object directly, bounded node types must be
+//! registered once via `register_bounded()`. The tree then uses this registry to include only
+//! bounded nodes when aggregating bounds via `NodeBase::subtree_bounds()`.
+//! 
+
+use std::{ any::{ Any, TypeId }, cell::RefCell, collections::HashMap };
+
+use crate::structs::geometry::Rect;
+use super::node::Node;
+
+
+/// Casts a type-erased node to a `Bounded` trait object.
+type BoundedCaster = fn(&dyn Any) -> Option<&dyn Bounded>;
+
+thread_local! {
+    static BOUNDED_REGISTRY: RefCell<HashMap<TypeId, BoundedCaster>> = RefCell::new(HashMap::new());
+}
+
+
+/// Implemented by nodes that occupy some space, such as those in spatial or UI trees.
+/// This provides a generic foundation for culling, hit-testing, and layout.
+///
+/// # Note
+/// Node types must also be registered via `register_bounded()` in order to be picked up by the
+/// tree.
+///
+/// # Example
+/// ```rust, ignore
+/// class! {
+///     dec Sprite;
+///
+///     export let pos:  (f32, f32);
+///     export let size: (f32, f32);
+///
+///     hk _init(pos: (f32, f32), size: (f32, f32)) {
+///         register_bounded::<Sprite>();
+///     }
+/// }
+///
+/// impl Bounded for Sprite {
+///     fn local_bounds(&self) -> Rect {
+///         Rect::from_pos_size(Vec2::new(self.pos.0, self.pos.1), Vec2::new(self.size.0, self.size.1))
+///     }
+/// }
+/// ```
+pub trait Bounded {
+
+    /// Gets the bounds of this node alone, not including any of its children.
+    /// As the core has no notion of transforms, all bounds within a tree are assumed to share
+    /// the same coordinate space.
+    fn local_bounds(&self) -> Rect;
+}
+
+
+/// Registers a node type as being `Bounded`, so that it is picked up when aggregating the bounds
+/// of a tree. Registering the same type more than once has no additional effect.
+///
+/// # Note
+/// The registry is per-thread, so this must be called on the same thread as the `NodeTree`.
+pub fn register_bounded<T: Node + Bounded>() {
+    fn cast<T: Node + Bounded>(node: &dyn Any) -> Option<&dyn Bounded> {
+        node.downcast_ref::<T>().map(|node| node as &dyn Bounded)
+    }
+    
+    BOUNDED_REGISTRY.with(|registry| {
+        registry.borrow_mut().insert(TypeId::of::<T>(), cast::<T>);
+    });
+}
+
+/// Attempts to cast a node to a `Bounded` trait object.
+/// Returns `None` if the node's type has not been registered via `register_bounded()`.
+pub fn as_bounded(node: &dyn Node) -> Option<&dyn Bounded> {
+    let any:    &dyn Any              = node.as_any();
+    let caster: Option<BoundedCaster> = BOUNDED_REGISTRY.with(|registry| registry.borrow().get(&any.type_id()).copied());
+    caster.and_then(|cast| cast(any))
+}
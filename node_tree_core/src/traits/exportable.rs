@@ -28,15 +28,21 @@
 //!
 //! - All types that are to be used in the export fields of nodes must implement `Exportable;
 //! Implementing a `Exportable` trait is quite simple, with there being only two functions for
-//! serializing and deserializing a value. There can also be "ghost" exportables, which do not save
+//! serializing and deserializing a value. Types may also describe the shape of their serialized
+//! form, which allows for more detailed `ExportError`s when a value fails to load. There can also be "ghost" exportables, which do not save
 //! or serialize any data. Please see the documentation for `Exportable::is_ghost_export()` for
 //! more detail.
 //! 
 
 use std::{
+    any,
+    borrow::Cow,
+    error,
+    fmt,
     collections::{ BTreeMap, BTreeSet, HashMap, HashSet },
     mem,
-    ops::Deref,
+    ops::{ Deref, Range, RangeInclusive },
+    marker::PhantomData,
     path,
     str::FromStr,
     time,
@@ -58,6 +64,90 @@ pub trait Voidable {
 }
 
 
+/// The kinds of values that may appear in a `toml` document.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ValueKind {
+    String,
+    Integer,
+    Float,
+    Boolean,
+    Datetime,
+    Array,
+    Table
+}
+
+impl ValueKind {
+    
+    /// Gets the kind of a `toml` value.
+    pub fn of(value: &toml::Value) -> Self {
+        match value {
+            toml::Value::String(_)      => ValueKind::String,
+            toml::Value::Integer(_)     => ValueKind::Integer,
+            toml::Value::Float(_)       => ValueKind::Float,
+            toml::Value::Boolean(_)     => ValueKind::Boolean,
+            toml::Value::Datetime(_)    => ValueKind::Datetime,
+            toml::Value::Array(_)       => ValueKind::Array,
+            toml::Value::InlineTable(_) => ValueKind::Table
+        }
+    }
+}
+
+impl fmt::Display for ValueKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            ValueKind::String   => "a string",
+            ValueKind::Integer  => "an integer",
+            ValueKind::Float    => "a float",
+            ValueKind::Boolean  => "a boolean",
+            ValueKind::Datetime => "a datetime",
+            ValueKind::Array    => "an array",
+            ValueKind::Table    => "a table"
+        })
+    }
+}
+
+
+/// Describes why a `toml` value could not be loaded via `Exportable::try_from_value()`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ExportError {
+    
+    /// The value was of a different kind than the one the type is saved as.
+    KindMismatch { expected: ValueKind, found: ValueKind },
+
+    /// The value was an array with a different number of elements than the type is saved with.
+    LengthMismatch { expected: usize, found: usize },
+
+    /// The value was of the right shape, but could not be converted, such as an integer which is
+    /// out of range.
+    Invalid { expected: String, found: ValueKind },
+
+    /// An element of an array or an entry of a table could not be loaded.
+    /// Array elements are keyed by their index, as in `[2]`.
+    Element { key: String, error: Box<ExportError> }
+}
+
+impl ExportError {
+    
+    /// Wraps this error as having occurred at the given key of an array or table.
+    pub fn at(self, key: impl fmt::Display) -> Self {
+        ExportError::Element { key: key.to_string(), error: Box::new(self) }
+    }
+}
+
+impl fmt::Display for ExportError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ExportError::KindMismatch { expected, found }   => write!(f, "expected {expected}, found {found}"),
+            ExportError::LengthMismatch { expected, found } => write!(f, "expected an array of {expected} elements, found {found}"),
+            ExportError::Invalid { expected, found }        => write!(f, "{found} could not be converted into `{expected}`"),
+            ExportError::Element { key, error }             => write!(f, "at {key}, {error}")
+        }
+    }
+}
+
+impl error::Error for ExportError {}
+
+
 /// Used for representing types that can be parsed and loaded from `TOML` files, and as such are
 /// supported fully via `node_tree`'s saving and loading system.
 pub trait Exportable {
@@ -86,6 +176,48 @@ pub trait Exportable {
 
     /// Converts a toml value right back to its origin type.
     fn from_value(value: toml::Value) -> Option<Self> where Self: Sized;
+
+    /// Gets the kind of toml value that this type is always saved as, if any.
+    /// This is used by the default `try_from_value()` to report kind mismatches.
+    fn expected_kind() -> Option<ValueKind> where Self: Sized { None }
+
+    /// Gets the number of elements that this type is always saved with if it is saved as an array,
+    /// such as for tuples or vectors of a fixed size.
+    /// This is used by the default `try_from_value()` to report length mismatches.
+    fn expected_len() -> Option<usize> where Self: Sized { None }
+
+    /// Converts a toml value back to its origin type, describing what was wrong with the value if
+    /// it could not be converted.
+    ///
+    /// By default, this checks the value against `expected_kind()` and `expected_len()` before
+    /// wrapping `from_value()`, so existing implementations only need to override those to give
+    /// detailed errors. Containers should override this to report which element failed to load.
+    fn try_from_value(value: toml::Value) -> Result<Self, ExportError> where Self: Sized {
+        let found: ValueKind = ValueKind::of(&value);
+        if let Some(expected) = Self::expected_kind() {
+            if expected != found {
+                return Err(ExportError::KindMismatch { expected, found });
+            }
+        }
+        if let (Some(expected), Some(arr)) = (Self::expected_len(), value.as_array()) {
+            if arr.len() != expected {
+                return Err(ExportError::LengthMismatch { expected, found: arr.len() });
+            }
+        }
+        Self::from_value(value).ok_or(ExportError::Invalid { expected: any::type_name::<Self>().to_string(), found })
+    }
+
+    /// Converts a type to a toml item for when it is saved as a field of a node in a scene file.
+    /// This can be overridden to emit a standard multi-line table (or an array of tables) with
+    /// comments and a custom key order rather than an inline value, which makes complex types
+    /// easier to edit by hand and to diff.
+    /// When loaded, tables are converted back into inline tables (and arrays of tables into
+    /// arrays) before being passed to `from_value()`.
+    ///
+    /// By default, this wraps `to_value()`.
+    fn to_document_fragment(&self) -> toml::Item {
+        toml::Item::Value(self.to_value())
+    }
 }
 
 impl Exportable for () {
@@ -100,7 +232,27 @@ impl Exportable for () {
     }
 }
 
+impl <T: ?Sized> Voidable for PhantomData<T> {
+    fn void() -> Self {
+        PhantomData
+    }
+}
+
+impl <T: ?Sized> Exportable for PhantomData<T> {
+    unsafe fn is_ghost_export(&self) -> bool { true }
+
+    fn to_value(&self) -> toml::Value {
+        unimplemented!()
+    }
+
+    fn from_value(_value: toml::Value) -> Option<Self> where Self: Sized {
+        unimplemented!()
+    }
+}
+
 impl Exportable for bool {
+    fn expected_kind() -> Option<ValueKind> where Self: Sized { Some(ValueKind::Boolean) }
+
     fn to_value(&self) -> toml::Value {
         (*self).into()
     }
@@ -114,6 +266,8 @@ impl Exportable for bool {
 }
 
 impl Exportable for u8 {
+    fn expected_kind() -> Option<ValueKind> where Self: Sized { Some(ValueKind::Integer) }
+
     fn to_value(&self) -> toml::Value {
         (*self as i64).into()
     }
@@ -126,6 +280,8 @@ impl Exportable for u8 {
     }
 }
 impl Exportable for u16 {
+    fn expected_kind() -> Option<ValueKind> where Self: Sized { Some(ValueKind::Integer) }
+
     fn to_value(&self) -> toml::Value {
         (*self as i64).into()
     }
@@ -138,6 +294,8 @@ impl Exportable for u16 {
     }
 }
 impl Exportable for u32 {
+    fn expected_kind() -> Option<ValueKind> where Self: Sized { Some(ValueKind::Integer) }
+
     fn to_value(&self) -> toml::Value {
         (*self as i64).into()
     }
@@ -150,6 +308,8 @@ impl Exportable for u32 {
     }
 }
 impl Exportable for u64 {
+    fn expected_kind() -> Option<ValueKind> where Self: Sized { Some(ValueKind::Integer) }
+
     fn to_value(&self) -> toml::Value {
         (*self as i64).into()
     }
@@ -162,6 +322,8 @@ impl Exportable for u64 {
     }
 }
 impl Exportable for i8 {
+    fn expected_kind() -> Option<ValueKind> where Self: Sized { Some(ValueKind::Integer) }
+
     fn to_value(&self) -> toml::Value {
         (*self as i64).into()
     }
@@ -174,6 +336,8 @@ impl Exportable for i8 {
     }
 }
 impl Exportable for i16 {
+    fn expected_kind() -> Option<ValueKind> where Self: Sized { Some(ValueKind::Integer) }
+
     fn to_value(&self) -> toml::Value {
         (*self as i64).into()
     }
@@ -186,6 +350,8 @@ impl Exportable for i16 {
     }
 }
 impl Exportable for i32 {
+    fn expected_kind() -> Option<ValueKind> where Self: Sized { Some(ValueKind::Integer) }
+
     fn to_value(&self) -> toml::Value {
         (*self as i64).into()
     }
@@ -198,6 +364,8 @@ impl Exportable for i32 {
     }
 }
 impl Exportable for i64 {
+    fn expected_kind() -> Option<ValueKind> where Self: Sized { Some(ValueKind::Integer) }
+
     fn to_value(&self) -> toml::Value {
         (*self).into()
     }
@@ -210,6 +378,8 @@ impl Exportable for i64 {
     }
 }
 impl Exportable for f32 {
+    fn expected_kind() -> Option<ValueKind> where Self: Sized { Some(ValueKind::Float) }
+
     fn to_value(&self) -> toml::Value {
         (*self as f64).into()
     }
@@ -222,6 +392,8 @@ impl Exportable for f32 {
     }
 }
 impl Exportable for f64 {
+    fn expected_kind() -> Option<ValueKind> where Self: Sized { Some(ValueKind::Float) }
+
     fn to_value(&self) -> toml::Value {
         (*self).into()
     }
@@ -235,6 +407,8 @@ impl Exportable for f64 {
 }
 
 impl Exportable for char {
+    fn expected_kind() -> Option<ValueKind> where Self: Sized { Some(ValueKind::String) }
+
     fn to_value(&self) -> toml::Value {
         self.to_string().into()
     }
@@ -254,6 +428,8 @@ impl Exportable for char {
     }
 }
 impl Exportable for String {
+    fn expected_kind() -> Option<ValueKind> where Self: Sized { Some(ValueKind::String) }
+
     fn to_value(&self) -> toml::Value {
         self.to_owned().into()
     }
@@ -265,7 +441,53 @@ impl Exportable for String {
         }
     }
 }
+impl Exportable for Box<str> {
+    fn expected_kind() -> Option<ValueKind> where Self: Sized { Some(ValueKind::String) }
+
+    fn to_value(&self) -> toml::Value {
+        self.deref().into()
+    }
+
+    fn from_value(value: toml::Value) -> Option<Self> where Self: Sized {
+        String::from_value(value).map(|s| s.into_boxed_str())
+    }
+}
+impl Exportable for std::rc::Rc<str> {
+    fn expected_kind() -> Option<ValueKind> where Self: Sized { Some(ValueKind::String) }
+
+    fn to_value(&self) -> toml::Value {
+        self.deref().into()
+    }
+
+    fn from_value(value: toml::Value) -> Option<Self> where Self: Sized {
+        String::from_value(value).map(|s| s.into())
+    }
+}
+impl Exportable for std::sync::Arc<str> {
+    fn expected_kind() -> Option<ValueKind> where Self: Sized { Some(ValueKind::String) }
+
+    fn to_value(&self) -> toml::Value {
+        self.deref().into()
+    }
+
+    fn from_value(value: toml::Value) -> Option<Self> where Self: Sized {
+        String::from_value(value).map(|s| s.into())
+    }
+}
+impl Exportable for Cow<'static, str> {
+    fn expected_kind() -> Option<ValueKind> where Self: Sized { Some(ValueKind::String) }
+
+    fn to_value(&self) -> toml::Value {
+        self.deref().into()
+    }
+
+    fn from_value(value: toml::Value) -> Option<Self> where Self: Sized {
+        String::from_value(value).map(Cow::Owned)
+    }
+}
 impl Exportable for NodePath {
+    fn expected_kind() -> Option<ValueKind> where Self: Sized { Some(ValueKind::String) }
+
     fn to_value(&self) -> toml::Value {
         self.to_owned().to_string().to_value()
     }
@@ -275,6 +497,8 @@ impl Exportable for NodePath {
     }
 }
 impl Exportable for path::PathBuf {
+    fn expected_kind() -> Option<ValueKind> where Self: Sized { Some(ValueKind::String) }
+
     fn to_value(&self) -> toml::Value {
         self.to_str().expect("Invalid unicode").to_owned().to_value()
     }
@@ -285,6 +509,8 @@ impl Exportable for path::PathBuf {
 }
 
 impl Exportable for net::Ipv4Addr {
+    fn expected_kind() -> Option<ValueKind> where Self: Sized { Some(ValueKind::String) }
+
     fn to_value(&self) -> toml::Value {
         self.to_string().to_value()
     }
@@ -294,6 +520,8 @@ impl Exportable for net::Ipv4Addr {
     }
 }
 impl Exportable for net::Ipv6Addr {
+    fn expected_kind() -> Option<ValueKind> where Self: Sized { Some(ValueKind::String) }
+
     fn to_value(&self) -> toml::Value {
         self.to_string().to_value()
     }
@@ -303,6 +531,8 @@ impl Exportable for net::Ipv6Addr {
     }
 }
 impl Exportable for net::IpAddr {
+    fn expected_kind() -> Option<ValueKind> where Self: Sized { Some(ValueKind::String) }
+
     fn to_value(&self) -> toml::Value {
         self.to_string().to_value()
     }
@@ -313,6 +543,8 @@ impl Exportable for net::IpAddr {
 }
 
 impl Exportable for time::Duration {
+    fn expected_kind() -> Option<ValueKind> where Self: Sized { Some(ValueKind::Float) }
+
     fn to_value(&self) -> toml::Value {
         self.as_secs_f64().to_value()
     }
@@ -322,6 +554,8 @@ impl Exportable for time::Duration {
     }
 }
 impl Exportable for toml::Datetime {
+    fn expected_kind() -> Option<ValueKind> where Self: Sized { Some(ValueKind::Datetime) }
+
     fn to_value(&self) -> toml::Value {
         toml::Value::Datetime(toml::Formatted::new(self.to_owned()))
     }
@@ -335,6 +569,8 @@ impl Exportable for toml::Datetime {
 }
 
 impl <T: Exportable> Exportable for Option<T> {
+    fn expected_kind() -> Option<ValueKind> where Self: Sized { Some(ValueKind::Table) }
+
     fn to_value(&self) -> toml::Value {
         let map: toml::InlineTable = match self {
             Some(value) => toml::InlineTable::from_iter(vec![("value".to_string(), value.to_value())]),
@@ -354,9 +590,47 @@ impl <T: Exportable> Exportable for Option<T> {
         }
         
     }
+
+    fn try_from_value(value: toml::Value) -> Result<Self, ExportError> where Self: Sized {
+        match value {
+            toml::Value::InlineTable(mut table) => match table.remove("value") {
+                Some(value) => T::try_from_value(value).map(Some).map_err(|err| err.at("`value`")),
+                None        => Ok(None)
+            },
+            value => Err(ExportError::KindMismatch { expected: ValueKind::Table, found: ValueKind::of(&value) })
+        }
+    }
+}
+
+/// Results are saved as an inline table with either an `ok` or an `err` key.
+/// Loading fails if both or neither of the keys are present.
+impl <T: Exportable, E: Exportable> Exportable for Result<T, E> {
+    fn expected_kind() -> Option<ValueKind> where Self: Sized { Some(ValueKind::Table) }
+
+    fn to_value(&self) -> toml::Value {
+        let map: toml::InlineTable = match self {
+            Ok(value) => toml::InlineTable::from_iter(vec![("ok".to_string(), value.to_value())]),
+            Err(err)  => toml::InlineTable::from_iter(vec![("err".to_string(), err.to_value())])
+        };
+
+        map.into()
+    }
+
+    fn from_value(value: toml::Value) -> Option<Self> where Self: Sized {
+        match value {
+            toml::Value::InlineTable(mut table) => match (table.remove("ok"), table.remove("err")) {
+                (Some(value), None) => Some(Ok(T::from_value(value)?)),
+                (None, Some(err))   => Some(Err(E::from_value(err)?)),
+                _                   => None
+            },
+            _ => None
+        }
+    }
 }
 
 impl <T: Exportable> Exportable for Vec<T> {
+    fn expected_kind() -> Option<ValueKind> where Self: Sized { Some(ValueKind::Array) }
+
     fn to_value(&self) -> toml::Value {
         let arr: toml::Array = toml::Array::from_iter(self.iter().map(|v| (v.to_owned()).to_value()));
         arr.into()
@@ -368,9 +642,18 @@ impl <T: Exportable> Exportable for Vec<T> {
             _                       => None
         }
     }
+
+    fn try_from_value(value: toml::Value) -> Result<Self, ExportError> where Self: Sized {
+        match value {
+            toml::Value::Array(arr) => arr.into_iter().enumerate().map(|(i, x)| T::try_from_value(x).map_err(|err| err.at(format!("[{i}]")))).collect(),
+            value                   => Err(ExportError::KindMismatch { expected: ValueKind::Array, found: ValueKind::of(&value) })
+        }
+    }
 }
 
 impl <T: Exportable + hash::Hash + cmp::Eq> Exportable for HashSet<T> {
+    fn expected_kind() -> Option<ValueKind> where Self: Sized { Some(ValueKind::Array) }
+
     fn to_value(&self) -> toml::Value {
         let arr: toml::Array = toml::Array::from_iter(self.iter().map(|x| x.to_value()));
         toml::Value::Array(arr)
@@ -384,6 +667,8 @@ impl <T: Exportable + hash::Hash + cmp::Eq> Exportable for HashSet<T> {
     }
 }
 impl <V: Exportable> Exportable for HashMap<char, V> {
+    fn expected_kind() -> Option<ValueKind> where Self: Sized { Some(ValueKind::Table) }
+
     fn to_value(&self) -> toml::Value {
         self.iter().map(|(k, v)| (k.to_string(), (v.to_owned()).to_value())).collect::<toml::InlineTable>().into()
     }
@@ -407,6 +692,8 @@ impl <V: Exportable> Exportable for HashMap<char, V> {
     }
 }
 impl <V: Exportable> Exportable for HashMap<String, V> {
+    fn expected_kind() -> Option<ValueKind> where Self: Sized { Some(ValueKind::Table) }
+
     fn to_value(&self) -> toml::Value {
         self.iter().map(|(k, v)| (k.to_owned(), (v.to_owned()).to_value())).collect::<toml::InlineTable>().into()
     }
@@ -421,9 +708,22 @@ impl <V: Exportable> Exportable for HashMap<String, V> {
             _ => None
         }
     }
+
+    fn try_from_value(value: toml::Value) -> Result<Self, ExportError> where Self: Sized {
+        match value {
+            toml::Value::InlineTable(table) => {
+                table.into_iter()
+                    .map(|(key, value)| V::try_from_value(value).map(|value| (key.to_string(), value)).map_err(|err| err.at(format!("`{key}`"))))
+                    .collect()
+            },
+            value => Err(ExportError::KindMismatch { expected: ValueKind::Table, found: ValueKind::of(&value) })
+        }
+    }
 }
 
 impl <T: Exportable + cmp::Ord> Exportable for BTreeSet<T> {
+    fn expected_kind() -> Option<ValueKind> where Self: Sized { Some(ValueKind::Array) }
+
     fn to_value(&self) -> toml::Value {
         let arr: toml::Array = toml::Array::from_iter(self.iter().map(|x| x.to_value()));
         toml::Value::Array(arr)
@@ -437,6 +737,8 @@ impl <T: Exportable + cmp::Ord> Exportable for BTreeSet<T> {
     }
 }
 impl <V: Exportable> Exportable for BTreeMap<char, V> {
+    fn expected_kind() -> Option<ValueKind> where Self: Sized { Some(ValueKind::Table) }
+
     fn to_value(&self) -> toml::Value {
         self.iter().map(|(k, v)| (k.to_string(), (v.to_owned()).to_value())).collect::<toml::InlineTable>().into()
     }
@@ -460,6 +762,8 @@ impl <V: Exportable> Exportable for BTreeMap<char, V> {
     }
 }
 impl <V: Exportable> Exportable for BTreeMap<String, V> {
+    fn expected_kind() -> Option<ValueKind> where Self: Sized { Some(ValueKind::Table) }
+
     fn to_value(&self) -> toml::Value {
         self.iter().map(|(k, v)| (k.to_owned(), (v.to_owned()).to_value())).collect::<toml::InlineTable>().into()
     }
@@ -474,6 +778,17 @@ impl <V: Exportable> Exportable for BTreeMap<String, V> {
             _ => None
         }
     }
+
+    fn try_from_value(value: toml::Value) -> Result<Self, ExportError> where Self: Sized {
+        match value {
+            toml::Value::InlineTable(table) => {
+                table.into_iter()
+                    .map(|(key, value)| V::try_from_value(value).map(|value| (key.to_string(), value)).map_err(|err| err.at(format!("`{key}`"))))
+                    .collect()
+            },
+            value => Err(ExportError::KindMismatch { expected: ValueKind::Table, found: ValueKind::of(&value) })
+        }
+    }
 }
 
 impl <T: Exportable> Exportable for Box<T> {
@@ -484,6 +799,17 @@ impl <T: Exportable> Exportable for Box<T> {
     fn from_value(value: toml::Value) -> Option<Self> where Self: Sized {
         T::from_value(value).map(|x| Box::new(x))
     }
+
+    fn expected_kind() -> Option<ValueKind> where Self: Sized { T::expected_kind() }
+    fn expected_len()  -> Option<usize>     where Self: Sized { T::expected_len() }
+
+    fn try_from_value(value: toml::Value) -> Result<Self, ExportError> where Self: Sized {
+        T::try_from_value(value).map(Box::new)
+    }
+
+    fn to_document_fragment(&self) -> toml::Item {
+        self.deref().to_document_fragment()
+    }
 }
 
 impl <T: Exportable> Exportable for std::rc::Rc<T> {
@@ -494,6 +820,17 @@ impl <T: Exportable> Exportable for std::rc::Rc<T> {
     fn from_value(value: toml::Value) -> Option<Self> where Self: Sized {
         T::from_value(value).map(|x| std::rc::Rc::new(x))
     }
+
+    fn expected_kind() -> Option<ValueKind> where Self: Sized { T::expected_kind() }
+    fn expected_len()  -> Option<usize>     where Self: Sized { T::expected_len() }
+
+    fn try_from_value(value: toml::Value) -> Result<Self, ExportError> where Self: Sized {
+        T::try_from_value(value).map(std::rc::Rc::new)
+    }
+
+    fn to_document_fragment(&self) -> toml::Item {
+        self.deref().to_document_fragment()
+    }
 }
 
 impl <T: Exportable> Exportable for std::sync::Arc<T> {
@@ -504,6 +841,17 @@ impl <T: Exportable> Exportable for std::sync::Arc<T> {
     fn from_value(value: toml::Value) -> Option<Self> where Self: Sized {
         T::from_value(value).map(|x| std::sync::Arc::new(x))
     }
+
+    fn expected_kind() -> Option<ValueKind> where Self: Sized { T::expected_kind() }
+    fn expected_len()  -> Option<usize>     where Self: Sized { T::expected_len() }
+
+    fn try_from_value(value: toml::Value) -> Result<Self, ExportError> where Self: Sized {
+        T::try_from_value(value).map(std::sync::Arc::new)
+    }
+
+    fn to_document_fragment(&self) -> toml::Item {
+        self.deref().to_document_fragment()
+    }
 }
 
 impl <T: Exportable> Exportable for std::sync::Mutex<T> {
@@ -514,9 +862,19 @@ impl <T: Exportable> Exportable for std::sync::Mutex<T> {
     fn from_value(value: toml::Value) -> Option<Self> where Self: Sized {
         T::from_value(value).map(|x| std::sync::Mutex::new(x))
     }
+
+    fn expected_kind() -> Option<ValueKind> where Self: Sized { T::expected_kind() }
+    fn expected_len()  -> Option<usize>     where Self: Sized { T::expected_len() }
+
+    fn try_from_value(value: toml::Value) -> Result<Self, ExportError> where Self: Sized {
+        T::try_from_value(value).map(std::sync::Mutex::new)
+    }
 }
 
 impl <const N: usize, T: Exportable> Exportable for [T; N] {
+    fn expected_kind() -> Option<ValueKind> where Self: Sized { Some(ValueKind::Array) }
+    fn expected_len()  -> Option<usize>     where Self: Sized { Some(N) }
+
     fn to_value(&self) -> toml::Value {
         toml::Value::Array(self.iter().map(|x| x.to_value()).collect())
     }
@@ -537,9 +895,72 @@ impl <const N: usize, T: Exportable> Exportable for [T; N] {
             _ => None
         }
     }
+
+    fn try_from_value(value: toml::Value) -> Result<Self, ExportError> where Self: Sized {
+        match value {
+            toml::Value::Array(arr) => {
+                if arr.len() != N {
+                    return Err(ExportError::LengthMismatch { expected: N, found: arr.len() });
+                }
+
+                let elements: Vec<T> = arr.into_iter().enumerate().map(|(i, x)| T::try_from_value(x).map_err(|err| err.at(format!("[{i}]")))).collect::<Result<_, _>>()?;
+                Ok(elements.try_into().unwrap_or_else(|_| unreachable!()))
+            },
+            value => Err(ExportError::KindMismatch { expected: ValueKind::Array, found: ValueKind::of(&value) })
+        }
+    }
+}
+
+impl <T: Exportable> Exportable for Range<T> {
+    fn expected_kind() -> Option<ValueKind> where Self: Sized { Some(ValueKind::Table) }
+
+    fn to_value(&self) -> toml::Value {
+        let mut table: toml::InlineTable = toml::InlineTable::new();
+                table.insert("start", self.start.to_value());
+                table.insert("end",   self.end.to_value());
+        toml::Value::InlineTable(table)
+    }
+
+    fn from_value(value: toml::Value) -> Option<Self> where Self: Sized {
+        match value {
+            toml::Value::InlineTable(mut table) => {
+                if table.get("inclusive").and_then(|inclusive| inclusive.as_bool()).unwrap_or(false) {
+                    return None;
+                }
+                Some(T::from_value(table.remove("start")?)?..T::from_value(table.remove("end")?)?)
+            },
+            _ => None
+        }
+    }
+}
+impl <T: Exportable> Exportable for RangeInclusive<T> {
+    fn expected_kind() -> Option<ValueKind> where Self: Sized { Some(ValueKind::Table) }
+
+    fn to_value(&self) -> toml::Value {
+        let mut table: toml::InlineTable = toml::InlineTable::new();
+                table.insert("start",     self.start().to_value());
+                table.insert("end",       self.end().to_value());
+                table.insert("inclusive", true.into());
+        toml::Value::InlineTable(table)
+    }
+
+    fn from_value(value: toml::Value) -> Option<Self> where Self: Sized {
+        match value {
+            toml::Value::InlineTable(mut table) => {
+                if !table.get("inclusive").and_then(|inclusive| inclusive.as_bool()).unwrap_or(false) {
+                    return None;
+                }
+                Some(T::from_value(table.remove("start")?)?..=T::from_value(table.remove("end")?)?)
+            },
+            _ => None
+        }
+    }
 }
 
 impl <A: Exportable> Exportable for (A,) {
+    fn expected_kind() -> Option<ValueKind> where Self: Sized { Some(ValueKind::Array) }
+    fn expected_len()  -> Option<usize>     where Self: Sized { Some(1) }
+
     fn to_value(&self) -> toml::Value {
         toml::Value::Array(toml::Array::from_iter(vec![self.0.to_value()]))
     }
@@ -557,6 +978,9 @@ impl <A: Exportable> Exportable for (A,) {
     }
 }
 impl <A: Exportable, B: Exportable> Exportable for (A, B) {
+    fn expected_kind() -> Option<ValueKind> where Self: Sized { Some(ValueKind::Array) }
+    fn expected_len()  -> Option<usize>     where Self: Sized { Some(2) }
+
     fn to_value(&self) -> toml::Value {
         toml::Value::Array(toml::Array::from_iter(vec![self.0.to_value(), self.1.to_value()]))
     }
@@ -574,6 +998,9 @@ impl <A: Exportable, B: Exportable> Exportable for (A, B) {
     }
 }
 impl <A: Exportable, B: Exportable, C: Exportable> Exportable for (A, B, C) {
+    fn expected_kind() -> Option<ValueKind> where Self: Sized { Some(ValueKind::Array) }
+    fn expected_len()  -> Option<usize>     where Self: Sized { Some(3) }
+
     fn to_value(&self) -> toml::Value {
         toml::Value::Array(toml::Array::from_iter(vec![self.0.to_value(), self.1.to_value(), self.2.to_value()]))
     }
@@ -591,6 +1018,9 @@ impl <A: Exportable, B: Exportable, C: Exportable> Exportable for (A, B, C) {
     }
 }
 impl <A: Exportable, B: Exportable, C: Exportable, D: Exportable> Exportable for (A, B, C, D) {
+    fn expected_kind() -> Option<ValueKind> where Self: Sized { Some(ValueKind::Array) }
+    fn expected_len()  -> Option<usize>     where Self: Sized { Some(4) }
+
     fn to_value(&self) -> toml::Value {
         toml::Value::Array(toml::Array::from_iter(vec![self.0.to_value(), self.1.to_value(), self.2.to_value(), self.3.to_value()]))
     }
@@ -608,6 +1038,9 @@ impl <A: Exportable, B: Exportable, C: Exportable, D: Exportable> Exportable for
     }
 }
 impl <A: Exportable, B: Exportable, C: Exportable, D: Exportable, E: Exportable> Exportable for (A, B, C, D, E) {
+    fn expected_kind() -> Option<ValueKind> where Self: Sized { Some(ValueKind::Array) }
+    fn expected_len()  -> Option<usize>     where Self: Sized { Some(5) }
+
     fn to_value(&self) -> toml::Value {
         toml::Value::Array(toml::Array::from_iter(vec![self.0.to_value(), self.1.to_value(), self.2.to_value(), self.3.to_value(), self.4.to_value()]))
     }
@@ -626,6 +1059,9 @@ impl <A: Exportable, B: Exportable, C: Exportable, D: Exportable, E: Exportable>
 }
 impl <A: Exportable, B: Exportable, C: Exportable, D: Exportable, E: Exportable,
       F: Exportable> Exportable for (A, B, C, D, E, F) {
+    fn expected_kind() -> Option<ValueKind> where Self: Sized { Some(ValueKind::Array) }
+    fn expected_len()  -> Option<usize>     where Self: Sized { Some(6) }
+
     fn to_value(&self) -> toml::Value {
         toml::Value::Array(toml::Array::from_iter(vec![
             self.0.to_value(), self.1.to_value(), self.2.to_value(), self.3.to_value(), self.4.to_value(),
@@ -650,6 +1086,9 @@ impl <A: Exportable, B: Exportable, C: Exportable, D: Exportable, E: Exportable,
 }
 impl <A: Exportable, B: Exportable, C: Exportable, D: Exportable, E: Exportable,
       F: Exportable, G: Exportable> Exportable for (A, B, C, D, E, F, G) {
+    fn expected_kind() -> Option<ValueKind> where Self: Sized { Some(ValueKind::Array) }
+    fn expected_len()  -> Option<usize>     where Self: Sized { Some(7) }
+
     fn to_value(&self) -> toml::Value {
         toml::Value::Array(toml::Array::from_iter(vec![
             self.0.to_value(), self.1.to_value(), self.2.to_value(), self.3.to_value(), self.4.to_value(),
@@ -674,6 +1113,9 @@ impl <A: Exportable, B: Exportable, C: Exportable, D: Exportable, E: Exportable,
 }
 impl <A: Exportable, B: Exportable, C: Exportable, D: Exportable, E: Exportable,
       F: Exportable, G: Exportable, H: Exportable> Exportable for (A, B, C, D, E, F, G, H) {
+    fn expected_kind() -> Option<ValueKind> where Self: Sized { Some(ValueKind::Array) }
+    fn expected_len()  -> Option<usize>     where Self: Sized { Some(8) }
+
     fn to_value(&self) -> toml::Value {
         toml::Value::Array(toml::Array::from_iter(vec![
             self.0.to_value(), self.1.to_value(), self.2.to_value(), self.3.to_value(), self.4.to_value(),
@@ -698,6 +1140,9 @@ impl <A: Exportable, B: Exportable, C: Exportable, D: Exportable, E: Exportable,
 }
 impl <A: Exportable, B: Exportable, C: Exportable, D: Exportable, E: Exportable,
       F: Exportable, G: Exportable, H: Exportable, I: Exportable> Exportable for (A, B, C, D, E, F, G, H, I) {
+    fn expected_kind() -> Option<ValueKind> where Self: Sized { Some(ValueKind::Array) }
+    fn expected_len()  -> Option<usize>     where Self: Sized { Some(9) }
+
     fn to_value(&self) -> toml::Value {
         toml::Value::Array(toml::Array::from_iter(vec![
             self.0.to_value(), self.1.to_value(), self.2.to_value(), self.3.to_value(), self.4.to_value(),
@@ -722,6 +1167,9 @@ impl <A: Exportable, B: Exportable, C: Exportable, D: Exportable, E: Exportable,
 }
 impl <A: Exportable, B: Exportable, C: Exportable, D: Exportable, E: Exportable,
       F: Exportable, G: Exportable, H: Exportable, I: Exportable, J: Exportable> Exportable for (A, B, C, D, E, F, G, H, I, J) {
+    fn expected_kind() -> Option<ValueKind> where Self: Sized { Some(ValueKind::Array) }
+    fn expected_len()  -> Option<usize>     where Self: Sized { Some(10) }
+
     fn to_value(&self) -> toml::Value {
         toml::Value::Array(toml::Array::from_iter(vec![
             self.0.to_value(), self.1.to_value(), self.2.to_value(), self.3.to_value(), self.4.to_value(),
@@ -744,3 +1192,110 @@ impl <A: Exportable, B: Exportable, C: Exportable, D: Exportable, E: Exportable,
         }
     }
 }
+
+
+/*
+ * Exportable
+ *      Enums
+ */
+
+
+/// Implements `Exportable` for a fieldless (C-like) enum without needing a derive.
+///
+/// # Encodings
+/// Two encodings are supported, which are selected by an optional leading keyword:
+/// - `by_index` (the default) stores each variant as its discriminant. This is compact, but
+///   reordering the variants or changing their discriminants will break any existing save files.
+/// - `by_name` stores each variant as its name. This takes up more space, but is resistant to
+///   variants being reordered or having their discriminants changed. Renaming a variant will
+///   still break any existing save files, however.
+///
+/// Discriminants may be restated for readability, but the enum's own discriminants are what
+/// get stored. Any restated discriminant is checked against the enum's at compile time:
+/// ```rust, compile_fail
+/// use node_tree::impl_exportable_enum;
+///
+/// #[derive(Debug, Clone, Copy, PartialEq)]
+/// enum Team { Red, Blue }
+///
+/// impl_exportable_enum!(Team { Red = 5, Blue });
+/// ```
+///
+/// # Example
+/// ```rust
+/// use node_tree::impl_exportable_enum;
+///
+/// #[derive(Debug, Clone, Copy, PartialEq)]
+/// #[repr(u8)]
+/// enum Team { Red = 0, Blue = 1 }
+///
+/// #[derive(Debug, Clone, Copy, PartialEq)]
+/// enum Difficulty { Easy, Hard }
+///
+/// impl_exportable_enum!(Team { Red = 0, Blue = 1 });
+/// impl_exportable_enum!(by_name Difficulty { Easy, Hard });
+/// ```
+#[macro_export]
+macro_rules! impl_exportable_enum {
+    (by_index $name:ident { $($variant:ident $(= $disc:expr)?),* $(,)? }) => {
+        $crate::impl_exportable_enum!(@check_discriminants $name { $($variant $(= $disc)?),* });
+
+        impl $crate::traits::exportable::Exportable for $name {
+            fn expected_kind() -> Option<$crate::traits::exportable::ValueKind> where Self: Sized { Some($crate::traits::exportable::ValueKind::Integer) }
+
+            fn to_value(&self) -> $crate::services::node_registry::Value {
+                match self {
+                    $(Self::$variant => (Self::$variant as i64).into(),)*
+                }
+            }
+
+            fn from_value(value: $crate::services::node_registry::Value) -> Option<Self> where Self: Sized {
+                match value {
+                    $crate::services::node_registry::Value::Integer(i) => {
+                        let i: i64 = i.into_value();
+                        $(if i == Self::$variant as i64 { return Some(Self::$variant); })*
+                        None
+                    },
+                    _ => None
+                }
+            }
+        }
+    };
+
+    (by_name $name:ident { $($variant:ident $(= $disc:expr)?),* $(,)? }) => {
+        $crate::impl_exportable_enum!(@check_discriminants $name { $($variant $(= $disc)?),* });
+
+        impl $crate::traits::exportable::Exportable for $name {
+            fn expected_kind() -> Option<$crate::traits::exportable::ValueKind> where Self: Sized { Some($crate::traits::exportable::ValueKind::String) }
+
+            fn to_value(&self) -> $crate::services::node_registry::Value {
+                match self {
+                    $(Self::$variant => stringify!($variant).into(),)*
+                }
+            }
+
+            fn from_value(value: $crate::services::node_registry::Value) -> Option<Self> where Self: Sized {
+                match value {
+                    $crate::services::node_registry::Value::String(s) => match s.value().as_str() {
+                        $(stringify!($variant) => Some(Self::$variant),)*
+                        _ => None
+                    },
+                    _ => None
+                }
+            }
+        }
+    };
+
+    ($name:ident { $($variants:tt)* }) => {
+        $crate::impl_exportable_enum!(by_index $name { $($variants)* });
+    };
+
+    (@check_discriminants $name:ident { $($variant:ident $(= $disc:expr)?),* }) => {
+        $($(
+            const _: () = assert!(
+                $name::$variant as i64 == ($disc) as i64,
+                concat!("The discriminant given for `", stringify!($name), "::", stringify!($variant), "` does not match the enum's!")
+            );
+        )?)*
+    };
+}
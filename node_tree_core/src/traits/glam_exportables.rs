@@ -6,10 +6,13 @@ use glam::{
 };
 use toml_edit as toml;
 
-use super::exportable::Exportable;
+use super::exportable::{ Exportable, ValueKind };
 
 
 impl Exportable for g_bool::BVec2 {
+    fn expected_kind() -> Option<ValueKind> where Self: Sized { Some(ValueKind::Array) }
+    fn expected_len()  -> Option<usize>     where Self: Sized { Some(2) }
+
     fn to_value(&self) -> toml::Value {
         toml::Value::Array(toml::Array::from_iter(vec![self.x, self.y]))
     }
@@ -27,6 +30,9 @@ impl Exportable for g_bool::BVec2 {
     }
 }
 impl Exportable for g_bool::BVec3 {
+    fn expected_kind() -> Option<ValueKind> where Self: Sized { Some(ValueKind::Array) }
+    fn expected_len()  -> Option<usize>     where Self: Sized { Some(3) }
+
     fn to_value(&self) -> toml::Value {
         toml::Value::Array(toml::Array::from_iter(vec![self.x, self.y, self.z]))
     }
@@ -44,6 +50,9 @@ impl Exportable for g_bool::BVec3 {
     }
 }
 impl Exportable for g_bool::BVec4 {
+    fn expected_kind() -> Option<ValueKind> where Self: Sized { Some(ValueKind::Array) }
+    fn expected_len()  -> Option<usize>     where Self: Sized { Some(4) }
+
     fn to_value(&self) -> toml::Value {
         toml::Value::Array(toml::Array::from_iter(vec![self.x, self.y, self.z, self.w]))
     }
@@ -62,6 +71,9 @@ impl Exportable for g_bool::BVec4 {
 }
 
 impl Exportable for g_u8::U8Vec2 {
+    fn expected_kind() -> Option<ValueKind> where Self: Sized { Some(ValueKind::Array) }
+    fn expected_len()  -> Option<usize>     where Self: Sized { Some(2) }
+
     fn to_value(&self) -> toml::Value {
         toml::Value::Array(toml::Array::from_iter(self.to_array().map(|x| x as i64)))
     }
@@ -79,6 +91,9 @@ impl Exportable for g_u8::U8Vec2 {
     }
 }
 impl Exportable for g_u8::U8Vec3 {
+    fn expected_kind() -> Option<ValueKind> where Self: Sized { Some(ValueKind::Array) }
+    fn expected_len()  -> Option<usize>     where Self: Sized { Some(3) }
+
     fn to_value(&self) -> toml::Value {
         toml::Value::Array(toml::Array::from_iter(self.to_array().map(|x| x as i64)))
     }
@@ -96,6 +111,9 @@ impl Exportable for g_u8::U8Vec3 {
     }
 }
 impl Exportable for g_u8::U8Vec4 {
+    fn expected_kind() -> Option<ValueKind> where Self: Sized { Some(ValueKind::Array) }
+    fn expected_len()  -> Option<usize>     where Self: Sized { Some(4) }
+
     fn to_value(&self) -> toml::Value {
         toml::Value::Array(toml::Array::from_iter(self.to_array().map(|x| x as i64)))
     }
@@ -114,6 +132,9 @@ impl Exportable for g_u8::U8Vec4 {
 }
 
 impl Exportable for g_u16::U16Vec2 {
+    fn expected_kind() -> Option<ValueKind> where Self: Sized { Some(ValueKind::Array) }
+    fn expected_len()  -> Option<usize>     where Self: Sized { Some(2) }
+
     fn to_value(&self) -> toml::Value {
         toml::Value::Array(toml::Array::from_iter(self.to_array().map(|x| x as i64)))
     }
@@ -131,6 +152,9 @@ impl Exportable for g_u16::U16Vec2 {
     }
 }
 impl Exportable for g_u16::U16Vec3 {
+    fn expected_kind() -> Option<ValueKind> where Self: Sized { Some(ValueKind::Array) }
+    fn expected_len()  -> Option<usize>     where Self: Sized { Some(3) }
+
     fn to_value(&self) -> toml::Value {
         toml::Value::Array(toml::Array::from_iter(self.to_array().map(|x| x as i64)))
     }
@@ -148,6 +172,9 @@ impl Exportable for g_u16::U16Vec3 {
     }
 }
 impl Exportable for g_u16::U16Vec4 {
+    fn expected_kind() -> Option<ValueKind> where Self: Sized { Some(ValueKind::Array) }
+    fn expected_len()  -> Option<usize>     where Self: Sized { Some(4) }
+
     fn to_value(&self) -> toml::Value {
         toml::Value::Array(toml::Array::from_iter(self.to_array().map(|x| x as i64)))
     }
@@ -166,6 +193,9 @@ impl Exportable for g_u16::U16Vec4 {
 }
 
 impl Exportable for g_u32::UVec2 {
+    fn expected_kind() -> Option<ValueKind> where Self: Sized { Some(ValueKind::Array) }
+    fn expected_len()  -> Option<usize>     where Self: Sized { Some(2) }
+
     fn to_value(&self) -> toml::Value {
         toml::Value::Array(toml::Array::from_iter(self.to_array().map(|x| x as i64)))
     }
@@ -183,6 +213,9 @@ impl Exportable for g_u32::UVec2 {
     }
 }
 impl Exportable for g_u32::UVec3 {
+    fn expected_kind() -> Option<ValueKind> where Self: Sized { Some(ValueKind::Array) }
+    fn expected_len()  -> Option<usize>     where Self: Sized { Some(3) }
+
     fn to_value(&self) -> toml::Value {
         toml::Value::Array(toml::Array::from_iter(self.to_array().map(|x| x as i64)))
     }
@@ -200,6 +233,9 @@ impl Exportable for g_u32::UVec3 {
     }
 }
 impl Exportable for g_u32::UVec4 {
+    fn expected_kind() -> Option<ValueKind> where Self: Sized { Some(ValueKind::Array) }
+    fn expected_len()  -> Option<usize>     where Self: Sized { Some(4) }
+
     fn to_value(&self) -> toml::Value {
         toml::Value::Array(toml::Array::from_iter(self.to_array().map(|x| x as i64)))
     }
@@ -218,6 +254,9 @@ impl Exportable for g_u32::UVec4 {
 }
 
 impl Exportable for g_u64::U64Vec2 {
+    fn expected_kind() -> Option<ValueKind> where Self: Sized { Some(ValueKind::Array) }
+    fn expected_len()  -> Option<usize>     where Self: Sized { Some(2) }
+
     fn to_value(&self) -> toml::Value {
         toml::Value::Array(toml::Array::from_iter(self.to_array().map(|x| x as i64)))
     }
@@ -235,6 +274,9 @@ impl Exportable for g_u64::U64Vec2 {
     }
 }
 impl Exportable for g_u64::U64Vec3 {
+    fn expected_kind() -> Option<ValueKind> where Self: Sized { Some(ValueKind::Array) }
+    fn expected_len()  -> Option<usize>     where Self: Sized { Some(3) }
+
     fn to_value(&self) -> toml::Value {
         toml::Value::Array(toml::Array::from_iter(self.to_array().map(|x| x as i64)))
     }
@@ -252,6 +294,9 @@ impl Exportable for g_u64::U64Vec3 {
     }
 }
 impl Exportable for g_u64::U64Vec4 {
+    fn expected_kind() -> Option<ValueKind> where Self: Sized { Some(ValueKind::Array) }
+    fn expected_len()  -> Option<usize>     where Self: Sized { Some(4) }
+
     fn to_value(&self) -> toml::Value {
         toml::Value::Array(toml::Array::from_iter(self.to_array().map(|x| x as i64)))
     }
@@ -270,6 +315,9 @@ impl Exportable for g_u64::U64Vec4 {
 }
 
 impl Exportable for g_i8::I8Vec2 {
+    fn expected_kind() -> Option<ValueKind> where Self: Sized { Some(ValueKind::Array) }
+    fn expected_len()  -> Option<usize>     where Self: Sized { Some(2) }
+
     fn to_value(&self) -> toml::Value {
         toml::Value::Array(toml::Array::from_iter(self.to_array().map(|x| x as i64)))
     }
@@ -287,6 +335,9 @@ impl Exportable for g_i8::I8Vec2 {
     }
 }
 impl Exportable for g_i8::I8Vec3 {
+    fn expected_kind() -> Option<ValueKind> where Self: Sized { Some(ValueKind::Array) }
+    fn expected_len()  -> Option<usize>     where Self: Sized { Some(3) }
+
     fn to_value(&self) -> toml::Value {
         toml::Value::Array(toml::Array::from_iter(self.to_array().map(|x| x as i64)))
     }
@@ -304,6 +355,9 @@ impl Exportable for g_i8::I8Vec3 {
     }
 }
 impl Exportable for g_i8::I8Vec4 {
+    fn expected_kind() -> Option<ValueKind> where Self: Sized { Some(ValueKind::Array) }
+    fn expected_len()  -> Option<usize>     where Self: Sized { Some(4) }
+
     fn to_value(&self) -> toml::Value {
         toml::Value::Array(toml::Array::from_iter(self.to_array().map(|x| x as i64)))
     }
@@ -322,6 +376,9 @@ impl Exportable for g_i8::I8Vec4 {
 }
 
 impl Exportable for g_i16::I16Vec2 {
+    fn expected_kind() -> Option<ValueKind> where Self: Sized { Some(ValueKind::Array) }
+    fn expected_len()  -> Option<usize>     where Self: Sized { Some(2) }
+
     fn to_value(&self) -> toml::Value {
         toml::Value::Array(toml::Array::from_iter(self.to_array().map(|x| x as i64)))
     }
@@ -339,6 +396,9 @@ impl Exportable for g_i16::I16Vec2 {
     }
 }
 impl Exportable for g_i16::I16Vec3 {
+    fn expected_kind() -> Option<ValueKind> where Self: Sized { Some(ValueKind::Array) }
+    fn expected_len()  -> Option<usize>     where Self: Sized { Some(3) }
+
     fn to_value(&self) -> toml::Value {
         toml::Value::Array(toml::Array::from_iter(self.to_array().map(|x| x as i64)))
     }
@@ -356,6 +416,9 @@ impl Exportable for g_i16::I16Vec3 {
     }
 }
 impl Exportable for g_i16::I16Vec4 {
+    fn expected_kind() -> Option<ValueKind> where Self: Sized { Some(ValueKind::Array) }
+    fn expected_len()  -> Option<usize>     where Self: Sized { Some(4) }
+
     fn to_value(&self) -> toml::Value {
         toml::Value::Array(toml::Array::from_iter(self.to_array().map(|x| x as i64)))
     }
@@ -374,6 +437,9 @@ impl Exportable for g_i16::I16Vec4 {
 }
 
 impl Exportable for g_i32::IVec2 {
+    fn expected_kind() -> Option<ValueKind> where Self: Sized { Some(ValueKind::Array) }
+    fn expected_len()  -> Option<usize>     where Self: Sized { Some(2) }
+
     fn to_value(&self) -> toml::Value {
         toml::Value::Array(toml::Array::from_iter(self.to_array().map(|x| x as i64)))
     }
@@ -391,6 +457,9 @@ impl Exportable for g_i32::IVec2 {
     }
 }
 impl Exportable for g_i32::IVec3 {
+    fn expected_kind() -> Option<ValueKind> where Self: Sized { Some(ValueKind::Array) }
+    fn expected_len()  -> Option<usize>     where Self: Sized { Some(3) }
+
     fn to_value(&self) -> toml::Value {
         toml::Value::Array(toml::Array::from_iter(self.to_array().map(|x| x as i64)))
     }
@@ -408,6 +477,9 @@ impl Exportable for g_i32::IVec3 {
     }
 }
 impl Exportable for g_i32::IVec4 {
+    fn expected_kind() -> Option<ValueKind> where Self: Sized { Some(ValueKind::Array) }
+    fn expected_len()  -> Option<usize>     where Self: Sized { Some(4) }
+
     fn to_value(&self) -> toml::Value {
         toml::Value::Array(toml::Array::from_iter(self.to_array().map(|x| x as i64)))
     }
@@ -426,6 +498,9 @@ impl Exportable for g_i32::IVec4 {
 }
 
 impl Exportable for g_i64::I64Vec2 {
+    fn expected_kind() -> Option<ValueKind> where Self: Sized { Some(ValueKind::Array) }
+    fn expected_len()  -> Option<usize>     where Self: Sized { Some(2) }
+
     fn to_value(&self) -> toml::Value {
         toml::Value::Array(toml::Array::from_iter(self.to_array()))
     }
@@ -443,6 +518,9 @@ impl Exportable for g_i64::I64Vec2 {
     }
 }
 impl Exportable for g_i64::I64Vec3 {
+    fn expected_kind() -> Option<ValueKind> where Self: Sized { Some(ValueKind::Array) }
+    fn expected_len()  -> Option<usize>     where Self: Sized { Some(3) }
+
     fn to_value(&self) -> toml::Value {
         toml::Value::Array(toml::Array::from_iter(self.to_array()))
     }
@@ -460,6 +538,9 @@ impl Exportable for g_i64::I64Vec3 {
     }
 }
 impl Exportable for g_i64::I64Vec4 {
+    fn expected_kind() -> Option<ValueKind> where Self: Sized { Some(ValueKind::Array) }
+    fn expected_len()  -> Option<usize>     where Self: Sized { Some(4) }
+
     fn to_value(&self) -> toml::Value {
         toml::Value::Array(toml::Array::from_iter(self.to_array()))
     }
@@ -478,6 +559,9 @@ impl Exportable for g_i64::I64Vec4 {
 }
 
 impl Exportable for g_f32::Vec2 {
+    fn expected_kind() -> Option<ValueKind> where Self: Sized { Some(ValueKind::Array) }
+    fn expected_len()  -> Option<usize>     where Self: Sized { Some(2) }
+
     fn to_value(&self) -> toml::Value {
         toml::Value::Array(toml::Array::from_iter(self.to_array().map(|x| x as f64)))
     }
@@ -495,6 +579,9 @@ impl Exportable for g_f32::Vec2 {
     }
 }
 impl Exportable for g_f32::Vec3 {
+    fn expected_kind() -> Option<ValueKind> where Self: Sized { Some(ValueKind::Array) }
+    fn expected_len()  -> Option<usize>     where Self: Sized { Some(3) }
+
     fn to_value(&self) -> toml::Value {
         toml::Value::Array(toml::Array::from_iter(self.to_array().map(|x| x as f64)))
     }
@@ -512,6 +599,9 @@ impl Exportable for g_f32::Vec3 {
     }
 }
 impl Exportable for g_f32::Vec3A {
+    fn expected_kind() -> Option<ValueKind> where Self: Sized { Some(ValueKind::Array) }
+    fn expected_len()  -> Option<usize>     where Self: Sized { Some(3) }
+
     fn to_value(&self) -> toml::Value {
         toml::Value::Array(toml::Array::from_iter(self.to_array().map(|x| x as f64)))
     }
@@ -529,6 +619,9 @@ impl Exportable for g_f32::Vec3A {
     }
 }
 impl Exportable for g_f32::Vec4 {
+    fn expected_kind() -> Option<ValueKind> where Self: Sized { Some(ValueKind::Array) }
+    fn expected_len()  -> Option<usize>     where Self: Sized { Some(4) }
+
     fn to_value(&self) -> toml::Value {
         toml::Value::Array(toml::Array::from_iter(self.to_array().map(|x| x as f64)))
     }
@@ -546,6 +639,9 @@ impl Exportable for g_f32::Vec4 {
     }
 }
 impl Exportable for g_f32::Mat2 {
+    fn expected_kind() -> Option<ValueKind> where Self: Sized { Some(ValueKind::Array) }
+    fn expected_len()  -> Option<usize>     where Self: Sized { Some(2) }
+
     fn to_value(&self) -> toml::Value {
         toml::Value::Array(toml::Array::from_iter(vec![self.x_axis.to_value(), self.y_axis.to_value()]))
     }
@@ -563,6 +659,9 @@ impl Exportable for g_f32::Mat2 {
     }
 }
 impl Exportable for g_f32::Mat3 {
+    fn expected_kind() -> Option<ValueKind> where Self: Sized { Some(ValueKind::Array) }
+    fn expected_len()  -> Option<usize>     where Self: Sized { Some(3) }
+
     fn to_value(&self) -> toml::Value {
         toml::Value::Array(toml::Array::from_iter(vec![self.x_axis.to_value(), self.y_axis.to_value(), self.z_axis.to_value()]))
     }
@@ -580,6 +679,9 @@ impl Exportable for g_f32::Mat3 {
     }
 }
 impl Exportable for g_f32::Mat3A {
+    fn expected_kind() -> Option<ValueKind> where Self: Sized { Some(ValueKind::Array) }
+    fn expected_len()  -> Option<usize>     where Self: Sized { Some(3) }
+
     fn to_value(&self) -> toml::Value {
         toml::Value::Array(toml::Array::from_iter(vec![self.x_axis.to_value(), self.y_axis.to_value(), self.z_axis.to_value()]))
     }
@@ -597,6 +699,9 @@ impl Exportable for g_f32::Mat3A {
     }
 }
 impl Exportable for g_f32::Mat4 {
+    fn expected_kind() -> Option<ValueKind> where Self: Sized { Some(ValueKind::Array) }
+    fn expected_len()  -> Option<usize>     where Self: Sized { Some(4) }
+
     fn to_value(&self) -> toml::Value {
         toml::Value::Array(toml::Array::from_iter(vec![self.x_axis.to_value(), self.y_axis.to_value(), self.z_axis.to_value(), self.w_axis.to_value()]))
     }
@@ -617,6 +722,9 @@ impl Exportable for g_f32::Mat4 {
     }
 }
 impl Exportable for g_f32::Quat {
+    fn expected_kind() -> Option<ValueKind> where Self: Sized { Some(ValueKind::Array) }
+    fn expected_len()  -> Option<usize>     where Self: Sized { Some(4) }
+
     fn to_value(&self) -> toml::Value {
         toml::Value::Array(toml::Array::from_iter(vec![self.x as f64, self.y as f64, self.z as f64, self.w as f64]))
     }
@@ -634,6 +742,8 @@ impl Exportable for g_f32::Quat {
     }
 }
 impl Exportable for g_f32::Affine2 {
+    fn expected_kind() -> Option<ValueKind> where Self: Sized { Some(ValueKind::Table) }
+
     fn to_value(&self) -> toml_edit::Value {
         let mut table: toml::InlineTable = toml::InlineTable::new();
                 table.insert("matrix2",     self.matrix2.to_value());
@@ -652,6 +762,8 @@ impl Exportable for g_f32::Affine2 {
     }
 }
 impl Exportable for g_f32::Affine3A {
+    fn expected_kind() -> Option<ValueKind> where Self: Sized { Some(ValueKind::Table) }
+
     fn to_value(&self) -> toml_edit::Value {
         let mut table: toml::InlineTable = toml::InlineTable::new();
                 table.insert("matrix3",     self.matrix3.to_value());
@@ -671,6 +783,9 @@ impl Exportable for g_f32::Affine3A {
 }
 
 impl Exportable for g_f64::DVec2 {
+    fn expected_kind() -> Option<ValueKind> where Self: Sized { Some(ValueKind::Array) }
+    fn expected_len()  -> Option<usize>     where Self: Sized { Some(2) }
+
     fn to_value(&self) -> toml::Value {
         toml::Value::Array(toml::Array::from_iter(self.to_array()))
     }
@@ -688,6 +803,9 @@ impl Exportable for g_f64::DVec2 {
     }
 }
 impl Exportable for g_f64::DVec3 {
+    fn expected_kind() -> Option<ValueKind> where Self: Sized { Some(ValueKind::Array) }
+    fn expected_len()  -> Option<usize>     where Self: Sized { Some(3) }
+
     fn to_value(&self) -> toml::Value {
         toml::Value::Array(toml::Array::from_iter(self.to_array()))
     }
@@ -705,6 +823,9 @@ impl Exportable for g_f64::DVec3 {
     }
 }
 impl Exportable for g_f64::DVec4 {
+    fn expected_kind() -> Option<ValueKind> where Self: Sized { Some(ValueKind::Array) }
+    fn expected_len()  -> Option<usize>     where Self: Sized { Some(4) }
+
     fn to_value(&self) -> toml::Value {
         toml::Value::Array(toml::Array::from_iter(self.to_array()))
     }
@@ -722,6 +843,9 @@ impl Exportable for g_f64::DVec4 {
     }
 }
 impl Exportable for g_f64::DMat2 {
+    fn expected_kind() -> Option<ValueKind> where Self: Sized { Some(ValueKind::Array) }
+    fn expected_len()  -> Option<usize>     where Self: Sized { Some(2) }
+
     fn to_value(&self) -> toml::Value {
         toml::Value::Array(toml::Array::from_iter(vec![self.x_axis.to_value(), self.y_axis.to_value()]))
     }
@@ -739,6 +863,9 @@ impl Exportable for g_f64::DMat2 {
     }
 }
 impl Exportable for g_f64::DMat3 {
+    fn expected_kind() -> Option<ValueKind> where Self: Sized { Some(ValueKind::Array) }
+    fn expected_len()  -> Option<usize>     where Self: Sized { Some(3) }
+
     fn to_value(&self) -> toml::Value {
         toml::Value::Array(toml::Array::from_iter(vec![self.x_axis.to_value(), self.y_axis.to_value(), self.z_axis.to_value()]))
     }
@@ -756,6 +883,9 @@ impl Exportable for g_f64::DMat3 {
     }
 }
 impl Exportable for g_f64::DMat4 {
+    fn expected_kind() -> Option<ValueKind> where Self: Sized { Some(ValueKind::Array) }
+    fn expected_len()  -> Option<usize>     where Self: Sized { Some(4) }
+
     fn to_value(&self) -> toml::Value {
         toml::Value::Array(toml::Array::from_iter(vec![self.x_axis.to_value(), self.y_axis.to_value(), self.z_axis.to_value(), self.w_axis.to_value()]))
     }
@@ -776,6 +906,9 @@ impl Exportable for g_f64::DMat4 {
     }
 }
 impl Exportable for g_f64::DQuat {
+    fn expected_kind() -> Option<ValueKind> where Self: Sized { Some(ValueKind::Array) }
+    fn expected_len()  -> Option<usize>     where Self: Sized { Some(4) }
+
     fn to_value(&self) -> toml::Value {
         toml::Value::Array(toml::Array::from_iter(vec![self.x, self.y, self.z, self.w]))
     }
@@ -793,6 +926,8 @@ impl Exportable for g_f64::DQuat {
     }
 }
 impl Exportable for g_f64::DAffine2 {
+    fn expected_kind() -> Option<ValueKind> where Self: Sized { Some(ValueKind::Table) }
+
     fn to_value(&self) -> toml_edit::Value {
         let mut table: toml::InlineTable = toml::InlineTable::new();
                 table.insert("matrix2",     self.matrix2.to_value());
@@ -811,6 +946,8 @@ impl Exportable for g_f64::DAffine2 {
     }
 }
 impl Exportable for g_f64::DAffine3 {
+    fn expected_kind() -> Option<ValueKind> where Self: Sized { Some(ValueKind::Table) }
+
     fn to_value(&self) -> toml_edit::Value {
         let mut table: toml::InlineTable = toml::InlineTable::new();
                 table.insert("matrix3",     self.matrix3.to_value());
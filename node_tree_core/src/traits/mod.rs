@@ -5,6 +5,7 @@ pub mod instanceable;
 pub mod registered;
 pub mod exportable;
 pub mod element;
+pub mod bounded;
 
 #[cfg(feature = "glam")]
 pub mod glam_exportables;
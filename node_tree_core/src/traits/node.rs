@@ -30,7 +30,7 @@ use std::fmt;
 use std::any::Any;
 use std::ops::{ Deref, DerefMut };
 
-use crate::structs::{ node_base::NodeBase, node_tree_base::{ ProcessMode, TerminationReason } };
+use crate::structs::{ node_base::{ NodeBase, NodeError }, node_tree_base::{ ProcessMode, ProcessOrder, TerminationReason }, node_path::NodePath, input::InputEvent, rid::RID };
 use super::registered::Registered;
 use super::instanceable::Instanceable;
 
@@ -91,10 +91,76 @@ pub trait Node: NodeAbstract + Registered {
     /// This runs once per tick, and returns a delta value capturing the time between frames.
     fn process(&mut self, _delta: f32) {}
 
+    /// This function can be overridden to respond to input events which are fed to the tree via
+    /// `NodeTreeBase::feed_input()`.
+    /// Events are dispatched from the top down, and propagation stops once a node calls
+    /// `NodeTreeBase::set_input_as_handled()`.
+    fn input(&mut self, _event: &InputEvent) {}
+
     /// This function can be overrriden to facilitate this node's terminal behaviour.
     /// It is run immeditately after this node is queued for destruction.
     fn terminal(&mut self, _reason: TerminationReason) {}
 
+    /// This function can be overridden to respond to a dependency registered via
+    /// `NodeBase::await_node()` becoming available.
+    /// It is run at the end of the first frame in which the given path resolves, and only once
+    /// per call to `await_node()`.
+    fn dependency_ready(&mut self, _path: NodePath) {}
+
+    /// This function can be overridden to respond to a child being added to this node, such as
+    /// to relayout a list.
+    /// It is run once the child's whole scene has been attached to the tree, so the child's own
+    /// children are already in place, but before any `ready()` functions of the scene are called.
+    fn child_added(&mut self, _child: RID) {}
+
+    /// This function can be overridden to respond to a child being removed from this node, either
+    /// via `remove_child()` or by the child being freed.
+    /// It is run after the `terminal()` functions of the child's branch, but before the child is
+    /// detached, so the child may still be accessed.
+    fn child_removed(&mut self, _child: RID) {}
+
+    /// A fallible variant of `ready()`, which is what the `NodeTree` actually calls.
+    /// By default, this calls `ready()` and succeeds, so only one of the two should be overridden.
+    /// Any returned `NodeError` is logged under this node's path and handled according to the
+    /// tree's `NodeErrorPolicy`.
+    fn try_ready(&mut self) -> Result<(), NodeError> {
+        self.ready();
+        Ok(())
+    }
+
+    /// A fallible variant of `process()`, which is what the `NodeTree` actually calls.
+    /// By default, this calls `process()` and succeeds, so only one of the two should be
+    /// overridden.
+    fn try_process(&mut self, delta: f32) -> Result<(), NodeError> {
+        self.process(delta);
+        Ok(())
+    }
+
+    /// A fallible variant of `terminal()`, which is what the `NodeTree` actually calls.
+    /// By default, this calls `terminal()` and succeeds, so only one of the two should be
+    /// overridden.
+    fn try_terminal(&mut self, reason: TerminationReason) -> Result<(), NodeError> {
+        self.terminal(reason);
+        Ok(())
+    }
+
+    /// Creates a fresh, unconnected instance of this node.
+    /// By default, this is the same as `clone_as_instance()`, although nodes created via the
+    /// `class!` macro override this to run their exported fields back through their registered
+    /// deserializer.
+    ///
+    /// # Note
+    /// There are three ways to copy a node, each with differing semantics:
+    /// - `clone_as_instance()` performs a memberwise `Clone`, meaning that any `UniqueField` is
+    ///   voided and any shared state (such as an `Rc`) remains shared with the original.
+    /// - `reinstance()` creates a brand new node from the original's exported configuration, as
+    ///   though it were loaded from disk. Non-exported fields are therefore void until the
+    ///   node's `loaded()` hook runs.
+    /// - `save_as_branch()` duplicates the node along with all of its children as a `NodeScene`.
+    fn reinstance(&self) -> Box<dyn Node> {
+        self.clone_as_instance()
+    }
+
     /// This returns the node's process mode, and entirely effects how the process() function
     /// behaves.
     /// By default, this returns `Inherit`.
@@ -104,6 +170,22 @@ pub trait Node: NodeAbstract + Registered {
     fn process_mode(&self) -> ProcessMode {
         ProcessMode::Inherit
     }
+
+    /// This returns whether this node's `process()` function is called before or after those of
+    /// its children.
+    /// By default, this returns `ParentFirst`.
+    fn process_order(&self) -> ProcessOrder {
+        ProcessOrder::ParentFirst
+    }
+
+    /// Returns a richer, human-readable label for this node, which the logger uses in place of
+    /// the node's path (e.g. `"Enemy#42 (Orc, hp=10)"`). This is purely for diagnostics, and
+    /// helps to tell apart many nodes of the same type in the log.
+    /// Nodes registered as singletons are still identified by their singleton name.
+    /// By default, this returns `None`.
+    fn log_identity(&self) -> Option<String> {
+        None
+    }
 }
 
 impl <N: Node> Instanceable for N {
@@ -29,6 +29,10 @@ use crate::structs::{ node_tree_base::NodeTreeBase, rid::RID };
 
 
 /// A trait that is implemented for types that can be used to get node RIDs from the `NodeTree`.
+///
+/// This is implemented for `NodePath`s, `&str`s and `String`s (singleton names), `RID`s, tree
+/// pointers (`Tp<T>` and `TpDyn`), and strong handles (`TpStrong`). `RID`s, tree pointers and
+/// handles only resolve if they reference a node that is still valid within the same tree.
 pub trait NodeGetter: Debug {
     
     /// A function that must be implemented per compatible type.
@@ -25,7 +25,8 @@
 use std::any::Any;
 use std::ops::{ Deref, DerefMut };
 
-use crate::structs::node_tree_base::NodeTreeBase;
+use crate::structs::{ node_tree_base::NodeTreeBase, rid::RID, tree_option::TreeOption, tree_pointer::TpDyn };
+use crate::traits::node::Node;
 
 
 /*
@@ -90,4 +91,45 @@ pub trait NodeTree: Deref<Target = NodeTreeBase> + DerefMut + Any {
 
     /// Converts this into a mutable Any type.
     fn as_any_mut(&mut self) -> &mut dyn Any;
+
+    /// Resolves a node's `RID` into a `TpDyn` without having to go through a `NodePath`.
+    /// Returns `None` if the `RID` does not reference a node within this tree.
+    ///
+    /// # Note
+    /// Any failures that occur through the returned pointer are reported on behalf of the root
+    /// node.
+    fn node_from_rid(&self, rid: RID) -> TreeOption<'_, TpDyn<'_>> {
+        let tree:  *mut dyn NodeTree = self.as_dyn_raw() as *mut dyn NodeTree;
+        let owner: RID               = self.root().rid();
+        unsafe {
+            TreeOption::new(tree, owner, TpDyn::new(tree, owner, rid).to_option())
+        }
+    }
+
+    /// Calls the given function on every node in the tree, from the top down, such that each node
+    /// is visited before any of its children. This allows external systems such as renderers to
+    /// walk the tree once per frame without having to look up each node by path.
+    fn for_each_node(&self, f: &mut dyn FnMut(&dyn Node)) {
+        let base: &NodeTreeBase = self.base();
+        for rid in base.root().top_down(true) {
+            if let Some(node) = base.get_node(rid) {
+                f(node);
+            }
+        }
+    }
+
+    /// Calls the given function on every node in the tree with mutable access, in the same order
+    /// as `for_each_node()`.
+    ///
+    /// # Note
+    /// The order is determined before any node is visited, so nodes that are added during the walk
+    /// are not visited, and nodes that are removed during the walk are skipped.
+    fn for_each_node_mut(&mut self, f: &mut dyn FnMut(&mut dyn Node)) {
+        let rids: Vec<RID> = self.base().root().top_down(true);
+        for rid in rids {
+            if let Some(node) = self.base_mut().get_node_mut(rid) {
+                f(node);
+            }
+        }
+    }
 }
@@ -26,17 +26,105 @@
 //! can be derived through the `Register` derive macro!
 //! 
 
-use crate::services::node_registry::{ FieldMap, SFieldMap };
+use toml_edit as toml;
+
+use crate::services::node_registry::{ FieldMap, SFieldMap, SCHEMA_VERSION_KEY };
 
 
 /// A trait which allows for the saving and loading of Nodes from owned data.
 /// This trait is implemented for you via the `Registered` derive macro, which is automatically
 /// set via the `class!` macro.
+///
+/// # Versioning
+/// Every saved node embeds the version of its schema under the `__schema_version` field. When a
+/// node is loaded from data with an older version, `migrate()` is called on the owned data before
+/// any fields are deserialized, allowing for fields to be renamed or transformed. Data from a newer
+/// version than the node's own schema cannot be loaded.
+///
+/// Both can be set through the `schema` attribute:
+/// ```rust, ignore
+/// class! {
+///     #[schema(version = 2, migrate = "Self::migrate_fields")]
+///     dec Fighter;
+///
+///     export let health: u32 = 100;
+///
+///     fn migrate_fields(version: u32, fields: &mut SFieldMap) {
+///         if version < 2 {
+///             if let Some(hp) = fields.remove("hp") {
+///                 fields.insert("health".into(), hp);
+///             }
+///         }
+///     }
+/// }
+/// ```
 pub trait Registered {
+
+    /// The version of this node's save data schema.
+    /// By default, this is `0`, which is also the version assumed for data saved without one.
+    fn schema_version() -> u32 where Self: Sized { 0 }
+
+    /// Migrates owned data saved under an older schema version to the current schema.
+    /// By default, this does nothing.
+    fn migrate(_version: u32, _owned_state: &mut SFieldMap) where Self: Sized {}
     
     /// Loads a `Node` from a set of owned data in a `toml` compatible format.
     fn load_from_owned(owned_state: SFieldMap) -> Result<Self, String> where Self: Sized; /* Required for V-Table Initialization */
 
     /// Saves a `Node`'s owned state to a `FieldMap`, which is compatible with `toml_edit`.
     fn save_from_owned(&self) -> FieldMap;
+
+    /// Gets the names and current values of every exported field of this node, which allows
+    /// for building generic tools such as property inspectors. Ghost exports (such as plain
+    /// fields and signals) are omitted.
+    ///
+    /// The `Registered` derive macro lists the fields in the order in which they are declared.
+    /// By default, they are sorted by name.
+    fn exported_fields(&self) -> Vec<(String, toml::Value)> {
+        let mut fields: Vec<(String, toml::Value)> = self.save_from_owned()
+            .into_iter()
+            .filter(|(name, value)| &**name != SCHEMA_VERSION_KEY && !unsafe { value.is_ghost_export() })
+            .map(|(name, value)| (name.to_string(), value.to_value()))
+            .collect();
+        
+        fields.sort_by(|(a, _), (b, _)| a.cmp(b));
+        fields
+    }
+
+    /// Sets an exported field of this node given its name and a new value, which allows for
+    /// runtime editors and for applying field updates that arrive as `(name, value)` pairs.
+    /// Returns `Err` if there is no such field, if it is not exported, or if the value is of the
+    /// wrong type.
+    ///
+    /// # Note
+    /// The field is assigned directly, so the setters of `export(notify)` fields are bypassed and
+    /// their change signals are not emitted.
+    ///
+    /// This is implemented by the `Registered` derive macro. By default, this always fails.
+    fn set_exported_field(&mut self, name: &str, _value: toml::Value) -> Result<(), String> {
+        Err(format!("cannot set the field `{name}`, as this node does not support setting exported fields"))
+    }
+
+    /// Applies a partial set of exported field values to this node, leaving any fields that are
+    /// not present in the patch untouched. Unlike `load_from_owned()`, not every field is required,
+    /// which makes this suitable for applying delta updates such as those received over a network.
+    /// Any schema version key in the patch is ignored.
+    ///
+    /// Every value is validated before any are assigned, so if `Err` is returned then the node is
+    /// left unchanged.
+    ///
+    /// # Note
+    /// As with `set_exported_field()`, the setters of `export(notify)` fields are bypassed.
+    ///
+    /// This is implemented by the `Registered` derive macro. By default, this applies each field
+    /// through `set_exported_field()` and stops at the first failure.
+    fn apply_field_patch(&mut self, patch: SFieldMap) -> Result<(), String> {
+        for (name, value) in patch {
+            if &*name == SCHEMA_VERSION_KEY {
+                continue;
+            }
+            self.set_exported_field(&name, value)?;
+        }
+        Ok(())
+    }
 }
@@ -29,9 +29,11 @@
 use core::panic;
 use std::any::Any;
 use std::ops::{ Deref, DerefMut };
+use std::time::{ Duration, Instant };
+use std::thread;
 
 use crate::structs::logger::LoggerVerbosity;
-use crate::structs::node_tree_base::{ NodeTreeBase, initialize_base };
+use crate::structs::node_tree_base::{ NodeTreeBase, TreeStatus, initialize_base };
 use crate::traits::{ instanceable::Instanceable, node_tree::NodeTree };
 
 
@@ -39,20 +41,82 @@ use crate::traits::{ instanceable::Instanceable, node_tree::NodeTree };
 /// not make use of other frameworks.
 #[derive(Debug)]
 pub struct TreeSimple {
-    base: Option<NodeTreeBase>
+    base:       Option<NodeTreeBase>,
+    target_fps: Option<f32>,
+    last_fps:   f32
 }
 
 impl TreeSimple {
+
+    /// How long before the end of a frame the governor stops sleeping and starts spin-waiting.
+    /// The OS scheduler is not precise enough to sleep right up until the deadline.
+    const SPIN_MARGIN: Duration = Duration::from_millis(1);
     
     /// Creates a new `TreeSimple` structure.
     pub fn new<I: Instanceable>(scene: I, verbosity: LoggerVerbosity) -> Box<Self> {
         let mut tree: Box<TreeSimple> = Box::new(TreeSimple {
-            base: None
+            base:       None,
+            target_fps: None,
+            last_fps:   0.0
         });
         
         initialize_base(&mut tree, scene, verbosity);
         tree
     }
+
+    /// Sets the frame rate that `step()` and `run()` will be capped to.
+    /// Passing `None` uncaps the frame rate, meaning that no time will be spent sleeping.
+    ///
+    /// # Panics
+    /// Panics if the frame rate is not a positive number, or is so small that a single frame's
+    /// budget cannot be represented.
+    pub fn set_target_fps(&mut self, fps: Option<f32>) {
+        if let Some(fps) = fps {
+            if fps.is_nan() || fps <= 0.0 || Duration::try_from_secs_f32(1.0 / fps).is_err() {
+                panic!("The target frame rate must be a positive number, but got {fps}!");
+            }
+        }
+        self.target_fps = fps;
+    }
+
+    /// Gets the frame rate that `step()` and `run()` are capped to, if any.
+    pub fn target_fps(&self) -> Option<f32> {
+        self.target_fps
+    }
+
+    /// Gets the frame rate that was actually achieved during the last call to `step()`.
+    pub fn last_fps(&self) -> f32 {
+        self.last_fps
+    }
+
+    /// Processes a single frame, and then waits out the remainder of the frame's budget if a
+    /// target frame rate is set.
+    /// This returns the `TreeStatus` after the frame has been processed.
+    pub fn step(&mut self) -> TreeStatus {
+        let frame_start: Instant    = Instant::now();
+        let status:      TreeStatus = self.process();
+
+        if let Some(fps) = self.target_fps {
+            let deadline: Instant = frame_start + Duration::from_secs_f32(1.0 / fps);
+            
+            // Sleep for most of the remaining budget, then spin for the tail end for accuracy.
+            let remaining: Duration = deadline.saturating_duration_since(Instant::now());
+            if remaining > Self::SPIN_MARGIN {
+                thread::sleep(remaining - Self::SPIN_MARGIN);
+            }
+            while Instant::now() < deadline {
+                std::hint::spin_loop();
+            }
+        }
+
+        self.last_fps = 1.0 / frame_start.elapsed().as_secs_f32();
+        status
+    }
+
+    /// Continuously processes the tree via `step()` until it has terminated.
+    pub fn run(&mut self) {
+        while self.step() != TreeStatus::Terminated {}
+    }
 }
 
 impl NodeTree for TreeSimple {
@@ -25,12 +25,20 @@
 //! Contains utility functions used throughout the crate.
 //!
 
+use std::collections::HashSet;
+
 use crate::{ prelude::{ RID, NodeTreeBase, Node }, structs::node_base::NodeStatus };
 
 
-/// Ensures that the name provided is unique relative to the list of other names.
-/// If it is not, then it will create a new unique name.
-pub fn ensure_unique_name(name: &str, relative_to: &[String]) -> String {
+/// Ensures that the name provided is unique relative to the set of other names.
+/// If it is not, then it will create a new unique name by giving it a numerical suffix, counting
+/// upwards from the suffix that it already has.
+///
+/// # Note
+/// Suffixes are searched for by doubling and then bisecting, so a set of names that were all
+/// generated from the same base (as in `Node`, `Node1`, `Node2`, ...) only costs a logarithmic
+/// number of lookups rather than a scan over every name.
+pub fn ensure_unique_name(name: &str, relative_to: &HashSet<String>) -> String {
     fn extract_numerical_suffix(s: &str) -> Option<usize> {
         let mut numerics: String = String::new();
         let mut ptr:      usize  = s.len() - 1;
@@ -55,54 +63,42 @@ pub fn ensure_unique_name(name: &str, relative_to: &[String]) -> String {
     }
 
     // Special Case:
-    // If the 'relative_to' array is empty, then return the name.
-    if relative_to.is_empty() {
+    // If the name is not taken, then return the name.
+    if name.is_empty() || !relative_to.contains(name) {
         return name.to_string();
     }
 
     // Strip the name bare of any numerical suffix.
     let given_value:         Option<usize> = extract_numerical_suffix(name);
-    let name_without_suffix: String        = match given_value {
-        Some(number) => name.split_at(name.find(&format!("{}", number)).unwrap()).0.to_string(),
-        None         => name.to_string()
+    let name_without_suffix: &str          = match given_value {
+        Some(number) => name.split_at(name.rfind(&format!("{}", number)).unwrap()).0,
+        None         => name
     };
-    
-    // Search for any similar names that have the same beginning but different suffixes.
-    let mut similar_names: Vec<String> = Vec::new();
-    for set_name in relative_to {
-        let idx_found: Option<usize> = set_name.find(&name_without_suffix);
-        
-        if let Some(idx) = idx_found {
-            if idx != 0 {   // We do not include similar names when the pattern does not start at the beginning of the string.
-                continue;
-            }
-            similar_names.push(set_name.to_string());
-        }
-    }
+    let is_taken = |value: usize| relative_to.contains(&format!("{name_without_suffix}{value}"));
 
-    if similar_names.is_empty() {
-        return name.to_string();
+    // Double the distance from the current suffix until a free one is found, then bisect back
+    // towards the last taken suffix so that the lowest free suffix of a contiguous run is used.
+    let start: usize = given_value.unwrap_or(1);
+    if !is_taken(start) {
+        return format!("{name_without_suffix}{start}");
     }
 
-    // Order all of the names with a numerical suffix.
-    // If this name does not have a numerical suffix, then give it the lowest possible numerical
-    // suffix.
-    // Otherwise, give it the closest numerical suffix to the one it currently has (counting
-    // upwards).
-    let mut new_value: usize      = given_value.unwrap_or(0);
-    let     values:    Vec<usize> = similar_names.iter().map(|n| extract_numerical_suffix(n).unwrap_or(0)).collect(); // If there are no numerical suffixes on similar names,
-    loop { // I dunno why I programmed this as a loop but I'll keep it this way until I figure out why I did so.
-        for value in values {
-            if new_value == value {
-                new_value += 1;
-                continue;
-            }
+    let mut taken: usize = start;
+    let mut free:  usize = start + 1;
+    while is_taken(free) {
+        taken = free;
+        free  = start + (free - start) * 2;
+    }
+    while free - taken > 1 {
+        let mid: usize = taken + (free - taken) / 2;
+        if is_taken(mid) {
+            taken = mid;
+        } else {
+            free = mid;
         }
-        break;
     }
     
-    let new_suffix: String = format!("{}", new_value);
-    name_without_suffix.to_string() + &new_suffix
+    format!("{name_without_suffix}{free}")
 }
 
 /// Takes in a NodeTree and prints out a graphical representation with a node as the origin.
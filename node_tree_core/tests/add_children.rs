@@ -0,0 +1,31 @@
+use node_tree::prelude::*;
+use node_tree::trees::TreeSimple;
+
+
+class! {
+    dec BatchRoot;
+
+    hk ready(&mut self) {
+        self.add_children(vec![Sibling::new(), Sibling::new(), Sibling::new()]);
+
+        let names: Vec<String> = self.children().iter().map(|c| c.name().to_string()).collect();
+        assert_eq!(names, vec!["Sibling".to_string(), "Sibling1".to_string(), "Sibling2".to_string()]);
+        
+        self.tree_mut().unwrap().queue_termination();
+    }
+}
+
+class! {
+    dec Sibling;
+
+    hk ready(&mut self) {
+        assert_eq!(self.parent_dyn().unwrap().num_children(), 3);
+    }
+}
+
+
+#[test]
+fn test_add_children() {
+    let mut tree: Box<TreeSimple> = TreeSimple::new(BatchRoot::new(), LoggerVerbosity::NoDebug);
+    while tree.process().is_active() {}
+}
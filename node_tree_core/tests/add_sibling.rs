@@ -0,0 +1,38 @@
+use node_tree::prelude::*;
+use node_tree::trees::TreeSimple;
+
+
+class! {
+    dec MenuItem;
+}
+
+class! {
+    dec Menu;
+
+    hk ready(&mut self) {
+        let mut middle: TpDyn = self.get_child_dyn(1).unwrap();
+
+        assert!(middle.add_sibling(MenuItem::new()).unwrap().is::<MenuItem>());
+        assert!(middle.add_sibling_before(MenuItem::new()).unwrap().is::<MenuItem>());
+
+        let names: Vec<String> = self.children().iter().map(|c| c.name().to_string()).collect();
+        assert_eq!(names, vec!["First", "MenuItem1", "Middle", "MenuItem", "Last"]);
+
+        // The root has no parent, and therefore no siblings.
+        assert!(self.add_sibling(MenuItem::new()).is_err());
+    }
+}
+
+
+#[test]
+fn test_add_sibling() {
+    let scene: NodeScene = scene! {
+        Menu {
+            MenuItem: "First",
+            MenuItem: "Middle",
+            MenuItem: "Last"
+        }
+    };
+
+    TreeSimple::new(scene, LoggerVerbosity::NoDebug);
+}
@@ -0,0 +1,34 @@
+use node_tree::prelude::*;
+use node_tree::trees::TreeSimple;
+
+
+class! {
+    dec Crumb;
+}
+
+
+#[test]
+fn test_ancestors() {
+    let scene: NodeScene = scene! {
+        Crumb: "Home" {
+            Crumb: "Docs" {
+                Crumb: "Guide" {
+                    Crumb: "Page"
+                }
+            }
+        }
+    };
+
+    let tree: Box<TreeSimple> = TreeSimple::new(scene, LoggerVerbosity::NoDebug);
+    let page: RID             = tree.get_node_rid(NodePath::from_str("/Home/Docs/Guide/Page"), None).unwrap();
+    let page: &dyn Node       = tree.get_node(page).unwrap();
+
+    // The ancestors are walked from the parent up to the root, excluding the node itself.
+    let breadcrumbs: Vec<String> = page.ancestors().map(|node| node.name().to_string()).collect();
+    assert_eq!(breadcrumbs, ["Guide", "Docs", "Home"]);
+    assert_eq!(page.root_pointer().name(), "Home");
+
+    // The root has no ancestors, and is its own root.
+    assert_eq!(tree.root().ancestors().count(), 0);
+    assert_eq!(tree.root().root_pointer().rid(), tree.root().rid());
+}
@@ -0,0 +1,35 @@
+use node_tree::prelude::*;
+use node_tree::trees::TreeSimple;
+
+
+#[derive(Debug, PartialEq)]
+struct Health(u32);
+
+#[derive(Debug, PartialEq)]
+struct Tag(&'static str);
+
+
+class! {
+    dec Plain;
+}
+
+
+#[test]
+fn test_attached_data() {
+    let mut tree: Box<TreeSimple> = TreeSimple::new(scene! { Plain }, LoggerVerbosity::NoDebug);
+    let     root: &mut dyn Node   = tree.root_mut();
+
+    assert_eq!(root.get_data::<Health>(), None);
+    root.set_data(Health(10));
+    root.set_data(Tag("enemy"));
+    assert_eq!(root.get_data::<Health>(), Some(&Health(10)));
+    assert_eq!(root.get_data::<Tag>(),    Some(&Tag("enemy")));
+
+    // Data of the same type is replaced, and can be modified in place.
+    root.set_data(Health(5));
+    root.get_data_mut::<Health>().unwrap().0 -= 1;
+    assert_eq!(root.get_data::<Health>(), Some(&Health(4)));
+
+    assert_eq!(root.take_data::<Tag>(), Some(Tag("enemy")));
+    assert_eq!(root.get_data::<Tag>(),  None);
+}
@@ -0,0 +1,89 @@
+use node_tree::prelude::*;
+use node_tree::trees::TreeSimple;
+
+
+class! {
+    dec Spawner;
+}
+
+class! {
+    dec Effect;
+
+    export default let power: u32;
+
+    hk ready(&mut self) {
+        if self.name() == "Temporary" {
+            self.set_auto_free_when_orphaned(true);
+        }
+    }
+}
+
+
+#[test]
+fn test_auto_free_parented() {
+    let scene: NodeScene = scene! {
+        Spawner {
+            Effect: "Temporary",
+            Effect: "Sibling"
+        }
+    };
+
+    let mut tree:      Box<TreeSimple> = TreeSimple::new(scene, LoggerVerbosity::NoDebug);
+    let     temporary: RID             = tree.root().children_rids()[0];
+    let     sibling:   RID             = tree.root().children_rids()[1];
+
+    // Every handle counts as a reference, as does the parent link.
+    let handle: TpStrong = tree.root().get_child_dyn(0).unwrap().to_strong();
+    let copy:   TpStrong = handle.clone();
+    assert_eq!(tree.reference_count(temporary), 3);
+    drop(handle);
+    drop(copy);
+    assert_eq!(tree.reference_count(temporary), 1);
+
+    // Transient tree pointers do not count, so walking the tree does not free a parented node.
+    tree.get_node_mut(sibling).unwrap().set_name("Renamed");
+    assert!(tree.dump_graphviz().contains("Temporary"));
+    tree.process_with_delta(0.0);
+
+    let names: Vec<String> = tree.root().children().iter().map(|child| child.name().to_string()).collect();
+    assert_eq!(names, ["Temporary", "Renamed"]);
+}
+
+#[test]
+fn test_auto_free_orphaned() {
+    let scene: NodeScene = scene! {
+        Spawner {
+            Effect: "Temporary",
+            Effect: "Persistent"
+        }
+    };
+
+    let mut tree:       Box<TreeSimple> = TreeSimple::new(scene, LoggerVerbosity::NoDebug);
+    let     temporary:  TpStrong        = tree.root().get_child_dyn(0).unwrap().to_strong();
+    let     persistent: TpStrong        = tree.root().get_child_dyn(1).unwrap().to_strong();
+
+    // A removed node that is freed once orphaned stays readable for as long as it has handles.
+    *tree.get_node_mut(temporary.rid()).unwrap().as_any_mut().downcast_mut::<Effect>().unwrap().power = 7;
+    assert!(tree.root_mut().remove_child("Temporary"));
+    assert!(tree.root_mut().remove_child("Persistent"));
+    assert!(tree.root().childless());
+    assert_eq!(tree.reference_count(temporary.rid()), 1);
+
+    let effect: &Effect = tree.get_node(temporary.rid()).unwrap().as_any().downcast_ref().unwrap();
+    assert_eq!(*effect.power, 7);
+    assert!(tree.get_node_rid(&temporary, None).is_some());
+
+    // Nodes that do not opt in are freed on removal regardless of their handles.
+    assert!(tree.get_node_rid(&persistent, None).is_none());
+
+    // The node is only freed at the end of the frame after its last handle is dropped.
+    let rid: RID = temporary.rid();
+    drop(temporary);
+    assert!(tree.get_node(rid).is_some());
+    tree.process_with_delta(0.0);
+    assert!(tree.get_node(rid).is_none());
+
+    // Stale handles do not resolve to new nodes which reuse their node's RID.
+    tree.root_mut().add_child(scene! { Effect: "Replacement" });
+    assert!(tree.get_node_rid(&persistent, None).is_none());
+}
@@ -0,0 +1,39 @@
+use node_tree::prelude::*;
+use node_tree::trees::TreeSimple;
+
+
+class! {
+    dec Provider;
+}
+
+class! {
+    dec Consumer;
+
+    let found: Vec<String> = Vec::new();
+    
+    hk ready(&mut self) {
+        self.await_node(NodePath::from_str("../Provider"));
+    }
+
+    hk dependency_ready(&mut self, path: NodePath) {
+        self.found.push(path.to_string());
+    }
+}
+
+
+#[test]
+fn test_await_node() {
+    let mut tree: Box<TreeSimple> = TreeSimple::new(scene! { Provider: "Root" { Consumer } }, LoggerVerbosity::NoDebug);
+    
+    // The dependency does not exist yet, so nothing happens.
+    tree.process_with_delta(0.1);
+    tree.process_with_delta(0.1);
+    let consumer: RID = tree.rid_of_path(&NodePath::from_str("Consumer")).unwrap();
+    assert!(tree.get_node(consumer).unwrap().as_any().downcast_ref::<Consumer>().unwrap().found.is_empty());
+
+    // Once it is added, the hook fires at the end of the frame, and only once.
+    tree.root_mut().add_child(Provider::new());
+    tree.process_with_delta(0.1);
+    tree.process_with_delta(0.1);
+    assert_eq!(*tree.get_node(consumer).unwrap().as_any().downcast_ref::<Consumer>().unwrap().found, vec!["../Provider".to_string()]);
+}
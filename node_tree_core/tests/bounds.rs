@@ -0,0 +1,49 @@
+use node_tree::prelude::*;
+use node_tree::trees::TreeSimple;
+
+
+class! {
+    dec Layer;
+}
+
+class! {
+    dec Sprite;
+
+    export let pos:  (f32, f32);
+    export let size: (f32, f32);
+
+    hk _init(pos: (f32, f32), size: (f32, f32)) {
+        register_bounded::<Sprite>();
+    }
+}
+
+impl Bounded for Sprite {
+    fn local_bounds(&self) -> Rect {
+        Rect::from_pos_size(Vec2::new(self.pos.0, self.pos.1), Vec2::new(self.size.0, self.size.1))
+    }
+}
+
+
+#[test]
+fn test_bounds() {
+    let scene: NodeScene = scene! {
+        Layer: "Root" {
+            Sprite((0.0, 0.0), (10.0, 10.0)): "A",
+            Layer: "Group" {
+                Sprite((20.0, 5.0), (5.0, 30.0)): "B"
+            },
+            Layer: "Empty"
+        }
+    };
+
+    let tree: Box<TreeSimple> = TreeSimple::new(scene, LoggerVerbosity::NoDebug);
+    let root: Rect            = tree.root().subtree_bounds().unwrap();
+    assert_eq!(root, Rect::new(Vec2::new(0.0, 0.0), Vec2::new(25.0, 35.0)));
+    assert!(root.contains(Vec2::new(22.0, 30.0)));
+
+    // Unbounded nodes are skipped, but their bounded descendants are not.
+    let group: RID = tree.root().children_rids()[1];
+    let empty: RID = tree.root().children_rids()[2];
+    assert_eq!(tree.get_node(group).unwrap().subtree_bounds().unwrap().width(), 5.0);
+    assert_eq!(tree.get_node(empty).unwrap().subtree_bounds(), None);
+}
@@ -0,0 +1,58 @@
+use node_tree::prelude::*;
+use node_tree::trees::TreeSimple;
+
+
+class! {
+    dec PhaseRoot;
+
+    hk ready(&mut self) {
+        let scene: NodeScene = scene! {
+            PhaseOwner {
+                PhaseEarly,
+                PhaseLate
+            }
+        };
+
+        let rid: RID = self.tree_mut().unwrap().build_scene(scene);
+        assert_eq!(self.get_node_dyn(nodepath!("PhaseOwner")).unwrap().rid(), rid);
+        assert!(self.get_node_dyn(nodepath!("PhaseOwner")).unwrap().is_owner());
+        self.tree_mut().unwrap().queue_termination();
+    }
+}
+
+class! {
+    dec PhaseOwner;
+
+    hk ready(&mut self) {
+        let late: Tp<PhaseLate> = self.get_child(1).unwrap();
+        assert!(*late.is_ready);
+    }
+}
+
+class! {
+    dec PhaseEarly;
+
+    hk ready(&mut self) {
+
+        // Siblings that come after this node are already part of the tree.
+        let late: Tp<PhaseLate> = self.get_node(nodepath!("../PhaseLate")).unwrap();
+        assert!(*late.is_ready);
+    }
+}
+
+class! {
+    dec PhaseLate;
+
+    default let is_ready: bool;
+
+    hk ready(&mut self) {
+        *self.is_ready = true;
+    }
+}
+
+
+#[test]
+fn test_build_phases() {
+    let mut tree: Box<TreeSimple> = TreeSimple::new(PhaseRoot::new(), LoggerVerbosity::NoDebug);
+    while tree.process().is_active() {}
+}
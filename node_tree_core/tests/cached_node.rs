@@ -0,0 +1,44 @@
+use node_tree::prelude::*;
+use node_tree::trees::TreeSimple;
+
+
+class! {
+    dec Place;
+}
+
+
+#[test]
+fn test_cached_node() {
+    let scene: NodeScene = scene! {
+        Place: "Root" {
+            Place: "A" {
+                Place: "B"
+            },
+            Place: "Manager"
+        }
+    };
+
+    let mut tree:    Box<TreeSimple> = TreeSimple::new(scene, LoggerVerbosity::NoDebug);
+    let     a:       RID             = tree.root().children_rids()[0];
+    let     manager: RID             = tree.root().children_rids()[1];
+    let     b:       RID             = tree.get_node(a).unwrap().children_rids()[0];
+    let     path:    NodePath        = nodepath!("../../Manager");
+
+    // Repeated lookups are served from the cache while the structure is unchanged.
+    let version: u64 = tree.structure_version();
+    assert_eq!(tree.get_node(b).unwrap().cached_node(&path).unwrap().rid(), manager);
+    assert_eq!(tree.get_node(b).unwrap().cached_node(&path).unwrap().rid(), manager);
+    assert_eq!(tree.structure_version(), version);
+
+    // Renaming the target invalidates the cached lookup.
+    tree.get_node_mut(manager).unwrap().set_name("Boss");
+    assert_ne!(tree.structure_version(), version);
+    assert!(tree.get_node(b).unwrap().cached_node(&path).is_none());
+
+    tree.get_node_mut(manager).unwrap().set_name("Manager");
+    assert_eq!(tree.get_node(b).unwrap().cached_node(&path).unwrap().rid(), manager);
+
+    // Freeing the target does as well.
+    tree.get_node_mut(manager).unwrap().free();
+    assert!(tree.get_node(b).unwrap().cached_node(&path).is_none());
+}
@@ -0,0 +1,57 @@
+use node_tree::prelude::*;
+use node_tree::trees::TreeSimple;
+
+
+class! {
+    dec Worker;
+
+    let frames: u32 = 0;
+
+    hk process(&mut self, _delta: f32) {
+        *self.frames += 1;
+        if self.name() == "Faulty" {
+            panic!("Out of bounds!");
+        }
+    }
+}
+
+
+fn frames(tree: &TreeSimple, rid: RID) -> u32 {
+    *tree.get_node(rid).unwrap().as_any().downcast_ref::<Worker>().unwrap().frames
+}
+
+
+#[test]
+fn test_catch_node_panics() {
+    let scene: NodeScene = scene! {
+        Worker: "Root" {
+            Worker: "Faulty" {
+                Worker: "Child"
+            },
+            Worker: "Healthy"
+        }
+    };
+
+    let mut tree: Box<TreeSimple> = TreeSimple::new(scene, LoggerVerbosity::NoDebug);
+    tree.set_catch_node_panics(true);
+    
+    let faulty:  RID = tree.root().children_rids()[0];
+    let healthy: RID = tree.root().children_rids()[1];
+    let child:   RID = tree.get_node(faulty).unwrap().children_rids()[0];
+
+    // The faulty node is disabled after its first panic, while the rest of the tree carries on.
+    for _ in 0..3 {
+        assert!(tree.process().is_active());
+    }
+    assert!(tree.get_node(faulty).unwrap().is_disabled());
+    assert_eq!(frames(&tree, faulty),  1);
+    assert_eq!(frames(&tree, child),   3);
+    assert_eq!(frames(&tree, healthy), 3);
+    assert!(tree.get_log().contains("Caught a panic in `process()`: Out of bounds!"));
+
+    // Alternatively, the node can be freed.
+    tree.set_node_panic_policy(NodePanicPolicy::Free);
+    tree.get_node_mut(faulty).unwrap().set_disabled(false);
+    tree.process();
+    assert_eq!(tree.root().num_children(), 1);
+}
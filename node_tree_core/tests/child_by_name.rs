@@ -0,0 +1,40 @@
+use node_tree::prelude::*;
+use node_tree::trees::TreeSimple;
+
+
+class! {
+    dec Menu;
+
+    hk ready(&mut self) {
+        let play: Tp<Button> = self.get_child_by_name("Play").unwrap();
+        assert_eq!(play.name(), "Play");
+        assert_eq!(self.get_child_by_name_dyn("Quit").unwrap().name(), "Quit");
+
+        // Missing children and the wrong types are reported as errors.
+        assert!(self.get_child_by_name_dyn("Options").is_err_and(|err| err.contains("no child named \"Options\"")));
+        assert!(self.get_child_by_name::<Menu>("Play").to_result().is_err());
+
+        // Only direct children are matched.
+        assert!(self.get_child_by_name_dyn("Icon").to_result().is_err());
+    }
+}
+
+class! {
+    dec Button;
+}
+
+
+#[test]
+fn test_get_child_by_name() {
+    let scene: NodeScene = scene! {
+        Menu {
+            Button: "Play" {
+                Button: "Icon"
+            },
+            Button: "Quit"
+        }
+    };
+
+    let tree: Box<TreeSimple> = TreeSimple::new(scene, LoggerVerbosity::NoDebug);
+    assert!(tree.root().get_child_by_name::<Button>("Quit").to_result().is_ok());
+}
@@ -0,0 +1,64 @@
+use node_tree::prelude::*;
+use node_tree::trees::TreeSimple;
+
+
+class! {
+    dec List;
+
+    let added:    Vec<RID>   = Vec::new();
+    let removed:  Vec<RID>   = Vec::new();
+    let attached: bool       = false;
+    let subtree:  Vec<usize> = Vec::new();
+
+    hk child_added(&mut self, child: RID) {
+        let grandchildren: usize = self.tree().unwrap().get_node(child).unwrap().num_children();
+        self.subtree.push(grandchildren);
+        self.added.push(child);
+    }
+
+    hk child_removed(&mut self, child: RID) {
+        *self.attached = self.children_rids().contains(&child);
+        self.removed.push(child);
+    }
+}
+
+class! {
+    dec Item;
+}
+
+
+#[test]
+fn test_child_hooks() {
+    let scene: NodeScene = scene! {
+        List {
+            Item: "A",
+            Item: "B"
+        }
+    };
+
+    let mut tree: Box<TreeSimple> = TreeSimple::new(scene, LoggerVerbosity::NoDebug);
+    let     a:    RID             = tree.get_node_rid(NodePath::from_str("/List/A"), None).unwrap();
+    let     b:    RID             = tree.get_node_rid(NodePath::from_str("/List/B"), None).unwrap();
+    
+    let list: &List = tree.root().as_any().downcast_ref::<List>().unwrap();
+    assert_eq!(*list.added, [a, b]);
+
+    // Adding a child at runtime notifies the parent once the child's whole scene is attached.
+    tree.root_mut().add_child(scene! { Item { Item, Item } });
+    let list: &List = tree.root().as_any().downcast_ref::<List>().unwrap();
+    assert_eq!(list.added.len(), 3);
+    assert_eq!(list.added[2], list.children_rids()[2]);
+    assert_eq!(*list.subtree, [0, 0, 2]);
+
+    // Both freeing and removing a child notify the parent while the child is still attached.
+    tree.get_node_mut(a).unwrap().free();
+    let list: &List = tree.root().as_any().downcast_ref::<List>().unwrap();
+    assert_eq!(*list.removed, [a]);
+    assert!(*list.attached);
+
+    assert!(tree.root_mut().remove_child("B"));
+    let list: &List = tree.root().as_any().downcast_ref::<List>().unwrap();
+    assert_eq!(*list.removed, [a, b]);
+    assert!(*list.attached);
+    assert_eq!(list.num_children(), 1);
+}
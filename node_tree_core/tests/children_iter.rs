@@ -0,0 +1,36 @@
+use node_tree::prelude::*;
+use node_tree::trees::TreeSimple;
+
+
+class! {
+    dec Shelf;
+
+    hk ready(&mut self) {
+        let names: Vec<String> = self.children_iter().map(|child| child.name().to_string()).collect();
+        assert_eq!(names, ["A", "B", "C"]);
+        
+        // Iteration may stop early, and yields the same pointers as `children()`.
+        let found: Option<TpDyn> = self.children_iter().find(|child| child.name() == "B");
+        assert_eq!(found, Some(self.children()[1]));
+        assert!(self.get_child_dyn(0).unwrap().children_iter().next().is_none());
+    }
+}
+
+class! {
+    dec Book;
+}
+
+
+#[test]
+fn test_children_iter() {
+    let scene: NodeScene = scene! {
+        Shelf {
+            Book: "A",
+            Book: "B",
+            Book: "C"
+        }
+    };
+
+    let tree: Box<TreeSimple> = TreeSimple::new(scene, LoggerVerbosity::NoDebug);
+    assert_eq!(tree.root().children_iter().count(), 3);
+}
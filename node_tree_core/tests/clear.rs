@@ -0,0 +1,84 @@
+use node_tree::prelude::*;
+use node_tree::trees::TreeSimple;
+
+
+class! {
+    dec Level;
+
+    hk terminal(&mut self, reason: TerminationReason) {
+        if matches!(reason, TerminationReason::RemovedAsChild) {
+            info!(self, "Removed {}", self.name());
+        }
+    }
+}
+
+class! {
+    dec Music;
+
+    hk ready(&mut self) {
+        self.register_as_singleton("Music".to_string());
+        self.add_to_group("audio");
+    }
+}
+
+class! {
+    dec Stale;
+
+    hk ready(&mut self) {
+        let rid: RID = self.rid();
+        self.tree_mut().unwrap().base_mut().call_deferred(move |tree| {
+            if let Some(node) = tree.get_node_mut(rid) {
+                node.set_name("Hijacked");
+            }
+        });
+    }
+}
+
+
+#[test]
+fn test_clear() {
+    let scene: NodeScene = scene! {
+        Level: "Root" {
+            Level: "Level1" {
+                Level: "Player"
+            },
+            Music: "Music"
+        }
+    };
+
+    let mut tree: Box<TreeSimple> = TreeSimple::new(scene, LoggerVerbosity::All);
+    tree.clear();
+
+    // Only the root remains, and nothing refers to the removed nodes.
+    assert!(tree.root().childless());
+    assert_eq!(tree.get_node_rid("Music".to_string(), None), None);
+    assert!(tree.nodes_in_group("audio").is_empty());
+    assert!(tree.status().is_active());
+    
+    let log: &str = tree.get_log();
+    assert!(log.find("Removed Level1").unwrap() < log.find("Removed Player").unwrap());
+
+    // The tree can immediately be reused.
+    tree.root_mut().add_child(scene! { Level: "Level2" });
+    assert_eq!(tree.root().num_children(), 1);
+    assert!(tree.process().is_active());
+}
+
+#[test]
+fn test_clear_drops_deferred_calls() {
+    let scene: NodeScene = scene! {
+        Level: "Root" {
+            Stale: "A",
+            Stale: "B"
+        }
+    };
+
+    // The removed nodes' deferred calls must not reach the nodes that reuse their RIDs.
+    let mut tree: Box<TreeSimple> = TreeSimple::new(scene, LoggerVerbosity::NoDebug);
+    tree.clear();
+    tree.root_mut().add_child(scene! { Level: "Level2" { Level: "Player" } });
+    assert!(tree.process().is_active());
+
+    let names: Vec<String> = tree.root().top_down(true).into_iter().map(|rid| tree.get_node(rid).unwrap().name().to_string()).collect();
+    assert_eq!(names, vec!["Root", "Level2", "Player"]);
+}
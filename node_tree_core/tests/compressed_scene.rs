@@ -0,0 +1,21 @@
+use std::{ env, fs, path::PathBuf };
+
+use node_tree::prelude::*;
+
+
+class! {
+    dec Chunk;
+}
+
+
+#[test]
+fn test_compressed_scene() {
+    let dir: PathBuf = env::temp_dir();
+    scene! { Chunk }.save(&dir, "compressed_scene").unwrap();
+    assert!(NodeScene::load(&dir.join("compressed_scene.scn")).is_ok());
+
+    // A gzip stream is recognized by its magic bytes and reported as such.
+    fs::write(dir.join("compressed_scene.scn"), [0x1f, 0x8b, 0x08, 0x00]).unwrap();
+    let err: String = NodeScene::load(&dir.join("compressed_scene.scn")).unwrap_err();
+    assert!(err.contains("gzip-compressed"));
+}
@@ -0,0 +1,54 @@
+use std::cell::Cell;
+
+use node_tree::prelude::*;
+use node_tree::trees::TreeSimple;
+
+
+class! {
+    dec Group;
+}
+
+class! {
+    dec Unit;
+}
+
+
+#[test]
+fn test_count_descendants() {
+    let scene: NodeScene = scene! {
+        Group {
+            Unit: "A",
+            Group: "Squad" {
+                Unit: "B",
+                Group: "Team" {
+                    Unit: "C",
+                    Unit: "D"
+                }
+            }
+        }
+    };
+
+    let tree:  Box<TreeSimple> = TreeSimple::new(scene, LoggerVerbosity::NoDebug);
+    let squad: RID             = tree.get_node_rid(NodePath::from_str("/Group/Squad"), None).unwrap();
+    let squad: &dyn Node       = tree.get_node(squad).unwrap();
+    let is_group = |node: &dyn Node| node.as_any().is::<Group>();
+    let is_unit  = |node: &dyn Node| node.as_any().is::<Unit>();
+
+    // The node itself is excluded, even if it matches.
+    assert_eq!(tree.root().count_descendants_where(is_unit), 4);
+    assert_eq!(tree.root().count_descendants_where(is_group), 2);
+    assert_eq!(squad.count_descendants_where(is_group), 1);
+    assert_eq!(squad.count_descendants_where(|_| true), 4);
+
+    assert!(squad.any_descendant(|node| node.name() == "D"));
+    assert!(!squad.any_descendant(|node| node.name() == "A"));
+    assert!(!squad.any_descendant(|node| node.name() == "Squad"));
+
+    // The search stops at the first match.
+    let visited: Cell<usize> = Cell::new(0);
+    assert!(tree.root().any_descendant(|node| {
+        visited.set(visited.get() + 1);
+        node.name() == "Squad"
+    }));
+    assert_eq!(visited.get(), 2);
+}
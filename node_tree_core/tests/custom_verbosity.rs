@@ -0,0 +1,62 @@
+use std::cell::Cell;
+use std::rc::Rc;
+
+use node_tree::prelude::*;
+use node_tree::trees::TreeSimple;
+
+
+class! {
+    dec Chatty;
+
+    hk ready(&mut self) {
+        debug!(self, "Debug from {}", self.name());
+        info!(self, "Info from {}", self.name());
+    }
+}
+
+
+#[test]
+fn test_custom_verbosity() {
+    let scene: NodeScene = scene! {
+        Chatty: "Root" {
+            Chatty: "World" {
+                Chatty: "Player"
+            },
+            Chatty: "Menu"
+        }
+    };
+
+    // Only allow debug logs from nodes under `World`.
+    let verbosity: LoggerVerbosity = LoggerVerbosity::custom(|record| {
+        record.level != LogLevel::Debug || record.system.to_str().starts_with("Root/World")
+    });
+
+    let tree: Box<TreeSimple> = TreeSimple::new(scene, verbosity);
+    let log:  &str            = tree.get_log();
+    
+    assert!(log.contains("Debug from Player"));
+    assert!(log.contains("Debug from World"));
+    assert!(!log.contains("Debug from Menu"));
+    assert!(!log.contains("Debug from Root"));
+    assert!(log.contains("Info from Menu"));
+}
+
+#[test]
+fn test_custom_verbosity_runs_once() {
+    let scene: NodeScene = scene! {
+        Chatty: "Root"
+    };
+
+    // The filter should only be consulted once per log.
+    let calls:   Rc<Cell<usize>> = Rc::new(Cell::new(0));
+    let counter: Rc<Cell<usize>> = calls.clone();
+    let verbosity: LoggerVerbosity = LoggerVerbosity::custom(move |record| {
+        if record.message.starts_with("Info from") {
+            counter.set(counter.get() + 1);
+        }
+        true
+    });
+
+    let _tree: Box<TreeSimple> = TreeSimple::new(scene, verbosity);
+    assert_eq!(calls.get(), 1);
+}
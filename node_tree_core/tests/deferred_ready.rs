@@ -0,0 +1,62 @@
+use node_tree::prelude::*;
+use node_tree::trees::TreeSimple;
+
+
+class! {
+    dec Spawner;
+
+    let frames: u32 = 0;
+
+    hk ready(&mut self) {
+        let mut spawned: Tp<Spawned> = self.add_child_deferred_ready(Spawned::new()).unwrap().cast().unwrap();
+        
+        // The spawned node can be configured before it is ready.
+        assert_eq!(*spawned.ready_with, 0);
+        *spawned.speed = 5;
+    }
+
+    hk process(&mut self, _delta: f32) {
+        *self.frames += 1;
+        
+        // The child is only ready at the end of the first frame, and is not processed until then.
+        let spawned: Tp<Spawned> = self.get_child(0).unwrap();
+        if *self.frames == 1 {
+            assert_eq!(*spawned.ready_with, 0);
+            assert_eq!(*spawned.processed,  0);
+        } else {
+            assert_eq!(*spawned.ready_with, 5);
+            assert_eq!(*spawned.processed,  *self.frames - 2);
+        }
+
+        if *self.frames == 3 {
+            self.tree_mut().unwrap().queue_termination();
+        }
+    }
+}
+
+class! {
+    dec Spawned;
+
+    let speed:      u32 = 0;
+    let ready_with: u32 = 0;
+    let processed:  u32 = 0;
+
+    hk ready(&mut self) {
+        *self.ready_with = *self.speed;
+    }
+
+    hk process(&mut self, _delta: f32) {
+        *self.processed += 1;
+    }
+}
+
+
+#[test]
+fn test_deferred_ready() {
+    let scene: NodeScene = scene! {
+        Spawner
+    };
+
+    let mut tree: Box<TreeSimple> = TreeSimple::new(scene, LoggerVerbosity::NoDebug);
+    while tree.process().is_active() {}
+}
@@ -0,0 +1,90 @@
+use node_tree::prelude::*;
+use node_tree::trees::TreeSimple;
+
+
+class! {
+    dec Mover;
+
+    hk process(&mut self, _delta: f32) {
+        if self.name() == "Mover" {
+            self.set_name_deferred("Taken");
+        }
+    }
+}
+
+class! {
+    dec Watcher;
+
+    default let found: bool;
+
+    hk process(&mut self, _delta: f32) {
+        *self.found = self.get_node_dyn(NodePath::from_str("../Mover")).is_ok();
+    }
+}
+
+
+#[test]
+fn test_deferred_rename() {
+    let scene: NodeScene = scene! {
+        Watcher: "Root" {
+            Mover,
+            Watcher,
+            Watcher: "Taken"
+        }
+    };
+
+    let mut tree:    Box<TreeSimple> = TreeSimple::new(scene, LoggerVerbosity::NoDebug);
+    let     mover:   RID             = tree.root().children_rids()[0];
+    let     watcher: RID             = tree.root().children_rids()[1];
+    
+    // The rename only applies once the frame is over, so the watcher still finds the old name.
+    tree.process_with_delta(0.0);
+    assert!(*tree.get_node(watcher).unwrap().as_any().downcast_ref::<Watcher>().unwrap().found);
+    
+    // The new name is still made unique among its siblings.
+    let name: &str = tree.get_node(mover).unwrap().name();
+    assert_ne!(name, "Mover");
+    assert_ne!(name, "Taken");
+    assert!(name.starts_with("Taken"));
+
+    tree.process_with_delta(0.0);
+    assert!(!*tree.get_node(watcher).unwrap().as_any().downcast_ref::<Watcher>().unwrap().found);
+}
+
+class! {
+    dec Indecisive;
+
+    hk process(&mut self, _delta: f32) {
+        if self.name() == "Indecisive" {
+            self.set_name_deferred("First");
+            self.set_name_deferred("Second");
+        }
+    }
+}
+
+#[test]
+fn test_deferred_rename_last_wins() {
+    let mut tree: Box<TreeSimple> = TreeSimple::new(scene! { Indecisive }, LoggerVerbosity::NoDebug);
+    tree.process_with_delta(0.0);
+    assert_eq!(tree.root().name(), "Second");
+}
+
+#[test]
+fn test_deferred_rename_canceled_on_exit() {
+    let scene: NodeScene = scene! {
+        Watcher: "Root" {
+            Mover
+        }
+    };
+
+    let mut tree:  Box<TreeSimple> = TreeSimple::new(scene, LoggerVerbosity::NoDebug);
+    let     mover: RID             = tree.root().children_rids()[0];
+    
+    // Schedule the rename, then remove the node before the frame's deferred calls are run.
+    tree.get_node_mut(mover).unwrap().set_name_deferred("Taken");
+    tree.root_mut().remove_child("Mover");
+    tree.root_mut().add_child(Watcher::new());
+    tree.process_with_delta(0.0);
+
+    assert!(tree.root().children().iter().all(|child| child.name() != "Taken"));
+}
@@ -0,0 +1,53 @@
+use node_tree::prelude::*;
+use node_tree::services::node_registry::Value;
+
+
+#[derive(Debug, Clone, Default, PartialEq)]
+struct Stats {
+    hp: i64,
+    mp: i64
+}
+
+impl Exportable for Stats {
+    fn to_value(&self) -> Value {
+        Value::from_iter([("hp", self.hp), ("mp", self.mp)])
+    }
+
+    fn from_value(value: Value) -> Option<Self> where Self: Sized {
+        let table = value.as_inline_table()?;
+        Some(Stats {
+            hp: table.get("hp")?.as_integer()?,
+            mp: table.get("mp")?.as_integer()?
+        })
+    }
+
+    fn to_document_fragment(&self) -> toml_edit::Item {
+        let mut table: toml_edit::Table = toml_edit::Table::new();
+        table.insert("hp", toml_edit::value(self.hp));
+        table.insert("mp", toml_edit::value(self.mp));
+        table.decor_mut().set_prefix("# The character's base stats.\n");
+        toml_edit::Item::Table(table)
+    }
+}
+
+
+class! {
+    dec Character;
+
+    export let stats: Stats = Stats { hp: 10, mp: 4 };
+    export let level: u32   = 1;
+}
+
+
+#[test]
+fn test_document_fragments() {
+    let scene: NodeScene = scene! { Character };
+    let saved: String    = scene.save_to_str().unwrap();
+
+    // The field is written as a standard table rather than an inline one.
+    assert!(saved.contains("# The character's base stats.\n[Character_0.stats]\nhp = 10\nmp = 4\n"));
+    assert!(saved.contains("level = 1"));
+
+    let loaded: NodeScene = NodeScene::load_from_str(&saved).unwrap();
+    assert_eq!(scene, loaded);
+}
@@ -0,0 +1,36 @@
+use node_tree::prelude::*;
+use node_tree::services::node_registry::Value;
+
+
+class! {
+    dec Marker;
+
+    export let position: (f32, f32, f32) = (0.0, 0.0, 0.0);
+}
+
+
+fn parse(value: &str) -> Value {
+    value.parse().unwrap()
+}
+
+
+#[test]
+fn test_export_errors() {
+    assert_eq!(u8::try_from_value(parse("\"1\"")), Err(ExportError::KindMismatch { expected: ValueKind::Integer, found: ValueKind::String }));
+    assert_eq!(<(f32, f32, f32)>::try_from_value(parse("[1.0, 2.0]")), Err(ExportError::LengthMismatch { expected: 3, found: 2 }));
+    assert_eq!(char::try_from_value(parse("\"ab\"")), Err(ExportError::Invalid { expected: "char".to_string(), found: ValueKind::String }));
+    
+    // Containers report which element failed to load.
+    let err: ExportError = Vec::<Option<u32>>::try_from_value(parse("[{ value = 1 }, { value = 2.5 }]")).unwrap_err();
+    assert_eq!(err.to_string(), "at [1], at `value`, expected an integer, found a float");
+    assert_eq!(Vec::<u32>::try_from_value(parse("[1, 2, 3]")), Ok(vec![1, 2, 3]));
+
+    // The errors are passed on when a scene fails to load.
+    let     scene:    NodeScene = scene! { Marker };
+    let mut document: String    = scene.save_to_str().unwrap();
+    assert_eq!(document.matches("position = [0.0, 0.0, 0.0]").count(), 1);
+    
+    document = document.replace("position = [0.0, 0.0, 0.0]", "position = [0.0, 0.0]");
+    let err: String = NodeScene::load_from_str(&document).unwrap_err();
+    assert_eq!(err, "corrupt save data; `position` is invalid: expected an array of 3 elements, found 2");
+}
@@ -0,0 +1,35 @@
+use node_tree::prelude::*;
+use node_tree::trees::TreeSimple;
+
+
+class! {
+    dec Chatty;
+
+    hk ready(&mut self) {
+        debug!(self, "debugging");
+        info!(self, "informing");
+        warn!(self, "warning");
+    }
+}
+
+
+#[test]
+fn test_export_log() {
+    let tree: Box<TreeSimple> = TreeSimple::new(scene! { Chatty }, LoggerVerbosity::All);
+
+    // Only logs at or above the given level are exported.
+    let warnings: String = tree.export_log(LogLevel::Warn, false);
+    assert_eq!(warnings.lines().count(), 1);
+    assert!(warnings.contains("| [Chatty] | WARN | warning"));
+    assert!(!warnings.contains('\u{001b}'));
+
+    let infos: String = tree.export_log(LogLevel::Info, false);
+    assert!(infos.contains("informing") && infos.contains("warning") && !infos.contains("debugging"));
+
+    // Everything is included at the lowest level, and matches the plain log.
+    assert_eq!(tree.export_log(LogLevel::Debug, false), tree.get_log());
+
+    // Colour codes can be kept for console output.
+    let coloured: String = tree.export_log(LogLevel::Warn, true);
+    assert!(coloured.starts_with("\u{001b}[33m") && coloured.ends_with("\u{001b}[0m\n"));
+}
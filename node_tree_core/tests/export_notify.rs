@@ -0,0 +1,51 @@
+use std::cell::Cell;
+
+use node_tree::prelude::*;
+use node_tree::trees::TreeSimple;
+
+
+class! {
+    dec Health;
+
+    export(notify) default let hp: u32;
+    
+    hk ready(&mut self) {
+        let listener: Tp<Listener> = self.get_child(0).unwrap();
+        connect! { hp_changed -> listener.on_hp_changed };
+    }
+
+    hk process(&mut self, _delta: f32) {
+        let hp: u32 = *self.hp() + 10;
+        self.set_hp(hp);
+
+        if *self.hp() == 30 {
+            let listener: Tp<Listener> = self.get_child(0).unwrap();
+            assert_eq!(listener.received.get(), 3);
+            self.tree_mut().unwrap().queue_termination();
+        }
+    }
+}
+
+class! {
+    dec Listener;
+
+    let received: Cell<u32> = Cell::new(0);
+
+    fn on_hp_changed(&self, hp: &u32) {
+        assert_eq!(self.parent::<Health>().unwrap().hp(), hp);
+        self.received.set(self.received.get() + 1);
+    }
+}
+
+
+#[test]
+fn test_export_notify() {
+    let scene: NodeScene = scene! {
+        Health {
+            Listener
+        }
+    };
+
+    let mut tree: Box<TreeSimple> = TreeSimple::new(scene, LoggerVerbosity::NoDebug);
+    while tree.process().is_active() {}
+}
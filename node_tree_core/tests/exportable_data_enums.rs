@@ -0,0 +1,58 @@
+use node_tree::prelude::*;
+use node_tree::services::node_registry::Value;
+use node_tree::trees::TreeSimple;
+
+
+#[derive(Debug, Clone, Default, PartialEq, Exportable)]
+enum State {
+    #[default]
+    Idle,
+    Wait(f32),
+    Patrol(Vec<u32>, bool),
+    Chase { target: String, speed: f32 }
+}
+
+class! {
+    dec Guard;
+
+    export let state: State = State::Idle;
+}
+
+
+#[test]
+fn test_exportable_data_enums() {
+    let states: [State; 4] = [
+        State::Idle,
+        State::Wait(1.5),
+        State::Patrol(vec![1, 2, 3], true),
+        State::Chase { target: "Thief".to_string(), speed: 2.0 }
+    ];
+    for state in &states {
+        assert_eq!(State::from_value(state.to_value()).as_ref(), Some(state));
+    }
+
+    // Unit variants are bare strings, while data variants are tagged by a single key.
+    assert_eq!(State::Idle.to_value().as_str(), Some("Idle"));
+    assert_eq!(State::Wait(1.5).to_value().to_string(), "{ Wait = 1.5 }");
+    assert_eq!(State::Patrol(vec![1], false).to_value().to_string(), "{ Patrol = [[1], false] }");
+    assert_eq!(states[3].to_value().to_string(), "{ Chase = { target = \"Thief\", speed = 2.0 } }");
+
+    // Unknown variants and malformed payloads are rejected with a description of the problem.
+    assert_eq!(State::from_value(Value::from("Sleep")), None);
+    assert_eq!(State::from_value("{ Idle = 1 }".parse().unwrap()), None);
+    assert_eq!(State::from_value("{ Wait = 1.0, Idle = 1 }".parse().unwrap()), None);
+    
+    let err: ExportError = State::try_from_value("{ Patrol = [[1], 2] }".parse().unwrap()).unwrap_err();
+    assert!(err.to_string().contains("[1]"));
+    let err: ExportError = State::try_from_value("{ Chase = { target = \"Thief\" } }".parse().unwrap()).unwrap_err();
+    assert!(err.to_string().contains("speed"));
+
+    // The enum round-trips as an exported field of a saved scene.
+    let mut guard: Guard = Guard::new();
+    *guard.state = states[3].clone();
+    
+    let saved:  String          = NodeScene::new(guard).save_to_str().unwrap();
+    let loaded: NodeScene       = NodeScene::load_from_str(&saved).unwrap();
+    let tree:   Box<TreeSimple> = TreeSimple::new(loaded, LoggerVerbosity::NoDebug);
+    assert_eq!(*tree.root().as_any().downcast_ref::<Guard>().unwrap().state, states[3]);
+}
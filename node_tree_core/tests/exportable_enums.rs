@@ -0,0 +1,31 @@
+use node_tree::prelude::*;
+use node_tree::services::node_registry::Value;
+
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[repr(u8)]
+enum Team {
+    Red  = 3,
+    Blue = 7
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Difficulty {
+    Easy,
+    Hard
+}
+
+impl_exportable_enum!(Team { Red = 3, Blue = 7 });
+impl_exportable_enum!(by_name Difficulty { Easy, Hard });
+
+
+#[test]
+fn test_exportable_enums() {
+    assert_eq!(Team::Blue.to_value().as_integer(), Some(7));
+    assert_eq!(Team::from_value(Team::Red.to_value()), Some(Team::Red));
+    assert_eq!(Team::from_value(Value::from(4)), None);
+
+    assert_eq!(Difficulty::Hard.to_value().as_str(), Some("Hard"));
+    assert_eq!(Difficulty::from_value(Difficulty::Easy.to_value()), Some(Difficulty::Easy));
+    assert_eq!(Difficulty::from_value(Value::from("Medium")), None);
+}
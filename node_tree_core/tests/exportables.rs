@@ -0,0 +1,22 @@
+use std::ops::{ Range, RangeInclusive };
+
+use node_tree::prelude::*;
+use node_tree::services::node_registry::Value;
+
+
+#[test]
+fn test_range_exportables() {
+    let range: Range<i32> = -3..7;
+    assert_eq!(Range::<i32>::from_value(range.to_value()), Some(range.clone()));
+    
+    let inclusive: RangeInclusive<f32> = 0.5..=2.5;
+    assert_eq!(RangeInclusive::<f32>::from_value(inclusive.to_value()), Some(inclusive.clone()));
+    
+    // The two encodings are distinct and cannot be mixed up.
+    assert_eq!(RangeInclusive::<i32>::from_value(range.to_value()), None);
+    assert_eq!(Range::<f32>::from_value(inclusive.to_value()), None);
+
+    // Both endpoints must be present and of the correct type.
+    assert_eq!(Range::<i32>::from_value(Value::from_iter([("start", 1)])), None);
+    assert_eq!(Range::<String>::from_value(range.to_value()), None);
+}
@@ -0,0 +1,51 @@
+use node_tree::prelude::*;
+use node_tree::trees::TreeSimple;
+
+
+class! {
+    dec Fighter;
+
+    sig on_hit(damage: u32);
+
+    export let name_tag: String = "Orc".to_string();
+    export let hp:       u32    = 10;
+    let        scratch:  u32    = 0;
+    export let speed:    f32    = 1.5;
+}
+
+
+#[test]
+fn test_exported_fields() {
+    let tree: Box<TreeSimple> = TreeSimple::new(Fighter::new(), LoggerVerbosity::NoDebug);
+    
+    // Only exported fields are listed, in declaration order, and through a `dyn Node`.
+    let fields: Vec<(String, String)> = tree.root()
+        .exported_fields()
+        .into_iter()
+        .map(|(name, value)| (name, value.to_string().trim().to_string()))
+        .collect();
+    
+    assert_eq!(fields, vec![
+        ("name_tag".to_string(), "\"Orc\"".to_string()),
+        ("hp".to_string(),       "10".to_string()),
+        ("speed".to_string(),    "1.5".to_string())
+    ]);
+}
+
+
+#[test]
+fn test_set_exported_field() {
+    let mut tree: Box<TreeSimple> = TreeSimple::new(Fighter::new(), LoggerVerbosity::NoDebug);
+    let     root: &mut dyn Node   = tree.root_mut();
+
+    assert!(root.set_exported_field("hp", 25.into()).is_ok());
+    assert!(root.set_exported_field("name_tag", "Goblin".into()).is_ok());
+    assert_eq!(*root.as_any().downcast_ref::<Fighter>().unwrap().hp, 25);
+    assert_eq!(*root.as_any().downcast_ref::<Fighter>().unwrap().name_tag, "Goblin");
+
+    // Unknown fields, ghost exports, and mismatched types are rejected.
+    assert!(root.set_exported_field("mana", 5.into()).is_err());
+    assert!(root.set_exported_field("scratch", 5.into()).is_err());
+    assert!(root.set_exported_field("hp", "lots".into()).is_err());
+    assert_eq!(*root.as_any().downcast_ref::<Fighter>().unwrap().hp, 25);
+}
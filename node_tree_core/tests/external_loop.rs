@@ -0,0 +1,47 @@
+use node_tree::prelude::*;
+use node_tree::trees::TreeSimple;
+
+
+class! {
+    dec Game;
+
+    default let elapsed: f32;
+    default let presses: u32;
+
+    hk process(&mut self, delta: f32) {
+        *self.elapsed += delta;
+        if *self.presses == 3 {
+            self.free();
+        }
+    }
+
+    hk input(&mut self, event: &InputEvent) {
+        if let InputEvent::Key { pressed: true, .. } = event {
+            *self.presses += 1;
+            self.tree_mut().unwrap().set_input_as_handled();
+        }
+    }
+}
+
+
+#[test]
+fn test_external_loop() {
+    let mut tree: Box<TreeSimple> = TreeSimple::new(scene! { Game }, LoggerVerbosity::NoDebug);
+
+    // Simulate a host loop which owns the timing, and forwards events between frames.
+    let mut frames: u32 = 0;
+    loop {
+        assert!(tree.handle_event(InputEvent::Key { code: 13, pressed: true }));
+        assert!(!tree.handle_event(InputEvent::Key { code: 13, pressed: false }));
+        
+        if let Some(game) = tree.root().as_any().downcast_ref::<Game>() {
+            assert_eq!(*game.elapsed, frames as f32 * 0.5);
+        }
+        
+        frames += 1;
+        if tree.tick(0.5).has_terminated() {
+            break;
+        }
+    }
+    assert_eq!(frames, 3);
+}
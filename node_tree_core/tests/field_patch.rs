@@ -0,0 +1,44 @@
+use node_tree::prelude::*;
+use node_tree::services::node_registry::SFieldMap;
+use node_tree::trees::TreeSimple;
+
+
+class! {
+    dec Player;
+
+    export let name_tag: String = "Hero".to_string();
+    export let hp:       u32    = 10;
+    export let speed:    f32    = 1.5;
+}
+
+
+#[test]
+fn test_field_patch() {
+    let mut tree: Box<TreeSimple> = TreeSimple::new(Player::new(), LoggerVerbosity::NoDebug);
+    let     root: &mut dyn Node   = tree.root_mut();
+
+    // Only the fields present in the patch are assigned.
+    let mut patch: SFieldMap = SFieldMap::new();
+    patch.insert("hp".into(), 4.into());
+    assert!(root.apply_field_patch(patch).is_ok());
+    
+    let player: &Player = root.as_any().downcast_ref::<Player>().unwrap();
+    assert_eq!(*player.hp, 4);
+    assert_eq!(*player.name_tag, "Hero");
+    assert_eq!(*player.speed, 1.5);
+
+    // A patch with a bad value or an unknown field is rejected as a whole.
+    let mut patch: SFieldMap = SFieldMap::new();
+    patch.insert("speed".into(), 3.0.into());
+    patch.insert("hp".into(), "lots".into());
+    assert!(root.apply_field_patch(patch).is_err());
+
+    let mut patch: SFieldMap = SFieldMap::new();
+    patch.insert("speed".into(), 3.0.into());
+    patch.insert("mana".into(), 5.into());
+    assert!(root.apply_field_patch(patch).is_err());
+    
+    let player: &Player = root.as_any().downcast_ref::<Player>().unwrap();
+    assert_eq!(*player.hp, 4);
+    assert_eq!(*player.speed, 1.5);
+}
@@ -0,0 +1,53 @@
+use node_tree::prelude::*;
+use node_tree::trees::TreeSimple;
+
+
+class! {
+    dec Window;
+
+    export let title: String;
+
+    hk _init(title: String) {}
+}
+
+class! {
+    dec Panel;
+}
+
+class! {
+    dec Button;
+
+    hk ready(&mut self) {
+        
+        // The nearest window is found, rather than the outer one.
+        let window: Tp<Window> = self.find_ancestor_of_type::<Window>().unwrap();
+        assert_eq!(*window.title, "Inner");
+
+        let outer: TpDyn = self.find_ancestor(|node| node.is_root()).unwrap();
+        assert_eq!(outer.name(), "Outer");
+
+        let panel: TpDyn = self.find_ancestor(|node| node.name().starts_with("Panel")).unwrap();
+        assert_eq!(panel.name(), "Panel");
+
+        assert!(self.find_ancestor_of_type::<Button>().is_none());
+        assert!(self.find_ancestor(|node| node.name() == "Button").is_none());
+    }
+}
+
+
+#[test]
+fn test_find_ancestor() {
+    let scene: NodeScene = scene! {
+        Window("Outer".to_string()): "Outer" {
+            Window("Inner".to_string()): "Inner" {
+                Panel {
+                    Button
+                }
+            }
+        }
+    };
+
+    let tree:   Box<TreeSimple> = TreeSimple::new(scene, LoggerVerbosity::NoDebug);
+    let button: &dyn Node       = tree.get_node(tree.get_node_rid(NodePath::from_str("/Outer/Inner/Panel/Button"), None).unwrap()).unwrap();
+    assert_eq!(button.name(), "Button");
+}
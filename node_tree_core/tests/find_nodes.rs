@@ -0,0 +1,31 @@
+use node_tree::prelude::*;
+use node_tree::trees::TreeSimple;
+
+
+class! {
+    dec Searchable;
+}
+
+
+#[test]
+fn test_find_nodes() {
+    let scene: NodeScene = scene! {
+        Searchable: "Root" {
+            Searchable: "A" {
+                Searchable: "A1"
+            },
+            Searchable: "B"
+        }
+    };
+
+    let tree: Box<TreeSimple> = TreeSimple::new(scene, LoggerVerbosity::NoDebug);
+    
+    let order: Vec<String> = tree.iter_bfs().map(|node| node.name().to_string()).collect();
+    assert_eq!(order, vec!["Root", "A", "B", "A1"]);
+
+    let found: Vec<String> = tree.find_nodes(|node| node.name().starts_with('A')).iter().map(|node| node.name().to_string()).collect();
+    assert_eq!(found, vec!["A", "A1"]);
+
+    assert!(tree.find_node(|node| node.childless()).is_some_and(|node| node.name() == "B"));
+    assert!(tree.find_node(|node| node.name() == "Missing").is_none());
+}
@@ -0,0 +1,67 @@
+use node_tree::prelude::*;
+use node_tree::trees::TreeSimple;
+
+
+class! {
+    dec Body;
+
+    export let mass: u32 = 1;
+}
+
+class! {
+    dec Census;
+
+    let counted: usize = 0;
+
+    hk ready(&mut self) {
+        let mut count: usize = 0;
+        self.tree().unwrap().for_each_node(&mut |_| count += 1);
+        *self.counted = count;
+    }
+}
+
+
+#[test]
+fn test_for_each_node() {
+    let scene: NodeScene = scene! {
+        Body: "Root" {
+            Body: "A" {
+                Body: "A1"
+            },
+            Body: "B"
+        }
+    };
+    let mut tree: Box<TreeSimple> = TreeSimple::new(scene, LoggerVerbosity::NoDebug);
+
+    // Every node is visited once, with parents before their children.
+    let mut names: Vec<String> = Vec::new();
+    tree.for_each_node(&mut |node| names.push(node.name().to_string()));
+    assert_eq!(names.len(), 4);
+    assert_eq!(names[0], "Root");
+    assert!(names.iter().position(|n| n == "A").unwrap() < names.iter().position(|n| n == "A1").unwrap());
+
+    tree.for_each_node_mut(&mut |node| {
+        if let Some(body) = node.as_any_mut().downcast_mut::<Body>() {
+            *body.mass += 1;
+        }
+    });
+
+    let mut total: u32 = 0;
+    tree.for_each_node(&mut |node| total += *node.as_any().downcast_ref::<Body>().unwrap().mass);
+    assert_eq!(total, 8);
+}
+
+#[test]
+fn test_for_each_node_from_node() {
+    let scene: NodeScene = scene! {
+        Census: "Root" {
+            Body: "A",
+            Body: "B"
+        }
+    };
+    let tree: Box<TreeSimple> = TreeSimple::new(scene, LoggerVerbosity::NoDebug);
+
+    // Nodes only have a `&dyn NodeTree`, which must still be walkable.
+    let root: &Census = tree.root().as_any().downcast_ref::<Census>().unwrap();
+    assert_eq!(*root.counted, 3);
+}
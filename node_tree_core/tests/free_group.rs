@@ -0,0 +1,52 @@
+use node_tree::prelude::*;
+use node_tree::trees::TreeSimple;
+
+
+class! {
+    dec Level;
+}
+
+class! {
+    dec Enemy;
+
+    hk ready(&mut self) {
+        self.add_to_group("enemies");
+    }
+}
+
+
+#[test]
+fn test_free_group() {
+    let scene: NodeScene = scene! {
+        Level: "Root" {
+            Enemy: "E1" {
+                Enemy: "E1Minion"
+            },
+            Enemy: "E2",
+            Level: "Props" {
+                Level: "Crate",
+                Level: "Barrel"
+            }
+        }
+    };
+
+    let mut tree: Box<TreeSimple> = TreeSimple::new(scene, LoggerVerbosity::NoDebug);
+    assert_eq!(tree.nodes_in_group("enemies").len(), 3);
+
+    // Nothing is freed until the end of the frame.
+    // The minion is only freed once, alongside its queued parent.
+    tree.free_group("enemies");
+    assert_eq!(tree.root().num_children(), 3);
+    
+    tree.process();
+    assert_eq!(tree.root().num_children(), 1);
+    assert!(tree.nodes_in_group("enemies").is_empty());
+
+    // Free by predicate.
+    tree.free_where(|node| node.name() == "Crate");
+    tree.process();
+    
+    let props: RID = tree.root().children_rids()[0];
+    let names: Vec<String> = tree.get_node(props).unwrap().children().iter().map(|node| node.name().to_string()).collect();
+    assert_eq!(names, vec!["Barrel"]);
+}
@@ -0,0 +1,41 @@
+use std::{ fmt::Debug, marker::PhantomData };
+
+use node_tree::prelude::*;
+use node_tree::services::node_registry::SFieldMap;
+use node_tree::trees::TreeSimple;
+
+
+#[derive(Debug, Clone, Abstract, Register)]
+pub struct Spawner<T: Debug + Clone + 'static> {
+    base:   NodeBase,
+    count:  ExportableField<u32>,
+    marker: PhantomData<T>
+}
+
+impl <T: Debug + Clone + 'static> Spawner<T> {
+    fn new(count: u32) -> Self {
+        Spawner { base: NodeBase::new("Spawner".to_string()), count: ExportableField::new(count), marker: PhantomData }
+    }
+}
+
+impl <T: Debug + Clone + 'static> Node for Spawner<T> {}
+
+
+#[derive(Debug, Clone)]
+struct Enemy;
+
+
+#[test]
+fn test_generic_node() {
+    let tree: Box<TreeSimple> = TreeSimple::new(Spawner::<Enemy>::new(3), LoggerVerbosity::NoDebug);
+    assert_eq!(tree.root().name(), "Spawner");
+
+    // The phantom marker is skipped when saving, but the other fields are not.
+    let saved: String = tree.root().save_as_branch().save_to_str().unwrap();
+    assert!(saved.contains("count = 3"));
+    assert!(!saved.contains("marker"));
+    
+    let fields: SFieldMap      = [("count".into(), 3u32.to_value())].into_iter().collect();
+    let loaded: Spawner<Enemy> = Spawner::load_from_owned(fields).unwrap();
+    assert_eq!(*loaded.count, 3);
+}
@@ -0,0 +1,35 @@
+use node_tree::prelude::*;
+use node_tree::trees::TreeSimple;
+
+
+class! {
+    dec GraphRoot;
+
+    hk ready(&mut self) {
+        self.register_as_singleton("GraphRoot".to_string());
+    }
+}
+
+class! {
+    dec GraphChild;
+}
+
+
+#[test]
+fn test_dump_graphviz() {
+    let scene: NodeScene = scene! {
+        GraphRoot {
+            GraphChild,
+            GraphChild
+        }
+    };
+
+    let tree:  Box<TreeSimple> = TreeSimple::new(scene, LoggerVerbosity::NoDebug);
+    let graph: String          = tree.dump_graphviz();
+
+    assert!(graph.starts_with("digraph NodeTree {"));
+    assert!(graph.contains("n0 [label=\"GraphRoot : graphviz::GraphRoot\", shape=box, style=filled, fillcolor=lightblue, peripheries=2];"));
+    assert!(graph.contains("n1 [label=\"GraphChild : graphviz::GraphChild\"];"));
+    assert!(graph.contains("n0 -> n1;"));
+    assert!(graph.contains("n0 -> n2;"));
+}
@@ -0,0 +1,62 @@
+use node_tree::prelude::*;
+use node_tree::trees::TreeSimple;
+
+
+class! {
+    dec InputRoot;
+    
+    default let received: u8;
+
+    hk input(&mut self, event: &InputEvent) {
+        if let InputEvent::Key { code: 32, pressed: true } = event {
+            *self.received += 1;
+        }
+    }
+}
+
+class! {
+    dec InputConsumer;
+
+    default let received: u8;
+
+    hk input(&mut self, event: &InputEvent) {
+        if let Some(msg) = event.custom::<&str>() {
+            assert_eq!(*msg, "consume");
+            *self.received += 1;
+            self.tree_mut().unwrap().set_input_as_handled();
+        }
+    }
+}
+
+class! {
+    dec InputIgnored;
+
+    hk input(&mut self, event: &InputEvent) {
+        if event.custom::<&str>().is_some() {
+            panic!("The event should have been handled before reaching this node!");
+        }
+    }
+}
+
+
+#[test]
+fn test_input_propagation() {
+    let scene: NodeScene = scene! {
+        InputRoot {
+            InputConsumer {
+                InputIgnored
+            }
+        }
+    };
+
+    let mut tree: Box<TreeSimple> = TreeSimple::new(scene, LoggerVerbosity::NoDebug);
+    
+    assert!(!tree.feed_input(InputEvent::Key { code: 32, pressed: true }));
+    assert!(!tree.feed_input(InputEvent::Text("Hello".to_string())));
+    assert!(tree.feed_input(InputEvent::Custom(Box::new("consume"))));
+
+    let root:     &InputRoot        = tree.root().as_any().downcast_ref().unwrap();
+    let consumer: Tp<InputConsumer> = root.get_child(0).unwrap();
+    assert_eq!(*root.received, 1);
+    assert_eq!(*consumer.received, 1);
+}
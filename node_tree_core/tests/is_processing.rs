@@ -0,0 +1,139 @@
+use node_tree::prelude::*;
+use node_tree::trees::TreeSimple;
+
+
+class! {
+    dec ModeRoot;
+
+    default let frame: u8;
+
+    hk process(&mut self, _delta: f32) {
+        *self.frame += 1;
+        
+        let pausable: TpDyn = self.get_node_dyn(nodepath!("Pausable")).unwrap();
+        let inherits: TpDyn = self.get_node_dyn(nodepath!("Inverse/Inherits")).unwrap();
+        let always:   TpDyn = self.get_node_dyn(nodepath!("Inverse/Always")).unwrap();
+        let inverse:  TpDyn = self.get_node_dyn(nodepath!("Inverse")).unwrap();
+
+        match *self.frame {
+            1 => {
+                assert!(self.is_processing());
+                assert!(pausable.is_processing());
+                assert!(!inverse.is_processing());
+                assert!(!inherits.is_processing());
+                assert!(always.is_processing());
+
+                self.tree_mut().unwrap().pause();
+                assert!(!self.is_processing());
+                assert!(!pausable.is_processing());
+                assert!(inverse.is_processing());
+                assert!(inherits.is_processing());
+                assert!(always.is_processing());
+            },
+            _ => panic!("The root should not be processed while the tree is paused!")
+        }
+    }
+}
+
+class! {
+    dec ModeAlways;
+
+    hk process(&mut self, _delta: f32) {
+        if self.tree().unwrap().is_paused() {
+            let inverse: TpDyn = self.get_node_dyn(nodepath!("..")).unwrap();
+            assert!(inverse.is_processing());
+            
+            self.tree_mut().unwrap().unpause();
+            assert!(!inverse.is_processing());
+            self.tree_mut().unwrap().queue_termination();
+            assert!(self.is_processing());
+        }
+    }
+
+    hk process_mode(&self) -> ProcessMode {
+        ProcessMode::Always
+    }
+}
+
+class! {
+    dec ModeInverse;
+
+    hk process_mode(&self) -> ProcessMode {
+        ProcessMode::Inverse
+    }
+}
+
+class! {
+    dec ModePausable;
+
+    hk process_mode(&self) -> ProcessMode {
+        ProcessMode::Pausable
+    }
+}
+
+class! {
+    dec ModeInherit;
+}
+
+class! {
+    dec Spawner;
+
+    default let frame: u8;
+
+    hk process(&mut self, _delta: f32) {
+        *self.frame += 1;
+        
+        match *self.frame {
+            1 => {
+                {
+                    let late: TpDyn = self.add_child_deferred_ready(scene! { ModeInherit: "Late" }).unwrap();
+                    assert!(!late.is_processing());
+                }
+
+                let mut sibling: TpDyn = self.get_node_dyn(nodepath!("Sibling")).unwrap();
+                assert!(sibling.is_processing());
+                sibling.set_disabled(true);
+                assert!(!sibling.is_processing());
+            },
+            _ => {
+                let late: TpDyn = self.get_node_dyn(nodepath!("Late")).unwrap();
+                assert!(late.is_processing());
+                self.tree_mut().unwrap().queue_termination();
+            }
+        }
+    }
+}
+
+
+#[test]
+fn test_is_processing() {
+    let scene: NodeScene = scene! {
+        ModeRoot {
+            ModePausable: "Pausable",
+            ModeInverse: "Inverse" {
+                ModeInherit: "Inherits",
+                ModeAlways: "Always"
+            }
+        }
+    };
+
+    let mut tree: Box<TreeSimple> = TreeSimple::new(scene, LoggerVerbosity::NoDebug);
+    while tree.process().is_active() {}
+    
+    assert!(!tree.root().is_processing());
+}
+
+#[test]
+fn test_is_processing_disabled_and_deferred() {
+    let scene: NodeScene = scene! {
+        Spawner {
+            ModeInherit: "Sibling"
+        }
+    };
+
+    let mut tree: Box<TreeSimple> = TreeSimple::new(scene, LoggerVerbosity::NoDebug);
+    while tree.process().is_active() {}
+
+    let spawner: &Spawner = tree.root().as_any().downcast_ref::<Spawner>().unwrap();
+    assert_eq!(*spawner.frame, 2);
+}
@@ -0,0 +1,37 @@
+use node_tree::prelude::*;
+use node_tree::trees::TreeSimple;
+
+
+class! {
+    dec Branch;
+
+    hk ready(&mut self) {
+        if !self.is_root() {
+            return;
+        }
+
+        let leaves: Vec<String> = self.leaves().iter().map(|leaf| leaf.name().to_string()).collect();
+        assert_eq!(leaves, vec!["D", "E", "C"]);
+
+        let order: Vec<String> = self.bottom_up(true).into_iter().map(|rid| self.tree().unwrap().get_node(rid).unwrap().name().to_string()).collect();
+        assert_eq!(order, vec!["C", "E", "D", "B", "A", "Root"]);
+    }
+}
+
+
+#[test]
+fn test_leaves() {
+    let scene: NodeScene = scene! {
+        Branch: "Root" {
+            Branch: "A" {
+                Branch: "D",
+                Branch: "E"
+            },
+            Branch: "B" {
+                Branch: "C"
+            }
+        }
+    };
+
+    TreeSimple::new(scene, LoggerVerbosity::NoDebug);
+}
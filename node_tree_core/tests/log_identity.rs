@@ -0,0 +1,37 @@
+use node_tree::prelude::*;
+use node_tree::trees::TreeSimple;
+
+
+class! {
+    dec Orc;
+
+    export let hp: u32;
+
+    hk _init(hp: u32) {}
+
+    hk ready(&mut self) {
+        info!(self, "Ready!");
+    }
+
+    hk log_identity(&self) -> Option<String> {
+        Some(format!("Orc#{} (hp={})", self.rid(), *self.hp))
+    }
+}
+
+
+#[test]
+fn test_log_identity() {
+    let scene: NodeScene = scene! {
+        Orc(10): "Root" {
+            Orc(25): "Minion"
+        }
+    };
+
+    let tree: Box<TreeSimple> = TreeSimple::new(scene, LoggerVerbosity::All);
+    let log:  &str            = tree.get_log();
+
+    // The richer label is used in place of the node's path.
+    assert!(log.contains("Orc#0 (hp=10)"));
+    assert!(log.contains("(hp=25)"));
+    assert!(!log.contains("Root/Minion"));
+}
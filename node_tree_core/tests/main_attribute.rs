@@ -0,0 +1,66 @@
+use std::sync::atomic::{ AtomicU32, Ordering };
+use std::time::{ Duration, Instant };
+
+use node_tree::prelude::*;
+use node_tree::trees::TreeSimple;
+
+
+static FRAMES: AtomicU32 = AtomicU32::new(0);
+
+
+class! {
+    dec Countdown;
+
+    let remaining: u32 = 3;
+    
+    hk process(&mut self, _delta: f32) {
+        FRAMES.fetch_add(1, Ordering::SeqCst);
+        *self.remaining -= 1;
+        if *self.remaining == 0 {
+            self.tree_mut().unwrap().queue_termination();
+        }
+    }
+}
+
+class! {
+    dec CappedCountdown;
+
+    let remaining: u32 = 3;
+    
+    hk process(&mut self, _delta: f32) {
+        *self.remaining -= 1;
+        if *self.remaining == 0 {
+            self.tree_mut().unwrap().queue_termination();
+        }
+    }
+}
+
+
+#[node_tree::main(root = Countdown, verbosity = OnlyIssues)]
+fn run(tree: &mut TreeSimple) {
+    assert_eq!(tree.root().name(), "Countdown");
+    assert_eq!(FRAMES.load(Ordering::SeqCst), 0);
+}
+
+#[node_tree::main(root = CappedCountdown, verbosity = OnlyIssues)]
+fn run_capped(tree: &mut TreeSimple) {
+    tree.set_target_fps(Some(50.0));
+}
+
+
+#[test]
+fn test_main_attribute() {
+    
+    // The tree is run until it terminates.
+    run();
+    assert_eq!(FRAMES.load(Ordering::SeqCst), 3);
+}
+
+#[test]
+fn test_main_attribute_target_fps() {
+    
+    // The frame cap set during setup is honoured by the generated loop.
+    let started: Instant = Instant::now();
+    run_capped();
+    assert!(started.elapsed() >= Duration::from_secs_f32(3.0 / 50.0));
+}
@@ -0,0 +1,47 @@
+use node_tree::prelude::*;
+use node_tree::trees::TreeSimple;
+
+
+class! {
+    dec Part;
+}
+
+
+#[test]
+fn test_make_scene_root() {
+    let mut scene: NodeScene = scene! {
+        Part: "Root" {
+            Part: "Level" {
+                Part: "Player",
+                Part: "Enemies" {
+                    Part: "Orc"
+                }
+            }
+        }
+    };
+    scene.append_as_owner(scene! {
+        Part: "Hud" {
+            Part: "Health"
+        }
+    });
+
+    let mut tree:  Box<TreeSimple> = TreeSimple::new(scene, LoggerVerbosity::NoDebug);
+    let     level: RID             = tree.root().children_rids()[0];
+    let     hud:   RID             = tree.root().children_rids()[1];
+    assert!(!tree.get_node(level).unwrap().is_owner());
+
+    tree.get_node_mut(level).unwrap().make_scene_root();
+    
+    // The level's branch is now saved on its own, and is owned by the level.
+    let level_node: &dyn Node = tree.get_node(level).unwrap();
+    assert!(level_node.is_owner());
+    for node in level_node.top_down(false) {
+        assert_eq!(tree.get_node(node).unwrap().owner_dyn().rid(), level);
+    }
+    
+    // Other scenes are unaffected.
+    assert_eq!(tree.get_node(hud).unwrap().owner_dyn().rid(), hud);
+    let health: RID = tree.get_node(hud).unwrap().children_rids()[0];
+    assert_eq!(tree.get_node(health).unwrap().owner_dyn().rid(), hud);
+    assert_eq!(tree.root().owner_dyn().rid(), tree.root().rid());
+}
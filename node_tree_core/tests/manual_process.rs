@@ -0,0 +1,67 @@
+use node_tree::prelude::*;
+use node_tree::trees::TreeSimple;
+
+
+class! {
+    dec Driver;
+
+    let frames: u32 = 0;
+
+    hk process(&mut self, delta: f32) {
+        *self.frames += 1;
+        if *self.frames % 2 == 0 {
+            self.get_node::<ManualCounter>(NodePath::from_str("Manual")).unwrap().process_now(delta);
+        }
+    }
+}
+
+class! {
+    dec Counter;
+
+    let calls: u32 = 0;
+
+    hk process(&mut self, _delta: f32) {
+        *self.calls += 1;
+    }
+}
+
+class! {
+    dec ManualCounter;
+
+    let calls: u32 = 0;
+
+    hk process(&mut self, _delta: f32) {
+        *self.calls += 1;
+    }
+
+    hk process_mode(&self) -> ProcessMode {
+        ProcessMode::Manual
+    }
+}
+
+
+#[test]
+fn test_manual_process_mode() {
+    let scene: NodeScene = scene! {
+        Driver {
+            ManualCounter: "Manual" {
+                Counter: "Child"
+            }
+        }
+    };
+
+    let mut tree: Box<TreeSimple> = TreeSimple::new(scene, LoggerVerbosity::NoDebug);
+    let manual:   RID             = tree.get_node_rid(NodePath::from_str("/Driver/Manual"), None).unwrap();
+    let child:    RID             = tree.get_node_rid(NodePath::from_str("/Driver/Manual/Child"), None).unwrap();
+
+    // Children of a manual node inherit from the node above it rather than becoming manual.
+    assert_eq!(tree.resolve_process_mode(manual), Some(ProcessMode::Manual));
+    assert_eq!(tree.resolve_process_mode(child),  Some(ProcessMode::Pausable));
+
+    // The manual node is only processed when the driver asks it to, every other frame.
+    assert!(!tree.step_until(6, 0.1, |_| false));
+    let manual_calls: u32 = *tree.get_node(manual).unwrap().as_any().downcast_ref::<ManualCounter>().unwrap().calls;
+    let child_calls:  u32 = *tree.get_node(child).unwrap().as_any().downcast_ref::<Counter>().unwrap().calls;
+    assert_eq!(manual_calls, 3);
+    assert_eq!(child_calls,  6);
+}
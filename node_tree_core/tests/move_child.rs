@@ -0,0 +1,43 @@
+use node_tree::prelude::*;
+use node_tree::trees::TreeSimple;
+
+
+class! {
+    dec Layer;
+}
+
+
+fn names(tree: &TreeSimple) -> Vec<String> {
+    tree.root().children_iter().map(|child| child.name().to_string()).collect()
+}
+
+
+#[test]
+fn test_move_child() {
+    let scene: NodeScene = scene! {
+        Layer: "Canvas" {
+            Layer: "A",
+            Layer: "B",
+            Layer: "C",
+            Layer: "D"
+        }
+    };
+    let mut tree: Box<TreeSimple> = TreeSimple::new(scene, LoggerVerbosity::NoDebug);
+
+    assert!(tree.root_mut().move_child("A", 2));
+    assert_eq!(names(&tree), ["B", "C", "A", "D"]);
+    assert!(!tree.root_mut().move_child("A", 4));
+    assert!(!tree.root_mut().move_child("E", 0));
+
+    let d: RID = tree.rid_of_path(&NodePath::from_str("D")).unwrap();
+    assert!(tree.get_node_mut(d).unwrap().move_to_front());
+    assert_eq!(names(&tree), ["D", "B", "C", "A"]);
+    
+    let b: RID = tree.rid_of_path(&NodePath::from_str("B")).unwrap();
+    assert!(tree.get_node_mut(b).unwrap().move_to_back());
+    assert_eq!(names(&tree), ["D", "C", "A", "B"]);
+
+    // The root has no siblings to move among.
+    assert!(!tree.root_mut().move_to_front());
+    assert!(!tree.root_mut().move_to_back());
+}
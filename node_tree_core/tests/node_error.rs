@@ -0,0 +1,37 @@
+use node_tree::prelude::*;
+use node_tree::trees::TreeSimple;
+use node_tree::structs::node_base::NodeStatus;
+
+
+class! {
+    dec Fallible;
+
+    let failures: u8 = 0;
+    
+    hk try_process(&mut self, _delta: f32) -> Result<(), NodeError> {
+        *self.failures += 1;
+        if *self.failures < 3 {
+            return Err(NodeError::custom(format!("failure #{}", *self.failures)));
+        }
+        Ok(())
+    }
+}
+
+
+#[test]
+fn test_node_error() {
+    let mut tree: Box<TreeSimple> = TreeSimple::new(Fallible::new(), LoggerVerbosity::NoDebug);
+    
+    // By default, errors are logged as warnings and the tree keeps running.
+    assert_eq!(tree.error_policy(), NodeErrorPolicy::Continue);
+    tree.process();
+    assert!(tree.status().is_active());
+    assert!(tree.get_log().contains("failure #1"));
+    assert!(matches!(tree.root().status(), NodeStatus::JustWarned(_)));
+
+    // Under the terminate policy, the next error brings the tree down.
+    tree.set_error_policy(NodeErrorPolicy::Terminate);
+    tree.process();
+    assert_eq!(tree.status(), TreeStatus::Terminated);
+    assert!(tree.get_log().contains("failure #2"));
+}
@@ -0,0 +1,33 @@
+use node_tree::prelude::*;
+use node_tree::trees::TreeSimple;
+
+
+class! {
+    dec RidRoot;
+
+    hk ready(&mut self) {
+        let child_rid: RID           = self.get_child_dyn(0).unwrap().rid();
+        let tree:      &dyn NodeTree = self.tree().unwrap();
+
+        let child: TpDyn = tree.node_from_rid(child_rid).unwrap();
+        assert_eq!(child.name(), "Stored");
+        assert!(tree.node_from_rid(RID::MAX).is_none());
+    }
+}
+
+class! {
+    dec RidChild;
+}
+
+
+#[test]
+fn test_node_from_rid() {
+    let scene: NodeScene = scene! {
+        RidRoot {
+            RidChild: "Stored"
+        }
+    };
+
+    let tree: Box<TreeSimple> = TreeSimple::new(scene, LoggerVerbosity::NoDebug);
+    assert!(tree.node_from_rid(0).is_some_and(|root| root.is_root()));
+}
@@ -0,0 +1,32 @@
+use std::rc::Rc;
+use std::cell::RefCell;
+
+use node_tree::prelude::*;
+use node_tree::trees::TreeSimple;
+
+
+class! {
+    dec Renamable;
+}
+
+
+#[test]
+fn test_node_renamed() {
+    let scene: NodeScene = scene! {
+        Renamable: "Root" {
+            Renamable: "Child"
+        }
+    };
+
+    let     renames: Rc<RefCell<Vec<(RID, String, String)>>> = Rc::new(RefCell::new(Vec::new()));
+    let mut tree:    Box<TreeSimple>                         = TreeSimple::new(scene, LoggerVerbosity::NoDebug);
+    
+    let renames_ref: Rc<RefCell<Vec<(RID, String, String)>>> = renames.clone();
+    tree.on_node_renamed(move |rid, old, new| renames_ref.borrow_mut().push((rid, old.to_string(), new.to_string())));
+
+    let child_rid: RID = tree.root().children_rids()[0];
+    tree.get_node_mut(child_rid).unwrap().set_name("Renamed");
+
+    assert_eq!(*renames.borrow(), vec![(child_rid, "/Root/Child".to_string(), "/Root/Renamed".to_string())]);
+    assert_eq!(tree.root().get_node_dyn(nodepath!("/Root/Renamed")).unwrap().rid(), child_rid);
+}
@@ -0,0 +1,22 @@
+use node_tree::prelude::*;
+
+
+#[test]
+fn test_nodepath_builder() {
+    let mut path: NodePath = NodePath::new();
+    path.push("a");
+    path.push("b");
+    assert_eq!(path.to_string(), "a/b");
+
+    let joined: NodePath = path.join(&nodepath!("../c"));
+    assert_eq!(joined.to_string(), "a/b/../c");
+    assert_eq!(joined.file_name(), Some("c"));
+    assert_eq!(path.join(&nodepath!("/root/d")), nodepath!("/root/d"));
+
+    let parent: NodePath = path.parent().unwrap();
+    assert_eq!(parent.to_string(), "a");
+    assert_eq!(parent.parent().unwrap().parent(), None);
+    assert_eq!(NodePath::new().file_name(), None);
+
+    assert_eq!(nodepath!("/root/child").to_string(), "/root/child");
+}
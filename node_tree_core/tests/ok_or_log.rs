@@ -0,0 +1,41 @@
+use node_tree::prelude::*;
+use node_tree::trees::TreeSimple;
+
+
+class! {
+    dec Seeker;
+
+    hk ready(&mut self) {
+        let missing: Option<TpDyn> = self.get_node_dyn(NodePath::from_str("Missing")).ok_or_log("Could not find the target");
+        assert!(missing.is_none());
+        
+        let absent: Option<TpDyn> = self.cached_node(&NodePath::from_str("Absent")).ok_or_log("Could not find the cached target");
+        assert!(absent.is_none());
+
+        // Present values are returned without posting anything.
+        let found: Option<TpDyn> = self.get_node_dyn(NodePath::from_str("Target")).ok_or_log("Could not find the present target");
+        assert!(found.is_some());
+    }
+}
+
+class! {
+    dec Target;
+}
+
+
+#[test]
+fn test_ok_or_log() {
+    let scene: NodeScene = scene! {
+        Seeker {
+            Target
+        }
+    };
+
+    let mut tree: Box<TreeSimple> = TreeSimple::new(scene, LoggerVerbosity::All);
+    assert!(tree.process().is_active());
+
+    let log: &str = tree.get_log();
+    assert!(log.contains("Could not find the target: The root path"));
+    assert!(log.contains("Could not find the cached target"));
+    assert!(!log.contains("Could not find the present target"));
+}
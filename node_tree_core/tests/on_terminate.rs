@@ -0,0 +1,45 @@
+use std::rc::Rc;
+use std::cell::RefCell;
+
+use node_tree::prelude::*;
+use node_tree::trees::TreeSimple;
+
+
+class! {
+    dec Quitter;
+
+    hk process(&mut self, _delta: f32) {
+        self.tree_mut().unwrap().queue_termination();
+    }
+}
+
+
+#[test]
+fn test_on_terminate() {
+    let calls: Rc<RefCell<Vec<&str>>> = Rc::new(RefCell::new(Vec::new()));
+    
+    // Callbacks fire in order once a queued termination completes.
+    let mut tree:   Box<TreeSimple>        = TreeSimple::new(scene! { Quitter }, LoggerVerbosity::NoDebug);
+    let     first:  Rc<RefCell<Vec<&str>>> = calls.clone();
+    let     second: Rc<RefCell<Vec<&str>>> = calls.clone();
+    tree.on_terminate(move |tree| {
+        assert_eq!(tree.status(), TreeStatus::Terminated);
+        first.borrow_mut().push("first");
+    });
+    tree.on_terminate(move |_| second.borrow_mut().push("second"));
+
+    while tree.process().is_active() {}
+    assert_eq!(*calls.borrow(), ["first", "second"]);
+
+    // They are not called again.
+    tree.terminate();
+    assert_eq!(calls.borrow().len(), 2);
+
+    // Immediate termination also calls them.
+    let mut tree:  Box<TreeSimple>        = TreeSimple::new(scene! { Quitter }, LoggerVerbosity::NoDebug);
+    let     third: Rc<RefCell<Vec<&str>>> = calls.clone();
+    tree.on_terminate(move |_| third.borrow_mut().push("third"));
+
+    tree.terminate();
+    assert_eq!(*calls.borrow(), ["first", "second", "third"]);
+}
@@ -0,0 +1,51 @@
+use node_tree::prelude::*;
+use node_tree::trees::TreeSimple;
+
+
+class! {
+    dec Emitter;
+    
+    sig on_event(count: u8);
+    
+    hk ready(&mut self) {
+        for i in 0..2 {
+            let listener: Tp<Listener> = self.get_child(i).unwrap();
+            connect! { on_event -> listener.listener (owned_by listener) };
+        }
+    }
+}
+
+class! {
+    dec Listener;
+
+    fn listener(&self, count: &u8) {
+        info!(self, "Heard {count}");
+    }
+}
+
+
+fn listeners(tree: &TreeSimple) -> usize {
+    tree.root().as_any().downcast_ref::<Emitter>().unwrap().on_event.listener_count()
+}
+
+
+#[test]
+fn test_owned_connections() {
+    let scene: NodeScene = scene! {
+        Emitter: "Root" {
+            Listener: "A",
+            Listener: "B"
+        }
+    };
+
+    let mut tree: Box<TreeSimple> = TreeSimple::new(scene, LoggerVerbosity::NoDebug);
+    assert_eq!(listeners(&tree), 2);
+
+    // Connections are dropped as soon as their owner leaves the tree.
+    tree.root_mut().remove_child("A");
+    assert_eq!(listeners(&tree), 1);
+    
+    let b: RID = tree.root().children_rids()[0];
+    tree.get_node_mut(b).unwrap().free();
+    assert_eq!(listeners(&tree), 0);
+}
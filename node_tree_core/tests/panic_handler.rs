@@ -0,0 +1,65 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use node_tree::prelude::*;
+use node_tree::trees::TreeSimple;
+
+
+class! {
+    dec PanicRoot;
+}
+
+class! {
+    dec Faulty;
+
+    hk process(&mut self, _delta: f32) {
+        error!(self, "Something went wrong!");
+    }
+}
+
+
+#[test]
+fn test_panic_handler() {
+    let scene: NodeScene = scene! {
+        PanicRoot {
+            Faulty
+        }
+    };
+
+    let     captured: Rc<RefCell<Option<PanicContext>>> = Rc::new(RefCell::new(None));
+    let     handle:   Rc<RefCell<Option<PanicContext>>> = captured.clone();
+    let mut tree:     Box<TreeSimple>                    = TreeSimple::new(scene, LoggerVerbosity::NoDebug);
+
+    tree.set_panic_handler(move |context| *handle.borrow_mut() = Some(context.clone()));
+    while tree.process().is_active() {}
+
+    let context: PanicContext = captured.borrow_mut().take().unwrap();
+    assert_eq!(context.path, nodepath!("/PanicRoot/Faulty"));
+    assert_eq!(context.type_name, "panic_handler::Faulty");
+    assert_eq!(context.message, "Something went wrong!");
+    assert!(context.tree.contains("Faulty"));
+}
+
+#[test]
+fn test_unregistered_panic() {
+    let scene: NodeScene = scene! {
+        PanicRoot
+    };
+
+    let     captured: Rc<RefCell<Option<PanicContext>>> = Rc::new(RefCell::new(None));
+    let     handle:   Rc<RefCell<Option<PanicContext>>> = captured.clone();
+    let mut tree:     Box<TreeSimple>                    = TreeSimple::new(scene, LoggerVerbosity::NoDebug);
+
+    // Logs from RIDs that do not belong to the tree are still posted.
+    tree.post(9999, Log::Warn("Stray warning!"));
+    assert!(tree.get_log().contains("Unregistered Node (RID 9999)"));
+    
+    tree.set_panic_handler(move |context| *handle.borrow_mut() = Some(context.clone()));
+    tree.post(9999, Log::Panic("Stray panic!"));
+
+    let context: PanicContext = captured.borrow_mut().take().unwrap();
+    assert_eq!(context.path, NodePath::new());
+    assert!(context.type_name.is_empty());
+    assert_eq!(context.message, "Stray panic!");
+    assert!(context.tree.is_empty());
+}
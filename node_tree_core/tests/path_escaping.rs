@@ -0,0 +1,45 @@
+use node_tree::prelude::*;
+use node_tree::trees::TreeSimple;
+
+
+class! {
+    dec Menu;
+}
+
+
+#[test]
+fn test_path_escaping() {
+    let scene: NodeScene = scene! {
+        Menu {
+            Menu: "Save/Load" {
+                Menu: "C:\\Saves"
+            }
+        }
+    };
+
+    let tree: Box<TreeSimple> = TreeSimple::new(scene, LoggerVerbosity::NoDebug);
+    let node: RID             = tree.get_node_rid(NodePath::from_str("/Menu/Save\\/Load"), None).unwrap();
+    assert_eq!(tree.get_node(node).unwrap().name(), "Save/Load");
+
+    // Raw segments bypass parsing entirely.
+    let raw: NodePath = NodePath::from_segments(&["Save/Load", "C:\\Saves"]);
+    assert_eq!(raw, NodePath::from_str("Save\\/Load/C:\\\\Saves"));
+    assert!(tree.get_node_rid(raw.clone(), Some(0)).is_some());
+    
+    // Formatted paths escape their names, and can be parsed back.
+    assert_eq!(raw.to_string(), "Save\\/Load/C:\\\\Saves");
+    assert_eq!(NodePath::from_str(&raw.to_string()), raw);
+    
+    // Absolute paths keep slashed names as single segments.
+    let own: NodePath = tree.get_node(node).unwrap().get_absolute_path();
+    assert_eq!(own, NodePath::from_segments(&["Menu", "Save/Load"]));
+    assert_eq!(own.to_string(), "Menu/Save\\/Load");
+    assert_eq!(own.file_name(), Some("Save/Load"));
+
+    let absolute: NodePath = tree.path_of_rid(node).unwrap();
+    assert_eq!(absolute.to_string(), "/Menu/Save\\/Load");
+    assert_eq!(tree.rid_of_path(&NodePath::from_str(&absolute.to_string())), Some(node));
+
+    // Unrelated backslashes are kept as they are.
+    assert_eq!(NodePath::from_str("a\\b").file_name(), Some("a\\b"));
+}
@@ -0,0 +1,44 @@
+use node_tree::prelude::*;
+use node_tree::trees::TreeSimple;
+
+
+class! {
+    dec Entity;
+}
+
+
+fn scene() -> NodeScene {
+    scene! {
+        Entity: "World" {
+            Entity: "Players" {
+                Entity: "Hero"
+            },
+            Entity: "Enemies" {
+                Entity: "Orc"
+            }
+        }
+    }
+}
+
+
+#[test]
+fn test_path_to() {
+    let tree:  Box<TreeSimple> = TreeSimple::new(scene(), LoggerVerbosity::NoDebug);
+    let other: Box<TreeSimple> = TreeSimple::new(scene(), LoggerVerbosity::NoDebug);
+    
+    let hero: &dyn Node = tree.get_node(tree.get_node_rid(NodePath::from_str("/World/Players/Hero"), None).unwrap()).unwrap();
+    let orc:  TpDyn     = tree.node_from_rid(tree.get_node_rid(NodePath::from_str("/World/Enemies/Orc"), None).unwrap()).unwrap();
+
+    // Paths climb to the common ancestor before descending.
+    let path: NodePath = hero.path_to(&orc).unwrap();
+    assert_eq!(path.to_string(), "../../Enemies/Orc");
+    assert_eq!(hero.get_node_raw(path), Some(orc.rid()));
+
+    assert_eq!(tree.root().path_to(&orc).unwrap().to_string(), "Enemies/Orc");
+    assert_eq!(orc.path_to(&tree.node_from_rid(tree.root().rid()).unwrap()).unwrap().to_string(), "../..");
+    assert_eq!(orc.path_to(&orc).unwrap().to_string(), ".");
+
+    // There is no path between nodes of different trees.
+    let foreign: TpDyn = other.node_from_rid(other.root().rid()).unwrap();
+    assert!(hero.path_to(&foreign).is_none());
+}
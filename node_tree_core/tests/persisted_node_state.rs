@@ -0,0 +1,43 @@
+use node_tree::prelude::*;
+use node_tree::trees::TreeSimple;
+
+
+class! {
+    dec Tagged;
+
+    export let value: u32 = 0;
+}
+
+
+#[test]
+fn test_persisted_node_state() {
+    let scene: NodeScene = scene! {
+        Tagged {
+            Tagged: "Enemy",
+            Tagged: "Plain"
+        }
+    };
+
+    let mut tree:  Box<TreeSimple> = TreeSimple::new(scene, LoggerVerbosity::NoDebug);
+    let     enemy: RID             = tree.get_node_rid(NodePath::from_str("/Tagged/Enemy"), None).unwrap();
+    {
+        let enemy: &mut dyn Node = tree.get_node_mut(enemy).unwrap();
+        enemy.add_to_group("enemies");
+        enemy.add_to_group("damageable");
+        enemy.set_process_mode_override(Some(ProcessMode::Always));
+    }
+
+    // Save the tree and load it back in.
+    let document: String          = tree.root().save_as_branch().save_to_str().unwrap();
+    let loaded:   NodeScene       = NodeScene::load_from_str(&document).unwrap();
+    let tree:     Box<TreeSimple> = TreeSimple::new(loaded, LoggerVerbosity::NoDebug);
+
+    let enemy: &dyn Node = tree.get_node(tree.get_node_rid(NodePath::from_str("/Tagged/Enemy"), None).unwrap()).unwrap();
+    assert_eq!(enemy.groups(), ["enemies", "damageable"]);
+    assert_eq!(enemy.process_mode_override(), Some(ProcessMode::Always));
+    assert_eq!(tree.resolve_process_mode(enemy.rid()), Some(ProcessMode::Always));
+
+    let plain: &dyn Node = tree.get_node(tree.get_node_rid(NodePath::from_str("/Tagged/Plain"), None).unwrap()).unwrap();
+    assert!(plain.groups().is_empty());
+    assert_eq!(plain.process_mode_override(), None);
+}
@@ -0,0 +1,75 @@
+use node_tree::prelude::*;
+use node_tree::trees::TreeSimple;
+
+
+class! {
+    dec Room;
+}
+
+class! {
+    dec Button;
+
+    sig pressed(amount: u32);
+}
+
+class! {
+    dec Door;
+
+    export let opened: u32 = 0;
+}
+
+
+fn press(tree: &TreeSimple, amount: u32) {
+    let button: RID = tree.get_node_rid(NodePath::from_str("/Room/Button"), None).unwrap();
+    tree.get_node(button).unwrap().as_any().downcast_ref::<Button>().unwrap().pressed.emit(amount);
+}
+
+fn opened(tree: &TreeSimple) -> u32 {
+    let door: RID = tree.get_node_rid(NodePath::from_str("/Room/Door"), None).unwrap();
+    *tree.get_node(door).unwrap().as_any().downcast_ref::<Door>().unwrap().opened
+}
+
+
+#[test]
+fn test_persistent_connections() {
+    register_signal::<Button, u32>("pressed", |button| &button.pressed);
+    register_method::<Door, u32>("open", |door, amount| *door.opened += amount);
+
+    let scene: NodeScene = scene! {
+        Room {
+            Button,
+            Door
+        }
+    };
+
+    // Connections made within a tree are established immediately.
+    let mut tree:   Box<TreeSimple> = TreeSimple::new(scene, LoggerVerbosity::NoDebug);
+    let     button: RID             = tree.get_node_rid(NodePath::from_str("/Room/Button"), None).unwrap();
+    tree.get_node_mut(button).unwrap().connect_persistent("pressed", NodePath::from_str("../Door"), "open");
+    
+    press(&tree, 2);
+    assert_eq!(opened(&tree), 2);
+
+    // The connection survives saving and loading.
+    let     document: String          = tree.root().save_as_branch().save_to_str().unwrap();
+    let     loaded:   NodeScene       = NodeScene::load_from_str(&document).unwrap();
+    let mut tree:     Box<TreeSimple> = TreeSimple::new(loaded, LoggerVerbosity::NoDebug);
+
+    let button: RID = tree.get_node_rid(NodePath::from_str("/Room/Button"), None).unwrap();
+    assert_eq!(tree.get_node(button).unwrap().persistent_connections(), [PersistentConnection {
+        signal: "pressed".to_string(),
+        target: NodePath::from_str("../Door"),
+        method: "open".to_string()
+    }]);
+
+    press(&tree, 3);
+    assert_eq!(opened(&tree), 5);
+    assert!(tree.status_summary().is_clean());
+
+    // Unregistered methods are reported rather than silently ignored.
+    tree.get_node_mut(button).unwrap().connect_persistent("pressed", NodePath::from_str("../Door"), "close");
+    
+    press(&tree, 1);
+    assert_eq!(opened(&tree), 6);
+    assert_eq!(tree.status_summary().warn, 1);
+}
@@ -0,0 +1,58 @@
+use node_tree::prelude::*;
+use node_tree::trees::TreeSimple;
+
+
+class! {
+    dec Region;
+}
+
+
+fn region(name: &str) -> NodeScene {
+    let mut region: Region = Region::new();
+    region.set_name(name);
+    NodeScene::new(region)
+}
+
+fn child_names(tree: &TreeSimple) -> Vec<String> {
+    tree.root().children_iter().map(|child| child.name().to_string()).collect()
+}
+
+
+#[test]
+fn test_placeholders() {
+    let mut far: NodeScene = region("Far");
+    far.append(region("Town"));
+    let mut cave: NodeScene = region("Cave");
+    cave.append(region("Tunnel"));
+    
+    let mut world: NodeScene = region("World");
+    world.append(region("Near"));
+    world.append(far.as_placeholder());
+    world.append(cave.as_placeholder());
+
+    let mut tree: Box<TreeSimple> = TreeSimple::new(world, LoggerVerbosity::NoDebug);
+    assert_eq!(child_names(&tree), ["Near", "Far", "Cave"]);
+    assert!(tree.root().get_node::<Placeholder>(nodepath!("Far")).to_result().is_ok());
+    assert!(tree.root().get_node_dyn(nodepath!("Far/Town")).to_result().is_err());
+
+    // Dormant placeholders are saved as-is.
+    let saved:  NodeScene = tree.root().save_as_branch();
+    let loaded: NodeScene = NodeScene::load_from_str(&saved.save_to_str().unwrap()).unwrap();
+    assert_eq!(saved, loaded);
+    assert_eq!(loaded.children()[1].root_type_name(), std::any::type_name::<Placeholder>());
+
+    // Expanding a placeholder replaces it with its scene, in the same position.
+    let far_rid: RID = tree.rid_of_path(&NodePath::from_str("Far")).unwrap();
+    assert!(tree.instantiate_placeholder(far_rid).is_some());
+    assert_eq!(child_names(&tree), ["Near", "Far", "Cave"]);
+    assert!(tree.root().get_node::<Region>(nodepath!("Far/Town")).to_result().is_ok());
+    assert_eq!(tree.instantiate_placeholder(far_rid), None);
+
+    // Expanding from the placeholder itself happens at the end of the frame.
+    let cave_rid: RID = tree.rid_of_path(&NodePath::from_str("Cave")).unwrap();
+    assert!(tree.get_node(cave_rid).unwrap().instantiate_placeholder());
+    assert!(tree.root().get_node_dyn(nodepath!("Cave/Tunnel")).to_result().is_err());
+    tree.process_with_delta(0.1);
+    assert!(tree.root().get_node::<Region>(nodepath!("Cave/Tunnel")).to_result().is_ok());
+    assert!(!tree.root().instantiate_placeholder());
+}
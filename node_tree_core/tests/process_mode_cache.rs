@@ -0,0 +1,51 @@
+use std::sync::atomic::{ AtomicU32, Ordering };
+
+use node_tree::prelude::*;
+use node_tree::trees::TreeSimple;
+
+
+static QUERIES: AtomicU32 = AtomicU32::new(0);
+
+
+class! {
+    dec Ticker;
+
+    let ticks: u32 = 0;
+    
+    hk process(&mut self, _delta: f32) {
+        *self.ticks += 1;
+    }
+
+    hk process_mode(&self) -> ProcessMode {
+        QUERIES.fetch_add(1, Ordering::SeqCst);
+        ProcessMode::Pausable
+    }
+}
+
+
+#[test]
+fn test_process_mode_cache() {
+    let mut tree: Box<TreeSimple> = TreeSimple::new(scene! { Ticker { Ticker } }, LoggerVerbosity::NoDebug);
+    tree.set_process_mode_caching(true);
+    assert!(tree.is_caching_process_modes());
+
+    // Each node's process mode is only queried once while the tree remains the same.
+    let before: u32 = QUERIES.load(Ordering::SeqCst);
+    for _ in 0..5 {
+        tree.process_with_delta(0.1);
+    }
+    assert_eq!(QUERIES.load(Ordering::SeqCst) - before, 2);
+
+    // Overrides still take effect immediately.
+    tree.root_mut().set_process_mode_override(Some(ProcessMode::Manual));
+    tree.process_with_delta(0.1);
+    let ticks: u32 = *tree.root().as_any().downcast_ref::<Ticker>().unwrap().ticks;
+    tree.process_with_delta(0.1);
+    assert_eq!(*tree.root().as_any().downcast_ref::<Ticker>().unwrap().ticks, ticks);
+
+    // Structural changes invalidate the cache.
+    let before: u32 = QUERIES.load(Ordering::SeqCst);
+    tree.root_mut().add_child(Ticker::new());
+    tree.process_with_delta(0.1);
+    assert_eq!(QUERIES.load(Ordering::SeqCst) - before, 2);
+}
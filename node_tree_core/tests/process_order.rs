@@ -0,0 +1,104 @@
+use std::sync::Mutex;
+
+use node_tree::prelude::*;
+use node_tree::trees::TreeSimple;
+
+
+static ORDER: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+
+class! {
+    dec Item;
+
+    default let size: u32;
+
+    hk process(&mut self, _delta: f32) {
+        *self.size += 1;
+        ORDER.lock().unwrap().push(self.name().to_string());
+    }
+}
+
+class! {
+    dec Column;
+
+    default let total: u32;
+
+    hk process_order(&self) -> ProcessOrder {
+        ProcessOrder::ChildrenFirst
+    }
+
+    hk process(&mut self, _delta: f32) {
+        *self.total = (0..self.num_children()).map(|i| *self.get_child::<Item>(i).unwrap().size).sum();
+        ORDER.lock().unwrap().push(self.name().to_string());
+    }
+}
+
+class! {
+    dec Row;
+
+    default let total: u32;
+
+    hk process(&mut self, _delta: f32) {
+        *self.total = *self.get_child::<Item>(0).unwrap().size;
+        ORDER.lock().unwrap().push(self.name().to_string());
+    }
+}
+
+
+#[test]
+fn test_process_order() {
+    let scene: NodeScene = scene! {
+        Row {
+            Item: "A",
+            Column {
+                Item: "B",
+                Item: "C"
+            }
+        }
+    };
+
+    let mut tree: Box<TreeSimple> = TreeSimple::new(scene, LoggerVerbosity::NoDebug);
+    tree.process_with_delta(0.0);
+
+    // The column runs after its children, while the row runs before them.
+    assert_eq!(*ORDER.lock().unwrap(), ["Row", "A", "B", "C", "Column"]);
+
+    // As such, the column sees its children's sizes from this frame, and the row from the last.
+    let column: RID = tree.get_node_rid(NodePath::from_str("/Row/Column"), None).unwrap();
+    assert_eq!(*tree.get_node(column).unwrap().as_any().downcast_ref::<Column>().unwrap().total, 2);
+    assert_eq!(*tree.root().as_any().downcast_ref::<Row>().unwrap().total, 0);
+}
+
+class! {
+    dec Counter;
+
+    default let ticks: u32;
+
+    hk process(&mut self, _delta: f32) {
+        *self.ticks += 1;
+    }
+}
+
+class! {
+    dec Spawner;
+
+    hk process(&mut self, _delta: f32) {
+        if self.num_children() == 0 {
+            self.add_child(Counter::new());
+        }
+    }
+}
+
+
+#[test]
+fn test_spawned_children_wait_a_frame() {
+    let mut tree: Box<TreeSimple> = TreeSimple::new(Spawner::new(), LoggerVerbosity::NoDebug);
+    let     ticks = |tree: &TreeSimple| *tree.get_node(tree.root().children_rids()[0]).unwrap().as_any().downcast_ref::<Counter>().unwrap().ticks;
+
+    // A child added during its parent's `process()` is not processed until the next frame.
+    tree.process_with_delta(0.0);
+    assert_eq!(ticks(&tree), 0);
+
+    tree.process_with_delta(0.0);
+    assert_eq!(ticks(&tree), 1);
+}
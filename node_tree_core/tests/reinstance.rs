@@ -0,0 +1,32 @@
+use std::rc::Rc;
+use std::cell::Cell;
+
+use node_tree::prelude::*;
+
+
+class! {
+    dec Spawner;
+
+    export let hp:     i32            = 10;
+           let shared: Rc<Cell<i32>>  = Rc::new(Cell::new(0));
+}
+
+
+#[test]
+fn test_reinstance() {
+    let mut original: Spawner = Spawner::new();
+    *original.hp = 25;
+    original.shared.set(5);
+
+    // A clone shares any reference-counted state with the original.
+    let cloned: Box<dyn Node> = original.clone_as_instance();
+    let cloned: &Spawner      = cloned.as_any().downcast_ref().unwrap();
+    assert!(Rc::ptr_eq(&cloned.shared, &original.shared));
+
+    // A reinstance is built from the exported fields alone.
+    let fresh: Box<dyn Node> = original.reinstance();
+    let fresh: &Spawner      = fresh.as_any().downcast_ref().unwrap();
+    assert_eq!(*fresh.hp, 25);
+    assert!(fresh.shared.is_void());
+    assert_eq!(fresh.name(), original.name());
+}
@@ -0,0 +1,53 @@
+use std::env;
+use std::fs;
+use std::path::{ Path, PathBuf };
+
+use node_tree::prelude::*;
+use node_tree::trees::TreeSimple;
+
+
+class! {
+    dec Room;
+
+    export let size: u32 = 1;
+}
+
+
+#[test]
+fn test_reload_from_scene() {
+    let scene: NodeScene = scene! {
+        Room: "World" {
+            Room: "Hall" {
+                Room: "Old"
+            }
+        }
+    };
+    let edited: NodeScene = scene! {
+        Room: "Hall" {
+            Room: "Kitchen" {
+                Room: "Pantry"
+            },
+            Room: "Study"
+        }
+    };
+
+    let dir: PathBuf = env::temp_dir();
+    edited.save(&dir, "reload_from_scene").unwrap();
+
+    let mut tree: Box<TreeSimple> = TreeSimple::new(scene, LoggerVerbosity::NoDebug);
+    let     hall: RID             = tree.get_node_rid(NodePath::from_str("/World/Hall"), None).unwrap();
+
+    // A missing file leaves the children untouched.
+    assert!(tree.get_node_mut(hall).unwrap().reload_from_scene(Path::new("missing.scn")).is_err());
+    assert!(tree.get_node_rid(NodePath::from_str("/World/Hall/Old"), None).is_some());
+
+    // Otherwise, the children are swapped out while the node itself stays the same.
+    assert!(tree.get_node_mut(hall).unwrap().reload_from_scene(&dir.join("reload_from_scene.scn")).is_ok());
+    fs::remove_file(dir.join("reload_from_scene.scn")).unwrap();
+
+    assert_eq!(tree.get_node_rid(NodePath::from_str("/World/Hall"), None), Some(hall));
+    assert_eq!(tree.get_node_rid(NodePath::from_str("/World/Hall/Old"), None), None);
+    assert!(tree.get_node_rid(NodePath::from_str("/World/Hall/Kitchen/Pantry"), None).is_some());
+    assert!(tree.get_node_rid(NodePath::from_str("/World/Hall/Study"), None).is_some());
+    assert!(tree.process().is_active());
+}
@@ -0,0 +1,62 @@
+use node_tree::prelude::*;
+use node_tree::trees::TreeSimple;
+
+
+class! {
+    dec Menu;
+
+    hk ready(&mut self) {
+        if self.is_root() {
+            self.register_as_singleton("Menu".to_string());
+        }
+    }
+
+    hk terminal(&mut self, reason: TerminationReason) {
+        if matches!(reason, TerminationReason::Freed) {
+            info!(self, "Closed {}", self.name());
+        }
+    }
+}
+
+class! {
+    dec Game;
+
+    let readied: bool = false;
+
+    hk ready(&mut self) {
+        *self.readied = true;
+    }
+}
+
+
+#[test]
+fn test_replace_root() {
+    let scene: NodeScene = scene! {
+        Menu {
+            Menu: "Options"
+        }
+    };
+
+    let mut tree: Box<TreeSimple> = TreeSimple::new(scene, LoggerVerbosity::All);
+    tree.replace_root(scene! {
+        Game {
+            Game: "Player"
+        }
+    });
+
+    // The old tree was torn down from the top down, along with its singletons.
+    let log: &str = tree.get_log();
+    assert!(log.find("Closed Menu").unwrap() < log.find("Closed Options").unwrap());
+    assert_eq!(tree.get_node_rid("Menu".to_string(), None), None);
+
+    // The new scene is installed as the root and fully readied.
+    assert_eq!(tree.root().name(), "Game");
+    assert_eq!(tree.root().rid(), 0);
+    assert!(tree.root().as_any().downcast_ref::<Game>().is_some_and(|game| *game.readied));
+    assert!(tree.get_node_rid(NodePath::from_str("/Game/Player"), None).is_some());
+    assert!(tree.process().is_active());
+
+    // Renaming the root changes every absolute path.
+    tree.rename_root("World");
+    assert!(tree.get_node_rid(NodePath::from_str("/World/Player"), None).is_some());
+}
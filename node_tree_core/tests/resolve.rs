@@ -0,0 +1,47 @@
+use node_tree::prelude::*;
+use node_tree::trees::TreeSimple;
+
+
+class! {
+    dec Place;
+}
+
+class! {
+    dec Settings;
+
+    hk ready(&mut self) {
+        self.register_as_singleton("Settings".to_string());
+    }
+}
+
+
+#[test]
+fn test_resolve() {
+    let scene: NodeScene = scene! {
+        Place: "Root" {
+            Place: "A" {
+                Place: "B",
+                Place: "Settings"
+            },
+            Settings: "C"
+        }
+    };
+
+    let tree: Box<TreeSimple> = TreeSimple::new(scene, LoggerVerbosity::NoDebug);
+    let a:    RID             = tree.root().children_rids()[0];
+    let b:    RID             = tree.get_node(a).unwrap().children_rids()[0];
+    let a:    &dyn Node       = tree.get_node(a).unwrap();
+    let b:    &dyn Node       = tree.get_node(b).unwrap();
+
+    // Absolute, owner-relative, singleton, then self-relative addressing.
+    assert_eq!(b.resolve("/Root/A/B").unwrap().name(), "B");
+    assert_eq!(b.resolve("~/A").unwrap().name(), "A");
+    assert_eq!(b.resolve("~").unwrap().name(), "Root");
+    assert_eq!(a.resolve("Settings").unwrap().name(), "C");
+    assert_eq!(a.resolve("./Settings").unwrap().name(), "Settings");
+    assert_eq!(b.resolve("../B").unwrap().name(), "B");
+    assert_eq!(a.resolve("B").unwrap().name(), "B");
+
+    assert!(a.resolve("/Other").is_err());
+    assert!(a.resolve("~/Missing").is_err());
+}
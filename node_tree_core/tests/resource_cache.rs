@@ -0,0 +1,58 @@
+use std::env;
+use std::fs::{ self, File };
+use std::path::{ Path, PathBuf };
+use std::rc::Rc;
+use std::time::{ Duration, SystemTime };
+
+use node_tree::prelude::*;
+use node_tree::trees::TreeSimple;
+
+
+class! {
+    dec Spawner;
+}
+
+class! {
+    dec Enemy;
+
+    export let health: u32 = 10;
+}
+
+
+#[test]
+fn test_resource_cache() {
+    let dir:  PathBuf = env::temp_dir();
+    let path: PathBuf = dir.join("resource_cache_enemy.scn");
+    NodeScene::new(Enemy::new()).save(&dir, "resource_cache_enemy").unwrap();
+
+    let mut tree: Box<TreeSimple> = TreeSimple::new(Spawner::new(), LoggerVerbosity::NoDebug);
+    
+    // The scene is only parsed once, and may be instanced as many times as needed.
+    let first:  Rc<NodeScene> = tree.load_scene_cached(&path).unwrap();
+    let second: Rc<NodeScene> = tree.load_scene_cached(&path).unwrap();
+    assert!(Rc::ptr_eq(&first, &second));
+    assert!(tree.resource_cache().contains(&path));
+
+    tree.root_mut().add_child(NodeScene::clone(&first));
+    tree.root_mut().add_child(NodeScene::clone(&second));
+    assert_eq!(tree.root().num_children(), 2);
+
+    // Modifying the file invalidates the cached scene.
+    let mut enemy: Enemy = Enemy::new();
+    *enemy.health = 20;
+    NodeScene::new(enemy).save(&dir, "resource_cache_enemy").unwrap();
+    File::options().write(true).open(&path).unwrap().set_modified(SystemTime::now() + Duration::from_secs(60)).unwrap();
+
+    let third: Rc<NodeScene> = tree.load_scene_cached(&path).unwrap();
+    assert!(!Rc::ptr_eq(&first, &third));
+    assert_eq!(*third.instance_as::<Enemy>().unwrap().health, 20);
+
+    // Clearing the cache does not invalidate scenes that were handed out.
+    tree.clear_cache();
+    assert!(tree.resource_cache().is_empty());
+    assert_eq!(*first.instance_as::<Enemy>().unwrap().health, 10);
+
+    fs::remove_file(&path).unwrap();
+    assert!(tree.load_scene_cached(Path::new("missing.scn")).is_err());
+    assert!(tree.resource_cache().is_empty());
+}
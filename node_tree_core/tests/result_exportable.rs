@@ -0,0 +1,25 @@
+use node_tree::prelude::*;
+use node_tree::services::node_registry::Value;
+
+
+#[test]
+fn test_result_ok_round_trip() {
+    let ok: Result<u32, String> = Ok(42);
+    assert_eq!(Result::<u32, String>::from_value(ok.to_value()), Some(ok));
+
+    let nested: Result<Vec<i32>, String> = Ok(vec![1, 2, 3]);
+    assert_eq!(Result::<Vec<i32>, String>::from_value(nested.to_value()), Some(nested));
+}
+
+
+#[test]
+fn test_result_err_round_trip() {
+    let err: Result<u32, String> = Err("missing config".to_string());
+    assert_eq!(Result::<u32, String>::from_value(err.to_value()), Some(err));
+
+    // The value must be tagged by exactly one of the two keys, with the correct type.
+    assert_eq!(Result::<u32, String>::from_value(Value::from_iter([("ok", 1), ("err", 2)])), None);
+    assert_eq!(Result::<u32, String>::from_value(None::<u32>.to_value()), None);
+    assert_eq!(Result::<u32, String>::from_value(Value::from_iter([("err", 2)])), None);
+    assert_eq!(Result::<u32, String>::from_value(Value::from(1)), None);
+}
@@ -0,0 +1,32 @@
+use node_tree::prelude::*;
+use node_tree::trees::TreeSimple;
+
+
+class! {
+    dec Place;
+
+    export let target: RID;
+
+    hk _init(target: RID) {}
+}
+
+
+#[test]
+fn test_rid_display() {
+    let scene: NodeScene = scene! {
+        Place(0): "Root" {
+            Place(0): "A"
+        }
+    };
+
+    let tree: Box<TreeSimple> = TreeSimple::new(scene, LoggerVerbosity::NoDebug);
+    let a:    RID             = tree.root().children_rids()[0];
+
+    // RIDs round trip through their string form.
+    assert_eq!(a.to_string().parse::<RID>(), Ok(a));
+
+    // RIDs may be stored in exported fields for intra-session tooling.
+    let saved:  String    = NodeScene::new(Place::new(a)).save_to_str().unwrap();
+    let loaded: NodeScene = NodeScene::load_from_str(&saved).unwrap();
+    assert!(loaded.diff(&NodeScene::new(Place::new(a))).is_empty());
+}
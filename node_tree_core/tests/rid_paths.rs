@@ -0,0 +1,35 @@
+use node_tree::prelude::*;
+use node_tree::trees::TreeSimple;
+
+
+class! {
+    dec PathNode;
+}
+
+
+#[test]
+fn test_rid_paths() {
+    let scene: NodeScene = scene! {
+        PathNode: "Root" {
+            PathNode: "A" {
+                PathNode: "B"
+            }
+        }
+    };
+
+    let tree: Box<TreeSimple> = TreeSimple::new(scene, LoggerVerbosity::NoDebug);
+    let a:    RID             = tree.root().children_rids()[0];
+    let b:    RID             = tree.get_node(a).unwrap().children_rids()[0];
+    
+    assert_eq!(tree.path_of_rid(b), Some(nodepath!("/Root/A/B")));
+    assert_eq!(tree.path_of_rid(RID::MAX), None);
+    
+    assert_eq!(tree.rid_of_path(&nodepath!("/Root/A/B")), Some(b));
+    assert_eq!(tree.rid_of_path(&nodepath!("A")), Some(a));
+    assert_eq!(tree.rid_of_path(&nodepath!("/Root/C")), None);
+
+    // The debug output shows the paths of referenced nodes.
+    let debug: String = format!("{:?}", tree.get_node(a).unwrap().base());
+    assert!(debug.contains("Parent: /Root (0)"));
+    assert!(debug.contains(&format!("/Root/A/B ({b})")));
+}
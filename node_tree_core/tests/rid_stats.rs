@@ -0,0 +1,31 @@
+use node_tree::prelude::*;
+use node_tree::trees::TreeSimple;
+
+
+class! {
+    dec Spawn;
+}
+
+
+#[test]
+fn test_rid_stats() {
+    let scene: NodeScene = scene! {
+        Spawn: "Root" {
+            Spawn: "A",
+            Spawn: "B",
+            Spawn: "C"
+        }
+    };
+
+    let mut tree: Box<TreeSimple> = TreeSimple::new(scene, LoggerVerbosity::NoDebug);
+    assert_eq!(tree.rid_stats(), RidStats { live: 4, capacity: 4, free_slots: 0 });
+
+    // Freed nodes leave free slots behind.
+    tree.free_where(|node| node.name() != "Root" && node.name() != "B");
+    tree.process_with_delta(0.1);
+    assert_eq!(tree.rid_stats(), RidStats { live: 2, capacity: 4, free_slots: 2 });
+
+    // Which are then reused before the capacity grows.
+    tree.root_mut().add_child(Spawn::new());
+    assert_eq!(tree.rid_stats(), RidStats { live: 3, capacity: 4, free_slots: 1 });
+}
@@ -0,0 +1,44 @@
+use node_tree::prelude::*;
+use node_tree::trees::TreeSimple;
+
+
+class! {
+    dec Level;
+}
+
+class! {
+    dec Item;
+}
+
+
+#[test]
+fn test_scene_depth() {
+    let sub_scene: NodeScene = scene! {
+        Item: "Chest" {
+            Item: "Lid" {
+                Item: "Lock"
+            }
+        }
+    };
+    let scene: NodeScene = scene! {
+        Level {
+            Item: "Room"
+        }
+    };
+
+    // Instance the sub scene at runtime so that it keeps its own owner.
+    let mut tree: Box<TreeSimple> = TreeSimple::new(scene, LoggerVerbosity::NoDebug);
+    let     room: RID             = tree.get_node_rid(NodePath::from_str("/Level/Room"), None).unwrap();
+    tree.get_node_mut(room).unwrap().add_child(sub_scene);
+
+    let node = |path: &str| tree.get_node(tree.get_node_rid(NodePath::from_str(path), None).unwrap()).unwrap();
+
+    assert_eq!(node("/Level").scene_depth(), 0);
+    assert_eq!(node("/Level/Room").scene_depth(), 1);
+
+    // The sub scene's depth is counted from its own root.
+    let lock: &dyn Node = node("/Level/Room/Chest/Lid/Lock");
+    assert_eq!(lock.depth(), 4);
+    assert_eq!(lock.scene_depth(), 2);
+    assert_eq!(node("/Level/Room/Chest").scene_depth(), 0);
+}
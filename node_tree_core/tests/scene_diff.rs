@@ -0,0 +1,95 @@
+use std::collections::HashMap;
+
+use node_tree::prelude::*;
+
+
+class! {
+    dec DiffRoot;
+
+    export let items: HashMap<String, u8> = vec![("a".to_string(), 0), ("b".to_string(), 1), ("c".to_string(), 2)].into_iter().collect();
+}
+
+class! {
+    dec DiffLeaf;
+
+    export let weight: f32;
+
+    hk _init(weight: f32) {}
+}
+
+
+#[test]
+fn test_scene_diff() {
+    let scene: NodeScene = scene! {
+        DiffRoot: "Root" {
+            DiffLeaf(1.0): "A",
+            DiffLeaf(1.0): "B"
+        }
+    };
+
+    // A scene should survive a round trip through its saved form.
+    let loaded: NodeScene = NodeScene::load_from_str(&scene.save_to_str().unwrap()).unwrap();
+    assert!(scene == loaded);
+    assert!(scene.diff(&loaded).is_empty());
+
+    // Detect changed fields, along with added and reordered nodes.
+    let changed: NodeScene = scene! {
+        DiffRoot: "Root" {
+            DiffLeaf(1.0): "B",
+            DiffLeaf(2.0): "A",
+            DiffLeaf(1.0): "C"
+        }
+    };
+
+    let diffs: Vec<SceneDiff> = scene.diff(&changed);
+    assert!(scene != changed);
+    assert_eq!(diffs.len(), 3);
+    assert!(diffs.contains(&SceneDiff::FieldChanged {
+        path:  nodepath!("Root/A"),
+        field: "weight".to_string(),
+        old:   Some("1.0".to_string()),
+        new:   Some("2.0".to_string())
+    }));
+    assert!(diffs.contains(&SceneDiff::NodeAdded { path: nodepath!("Root/C") }));
+    assert!(diffs.contains(&SceneDiff::ChildrenReordered { path: nodepath!("Root") }));
+}
+
+#[test]
+fn test_scene_diff_persisted_state() {
+    let build = |groups: &[&str], mode: Option<ProcessMode>, method: &str| {
+        let mut leaf: DiffLeaf = DiffLeaf::new(1.0);
+        leaf.set_name("A");
+        for group in groups {
+            leaf.add_to_group(group);
+        }
+        leaf.set_process_mode_override(mode);
+        leaf.connect_persistent("hit", nodepath!(".."), method);
+
+        let mut scene: NodeScene = NodeScene::from_node(DiffRoot::new());
+        scene.append(NodeScene::from_node(leaf));
+        scene
+    };
+    let connection = |method: &str| PersistentConnection {
+        signal: "hit".to_string(),
+        target: nodepath!(".."),
+        method: method.to_string()
+    };
+
+    // Groups are compared regardless of the order that they were joined in.
+    let scene: NodeScene = build(&["common", "enemies"], None, "on_hit");
+    assert!(scene == build(&["enemies", "common"], None, "on_hit"));
+
+    // Scenes which only differ by their persisted state are not equal.
+    let changed: NodeScene      = build(&["common", "allies"], Some(ProcessMode::Always), "on_struck");
+    let diffs:   Vec<SceneDiff> = scene.diff(&changed);
+    let path:    NodePath       = nodepath!("DiffRoot/A");
+    assert!(scene != changed);
+    assert_eq!(diffs.len(), 3);
+    assert!(diffs.contains(&SceneDiff::GroupsChanged {
+        path: path.clone(),
+        old:  vec!["common".to_string(), "enemies".to_string()],
+        new:  vec!["allies".to_string(), "common".to_string()]
+    }));
+    assert!(diffs.contains(&SceneDiff::ProcessModeChanged { path: path.clone(), old: None, new: Some(ProcessMode::Always) }));
+    assert!(diffs.contains(&SceneDiff::ConnectionsChanged { path, old: vec![connection("on_hit")], new: vec![connection("on_struck")] }));
+}
@@ -0,0 +1,40 @@
+use node_tree::prelude::*;
+
+
+class! {
+    dec Menu;
+}
+
+class! {
+    dec MenuItem;
+
+    export let label: String;
+
+    hk _init(label: String) {}
+}
+
+
+fn named<N: Node>(mut node: N, name: &str) -> N {
+    node.set_name(name);
+    node
+}
+
+
+#[test]
+fn test_scene_from_node() {
+    let expected: NodeScene = scene! {
+        Menu: "Menu" {
+            MenuItem("Play".to_string()): "Play",
+            MenuItem("Quit".to_string()): "Quit",
+            Menu: "Options"
+        }
+    };
+
+    // Assemble the same scene programmatically, node-by-node.
+    let mut scene: NodeScene = NodeScene::from_node(named(Menu::new(), "Menu"));
+    scene.append(NodeScene::from_node(named(MenuItem::new("Play".to_string()), "Play")));
+    scene.append(NodeScene::from_node(named(MenuItem::new("Quit".to_string()), "Quit")));
+    scene.append(NodeScene::from_node_dyn(named(Menu::new(), "Options").to_dyn_box()));
+
+    assert!(scene.diff(&expected).is_empty());
+}
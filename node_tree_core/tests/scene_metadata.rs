@@ -0,0 +1,31 @@
+use node_tree::prelude::*;
+
+
+class! {
+    dec Part;
+}
+
+
+#[test]
+fn test_scene_metadata() {
+    let scene: NodeScene = scene! {
+        Part: "Root" {
+            Part: "A" {
+                Part: "B" {
+                    Part: "C"
+                }
+            },
+            Part: "D"
+        }
+    };
+
+    assert_eq!(scene.node_count(), 5);
+    assert_eq!(scene.max_depth(), 3);
+    assert_eq!(NodeScene::new(Part::new()).node_count(), 1);
+    assert_eq!(NodeScene::new(Part::new()).max_depth(), 0);
+
+    // The metadata is available on loaded scenes before they are instanced.
+    let loaded: NodeScene = NodeScene::load_from_str(&scene.save_to_str().unwrap()).unwrap();
+    assert_eq!(loaded.node_count(), 5);
+    assert_eq!(loaded.max_depth(), 3);
+}
@@ -0,0 +1,32 @@
+use node_tree::prelude::*;
+
+
+class! {
+    dec Level;
+
+    export let difficulty: u8 = 1;
+}
+
+class! {
+    dec Menu;
+}
+
+
+#[test]
+fn test_scene_root_type() {
+    let mut level: Level = Level::new();
+    *level.difficulty = 3;
+    level.set_name("Dungeon");
+
+    let mut scene:  NodeScene = NodeScene::new(level);
+    scene.append(scene! { Menu });
+    
+    let     loaded: NodeScene = NodeScene::load_from_str(&scene.save_to_str().unwrap()).unwrap();
+    assert_eq!(loaded.root_type_name(), std::any::type_name::<Level>());
+
+    // The root can be instanced as its own type, but not as any other.
+    let root: Box<Level> = loaded.instance_as::<Level>().unwrap();
+    assert_eq!(root.name(), "Dungeon");
+    assert_eq!(*root.difficulty, 3);
+    assert!(loaded.instance_as::<Menu>().is_none());
+}
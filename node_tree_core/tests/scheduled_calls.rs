@@ -0,0 +1,49 @@
+use std::rc::Rc;
+use std::cell::RefCell;
+
+use node_tree::prelude::*;
+use node_tree::trees::TreeSimple;
+
+
+class! {
+    dec Idle;
+}
+
+
+#[test]
+fn test_scheduled_calls() {
+    let calls: Rc<RefCell<Vec<&str>>> = Rc::new(RefCell::new(Vec::new()));
+    let mut tree: Box<TreeSimple> = TreeSimple::new(scene! { Idle }, LoggerVerbosity::NoDebug);
+
+    let deferred: Rc<RefCell<Vec<&str>>> = calls.clone();
+    let timed:    Rc<RefCell<Vec<&str>>> = calls.clone();
+    let dropped:  Rc<RefCell<Vec<&str>>> = calls.clone();
+    
+    tree.call_deferred(move |_| deferred.borrow_mut().push("deferred"));
+    tree.after(0.25, move |_| timed.borrow_mut().push("timed"));
+    let handle: CancelHandle = tree.after(0.1, move |_| dropped.borrow_mut().push("canceled"));
+    handle.cancel();
+    assert!(handle.is_canceled());
+
+    // Deferred calls run at the end of the next frame, while timed calls wait for enough time to
+    // pass. Canceled calls never run.
+    tree.process_with_delta(0.1);
+    assert_eq!(*calls.borrow(), ["deferred"]);
+    tree.process_with_delta(0.1);
+    assert_eq!(*calls.borrow(), ["deferred"]);
+    tree.process_with_delta(0.1);
+    assert_eq!(*calls.borrow(), ["deferred", "timed"]);
+
+    // Calls deferred from within a deferred call run on the following frame.
+    let outer: Rc<RefCell<Vec<&str>>> = calls.clone();
+    tree.call_deferred(move |tree| {
+        outer.borrow_mut().push("outer");
+        let inner: Rc<RefCell<Vec<&str>>> = outer.clone();
+        tree.call_deferred(move |_| inner.borrow_mut().push("inner"));
+    });
+
+    tree.process_with_delta(0.1);
+    assert_eq!(calls.borrow().last(), Some(&"outer"));
+    tree.process_with_delta(0.1);
+    assert_eq!(calls.borrow().last(), Some(&"inner"));
+}
@@ -0,0 +1,50 @@
+use node_tree::prelude::*;
+use node_tree::services::node_registry::SFieldMap;
+
+
+class! {
+    #[schema(version = 2, migrate = "Self::migrate_fields")]
+    dec Fighter;
+
+    export let health: u32 = 100;
+
+    fn migrate_fields(version: u32, fields: &mut SFieldMap) {
+        if version < 2 {
+            if let Some(hp) = fields.remove("hp") {
+                fields.insert("health".into(), hp);
+            }
+        }
+    }
+}
+
+
+fn health_of(scene: &NodeScene) -> u32 {
+    let node:   Box<dyn Node> = unsafe { scene.get_node() };
+    let health: u32           = *node.as_any().downcast_ref::<Fighter>().unwrap().health;
+    
+    let _ = Box::into_raw(node); // The scene still owns the node.
+    health
+}
+
+
+#[test]
+fn test_schema_migration() {
+    let mut fighter: Fighter = Fighter::new();
+    *fighter.health = 42;
+
+    // Current saves embed the schema version and load as is.
+    let saved: String = NodeScene::new(fighter).save_to_str().unwrap();
+    assert!(saved.contains("__schema_version = 2"));
+    
+    let loaded: NodeScene = NodeScene::load_from_str(&saved).unwrap();
+    assert_eq!(health_of(&loaded), 42);
+
+    // Saves from the first version of the schema stored the field as `hp`.
+    let v1:       String    = saved.replace("__schema_version = 2", "__schema_version = 1").replace("health", "hp");
+    let migrated: NodeScene = NodeScene::load_from_str(&v1).unwrap();
+    assert_eq!(health_of(&migrated), 42);
+
+    // Saves from a newer schema cannot be loaded.
+    let v3: String = saved.replace("__schema_version = 2", "__schema_version = 3");
+    assert!(NodeScene::load_from_str(&v3).is_err());
+}
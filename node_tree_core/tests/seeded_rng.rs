@@ -0,0 +1,43 @@
+use node_tree::prelude::*;
+use node_tree::trees::TreeSimple;
+
+
+class! {
+    dec Dice;
+}
+
+
+fn roll(seed: u64) -> Vec<u64> {
+    let scene: NodeScene = scene! {
+        Dice: "Root" {
+            Dice: "A",
+            Dice: "B"
+        }
+    };
+
+    let mut tree: Box<TreeSimple> = TreeSimple::new(scene, LoggerVerbosity::NoDebug);
+    tree.set_seed(seed);
+
+    let mut rolls: Vec<u64> = Vec::new();
+    for rid in tree.root().children_rids().to_vec() {
+        let node: &dyn Node = tree.get_node(rid).unwrap();
+        let mut rng: NodeRng = node.rng();
+        rolls.push(rng.next_u64());
+        rolls.push(rng.next_u64());
+    }
+    rolls
+}
+
+
+#[test]
+fn test_seeded_rng() {
+    let first:  Vec<u64> = roll(42);
+    let second: Vec<u64> = roll(42);
+    let other:  Vec<u64> = roll(7);
+
+    // The same seed reproduces the same streams, while each node has its own stream.
+    assert_eq!(first, second);
+    assert_ne!(first, other);
+    assert_ne!(first[0], first[1]);
+    assert_ne!(first[0], first[2]);
+}
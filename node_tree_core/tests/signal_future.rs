@@ -0,0 +1,69 @@
+#![cfg(feature = "async")]
+
+use std::future::Future;
+use std::pin::{ pin, Pin };
+use std::sync::Arc;
+use std::sync::atomic::{ AtomicBool, Ordering };
+use std::task::{ Context, Poll, Wake, Waker };
+
+use node_tree::prelude::*;
+use node_tree::structs::signals::SignalFuture;
+use node_tree::trees::TreeSimple;
+
+
+class! {
+    dec Target;
+
+    sig on_hit(damage: u32);
+
+    let frames: u32 = 0;
+
+    hk process(&mut self, _delta: f32) {
+        *self.frames += 1;
+        if *self.frames == 3 {
+            self.on_hit.emit(*self.frames * 10);
+        }
+    }
+}
+
+
+/// Records whether the future has been woken.
+struct Flag(AtomicBool);
+
+impl Wake for Flag {
+    fn wake(self: Arc<Self>) {
+        self.0.store(true, Ordering::SeqCst);
+    }
+}
+
+
+#[test]
+fn test_signal_future() {
+    let mut tree:   Box<TreeSimple> = TreeSimple::new(scene! { Target }, LoggerVerbosity::NoDebug);
+    let     target: &dyn Node       = tree.root();
+    let     target: &Target         = target.as_any().downcast_ref::<Target>().unwrap();
+
+    let     flag:    Arc<Flag>                    = Arc::new(Flag(AtomicBool::new(false)));
+    let     waker:   Waker                        = Waker::from(flag.clone());
+    let mut context: Context                      = Context::from_waker(&waker);
+    let mut hit:     Pin<&mut SignalFuture<u32>>  = pin!(target.on_hit.next());
+
+    // Dropping a pending future removes its connection.
+    drop(target.on_hit.next());
+    assert_eq!(target.on_hit.listener_count(), 1);
+
+    // Drive the future between frames until the signal is emitted.
+    let mut frames: u32 = 0;
+    let     damage: u32 = loop {
+        if let Poll::Ready(damage) = hit.as_mut().poll(&mut context) {
+            break damage;
+        }
+        tree.process();
+        frames += 1;
+    };
+    
+    assert_eq!(damage, 30);
+    assert_eq!(frames, 3);
+    assert!(flag.0.load(Ordering::SeqCst));
+    assert_eq!(tree.root().as_any().downcast_ref::<Target>().unwrap().on_hit.listener_count(), 0);
+}
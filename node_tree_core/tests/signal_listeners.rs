@@ -0,0 +1,23 @@
+use node_tree::prelude::*;
+
+
+#[test]
+fn test_signal_listeners() {
+    let signal: Signal<u8> = Signal::new();
+    assert_eq!(signal.listener_count(), 0);
+    assert_eq!(signal.emit(0), 0);
+
+    let persistent: RID = unsafe { signal.connect(|_| ()) };
+    unsafe {
+        signal.connect_once(|_| ());
+    }
+    assert_eq!(signal.listener_count(), 2);
+
+    // The one-shot connection is invoked once and then pruned.
+    assert_eq!(signal.emit(1), 2);
+    assert_eq!(signal.listener_count(), 1);
+    assert_eq!(signal.emit(2), 1);
+
+    signal.disconnect(persistent);
+    assert_eq!(signal.emit(3), 0);
+}
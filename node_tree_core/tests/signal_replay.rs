@@ -0,0 +1,37 @@
+use std::rc::Rc;
+use std::cell::RefCell;
+
+use node_tree::prelude::*;
+
+
+#[test]
+fn test_signal_replay() {
+    let signal: Signal<u8> = Signal::with_replay(2);
+    assert_eq!(signal.replay_capacity(), 2);
+    for value in 1..=3 {
+        signal.emit(value);
+    }
+
+    // Late listeners catch up on the last two emissions before receiving new ones.
+    let received: Rc<RefCell<Vec<u8>>> = Rc::new(RefCell::new(Vec::new()));
+    let listener: Rc<RefCell<Vec<u8>>> = received.clone();
+    unsafe {
+        signal.connect_replay(move |&value| listener.borrow_mut().push(value));
+    }
+    assert_eq!(*received.borrow(), [2, 3]);
+    
+    assert_eq!(signal.emit(4), 1);
+    assert_eq!(*received.borrow(), [2, 3, 4]);
+
+    // Clones keep the capacity but not the history, and plain signals have nothing to replay.
+    let cloned: Signal<u8> = signal.clone();
+    assert_eq!(cloned.replay_capacity(), 2);
+    
+    let plain: Signal<u8> = Signal::new();
+    plain.emit(5);
+    unsafe {
+        cloned.connect_replay(|_| panic!("nothing should be replayed"));
+        plain.connect_replay(|_| panic!("nothing should be replayed"));
+    }
+    assert_eq!(plain.replay_capacity(), 0);
+}
@@ -0,0 +1,35 @@
+use std::thread;
+use std::time::Duration;
+
+use node_tree::prelude::*;
+use node_tree::trees::TreeSimple;
+
+
+class! {
+    dec Hitch;
+
+    hk process(&mut self, _delta: f32) {
+        if self.tree().unwrap().frame_count() == 2 {
+            thread::sleep(Duration::from_millis(20));
+        }
+    }
+}
+
+
+#[test]
+fn test_slow_frames() {
+    let mut tree: Box<TreeSimple> = TreeSimple::new(scene! { Hitch }, LoggerVerbosity::All);
+    tree.set_slow_frame_threshold(Some(Duration::from_millis(10)));
+    assert_eq!(tree.slow_frame_threshold(), Some(Duration::from_millis(10)));
+    
+    for _ in 0..3 {
+        tree.process_with_delta(0.1);
+    }
+    assert_eq!(tree.frame_count(), 3);
+
+    // Only the second frame hitched.
+    let log: &str = tree.get_log();
+    assert!(log.contains("Frame 2 took"));
+    assert!(!log.contains("Frame 1 took"));
+    assert!(!log.contains("Frame 3 took"));
+}
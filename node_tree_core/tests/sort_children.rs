@@ -0,0 +1,39 @@
+use node_tree::prelude::*;
+use node_tree::trees::TreeSimple;
+
+
+class! {
+    dec Leaderboard;
+}
+
+class! {
+    dec Entry;
+
+    export let score: u32;
+
+    hk _init(score: u32) {}
+}
+
+
+#[test]
+fn test_sort_children_by() {
+    let scene: NodeScene = scene! {
+        Leaderboard {
+            Entry(20): "Alice",
+            Entry(50): "Bob",
+            Entry(20): "Carol",
+            Entry(10): "Dave"
+        }
+    };
+
+    let mut tree: Box<TreeSimple> = TreeSimple::new(scene, LoggerVerbosity::NoDebug);
+    let score = |node: &dyn Node| *node.as_any().downcast_ref::<Entry>().unwrap().score;
+    
+    // Sort by descending score, keeping ties in their original order.
+    tree.root_mut().sort_children_by(|node| std::cmp::Reverse(score(node)));
+    let names: Vec<String> = tree.root().children().iter().map(|child| child.name().to_string()).collect();
+    assert_eq!(names, ["Bob", "Alice", "Carol", "Dave"]);
+
+    // Paths are unaffected by the order.
+    assert!(tree.get_node_rid(NodePath::from_str("/Leaderboard/Dave"), None).is_some());
+}
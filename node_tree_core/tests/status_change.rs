@@ -0,0 +1,36 @@
+use std::rc::Rc;
+use std::cell::RefCell;
+
+use node_tree::prelude::*;
+use node_tree::trees::TreeSimple;
+
+
+class! {
+    dec Idle;
+}
+
+
+#[test]
+fn test_status_change() {
+    let changes: Rc<RefCell<Vec<(TreeStatus, TreeStatus)>>> = Rc::new(RefCell::new(Vec::new()));
+    let mut tree: Box<TreeSimple> = TreeSimple::new(scene! { Idle }, LoggerVerbosity::NoDebug);
+    
+    let observer: Rc<RefCell<Vec<(TreeStatus, TreeStatus)>>> = changes.clone();
+    tree.on_status_change(move |old, new| observer.borrow_mut().push((old, new)));
+
+    // Redundant changes are not reported.
+    tree.pause();
+    tree.pause();
+    tree.unpause();
+    tree.queue_termination();
+    while tree.process().is_active() {}
+
+    use TreeProcess::*;
+    assert_eq!(*changes.borrow(), [
+        (TreeStatus::Process(Running),           TreeStatus::Process(Paused)),
+        (TreeStatus::Process(Paused),            TreeStatus::Process(Running)),
+        (TreeStatus::Process(Running),           TreeStatus::QueuedTermination(Running)),
+        (TreeStatus::QueuedTermination(Running), TreeStatus::Terminating),
+        (TreeStatus::Terminating,                TreeStatus::Terminated)
+    ]);
+}
@@ -0,0 +1,50 @@
+use node_tree::prelude::*;
+use node_tree::trees::TreeSimple;
+
+
+class! {
+    dec Chatty;
+
+    hk ready(&mut self) {
+        debug!(self, "debugging");
+        warn!(self, "first warning");
+        warn!(self, "second warning");
+    }
+}
+
+class! {
+    dec Quiet;
+}
+
+
+#[test]
+fn test_status_summary() {
+    let tree:    Box<TreeSimple> = TreeSimple::new(scene! { Chatty }, LoggerVerbosity::All);
+    let summary: LogSummary      = tree.status_summary();
+    
+    assert_eq!(summary.warn, 2);
+    assert_eq!(summary.panic, 0);
+    assert!(summary.debug >= 1);
+    assert!(!summary.is_clean());
+
+    // Logs that are filtered out by the verbosity are not counted.
+    let tree:    Box<TreeSimple> = TreeSimple::new(scene! { Chatty }, LoggerVerbosity::OnlyIssues);
+    let summary: LogSummary      = tree.status_summary();
+    
+    assert_eq!(summary.debug, 0);
+    assert_eq!(summary.warn, 2);
+}
+
+#[cfg(feature = "testing")]
+#[test]
+fn test_assert_clean() {
+    let tree: Box<TreeSimple> = TreeSimple::new(scene! { Quiet }, LoggerVerbosity::All);
+    tree.assert_clean();
+
+    let tree:   Box<TreeSimple> = TreeSimple::new(scene! { Chatty }, LoggerVerbosity::All);
+    let result: Result<(), _>   = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| tree.assert_clean()));
+    
+    let message: String = *result.unwrap_err().downcast::<String>().unwrap();
+    assert!(message.contains("2 warning(s)"));
+    assert!(message.contains("first warning") && message.contains("second warning"));
+}
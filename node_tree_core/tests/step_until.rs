@@ -0,0 +1,42 @@
+use node_tree::prelude::*;
+use node_tree::trees::TreeSimple;
+
+
+class! {
+    dec Timer;
+
+    let elapsed: f32 = 0.0;
+
+    hk process(&mut self, delta: f32) {
+        *self.elapsed += delta;
+        if *self.elapsed < 1.0 {
+            return;
+        }
+
+        if self.is_root() {
+            self.tree_mut().unwrap().queue_termination();
+        } else {
+            self.queue_free();
+        }
+    }
+}
+
+
+#[test]
+fn test_step_until() {
+    let scene: NodeScene = scene! {
+        Timer: "Root" {
+            Timer: "Countdown"
+        }
+    };
+
+    let mut tree: Box<TreeSimple> = TreeSimple::new(scene, LoggerVerbosity::NoDebug);
+
+    // The countdown frees itself after a simulated second has passed.
+    assert!(!tree.step_until(5, 0.1, |tree| tree.root().childless()));
+    assert!(tree.step_until(10, 0.1, |tree| tree.root().childless()));
+
+    // The root queues the tree's termination at the same time, so this stops early.
+    assert!(!tree.step_until(100, 0.1, |_| false));
+    assert!(!tree.status().is_active());
+}
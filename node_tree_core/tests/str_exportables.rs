@@ -0,0 +1,40 @@
+use std::borrow::Cow;
+use std::rc::Rc;
+use std::sync::Arc;
+
+use node_tree::prelude::*;
+use node_tree::services::node_registry::Value;
+
+
+class! {
+    dec Config;
+
+    export let title: Box<str>          = "Untitled".into();
+    export let theme: Arc<str>          = "dark".into();
+    export let motto: Cow<'static, str> = Cow::Borrowed("Hello!");
+}
+
+
+#[test]
+fn test_str_exportables() {
+    let boxed: Box<str> = "boxed".into();
+    assert_eq!(boxed.to_value().as_str(), Some("boxed"));
+    assert_eq!(Box::<str>::from_value(boxed.to_value()), Some(boxed));
+
+    let shared: Rc<str> = "shared".into();
+    assert_eq!(Rc::<str>::from_value(shared.to_value()), Some(shared));
+
+    let atomic: Arc<str> = "atomic".into();
+    assert_eq!(Arc::<str>::from_value(atomic.to_value()), Some(atomic));
+
+    // Borrowed strings are loaded back in as owned strings.
+    let cow: Cow<'static, str> = Cow::Borrowed("cow");
+    assert!(matches!(Cow::<'static, str>::from_value(cow.to_value()), Some(Cow::Owned(s)) if s == "cow"));
+
+    assert_eq!(Box::<str>::from_value(Value::from(3)), None);
+
+    // They can also be used as exported fields.
+    let scene:  NodeScene = scene! { Config };
+    let loaded: NodeScene = NodeScene::load_from_str(&scene.save_to_str().unwrap()).unwrap();
+    assert_eq!(scene, loaded);
+}
@@ -0,0 +1,51 @@
+use node_tree::prelude::*;
+use node_tree::trees::TreeSimple;
+
+
+class! {
+    dec OrderRoot;
+
+    default let frame: u8;
+            let order: Vec<String> = Vec::new();
+
+    hk process(&mut self, _delta: f32) {
+        *self.frame += 1;
+        match *self.frame {
+            1 => (),
+            2 => {
+                assert_eq!(*self.order, vec!["A".to_string(), "B".to_string()]);
+                self.order.clear();
+                
+                assert!(self.swap_children("A", "B"));
+                assert!(!self.swap_children("A", "C"));
+            },
+            _ => {
+                assert_eq!(*self.order, vec!["B".to_string(), "A".to_string()]);
+                self.tree_mut().unwrap().queue_termination();
+            }
+        }
+    }
+}
+
+class! {
+    dec OrderChild;
+
+    hk process(&mut self, _delta: f32) {
+        let name: String = self.name().to_string();
+        self.parent::<OrderRoot>().unwrap().order.push(name);
+    }
+}
+
+
+#[test]
+fn test_swap_children() {
+    let scene: NodeScene = scene! {
+        OrderRoot {
+            OrderChild: "A",
+            OrderChild: "B"
+        }
+    };
+
+    let mut tree: Box<TreeSimple> = TreeSimple::new(scene, LoggerVerbosity::NoDebug);
+    while tree.process().is_active() {}
+}
@@ -0,0 +1,57 @@
+use std::time::{ Duration, Instant };
+
+use node_tree::prelude::*;
+use node_tree::trees::TreeSimple;
+
+
+class! {
+    dec Ticker;
+
+    let frames: u8 = 0;
+
+    hk process(&mut self, _delta: f32) {
+        *self.frames += 1;
+        if *self.frames == 5 {
+            self.tree_mut().unwrap().queue_termination();
+        }
+    }
+}
+
+
+#[test]
+fn test_target_fps() {
+    let mut tree: Box<TreeSimple> = TreeSimple::new(Ticker::new(), LoggerVerbosity::NoDebug);
+    tree.set_target_fps(Some(100.0));
+    assert_eq!(tree.target_fps(), Some(100.0));
+    
+    // Each capped frame must last for at least the frame's budget.
+    let started: Instant = Instant::now();
+    tree.step();
+    assert!(started.elapsed() >= Duration::from_secs_f32(1.0 / 100.0));
+    assert!(tree.last_fps() > 0.0);
+
+    // Uncapping the tree still processes frames.
+    tree.set_target_fps(None);
+    assert_eq!(tree.target_fps(), None);
+    tree.step();
+    
+    let ticker: &Ticker = tree.root().as_any().downcast_ref().unwrap();
+    assert_eq!(*ticker.frames, 2);
+
+    tree.run();
+    assert_eq!(tree.status(), TreeStatus::Terminated);
+}
+
+#[test]
+#[should_panic]
+fn test_zero_target_fps() {
+    let mut tree: Box<TreeSimple> = TreeSimple::new(Ticker::new(), LoggerVerbosity::NoDebug);
+    tree.set_target_fps(Some(0.0));
+}
+
+#[test]
+#[should_panic]
+fn test_negative_target_fps() {
+    let mut tree: Box<TreeSimple> = TreeSimple::new(Ticker::new(), LoggerVerbosity::NoDebug);
+    tree.set_target_fps(Some(-30.0));
+}
@@ -0,0 +1,29 @@
+#![cfg(feature = "testing")]
+
+use node_tree::prelude::*;
+use node_tree::testing::{ run_node, NodeRun };
+
+
+class! {
+    dec Accumulator;
+
+    let elapsed: f32 = 0.0;
+    
+    hk process(&mut self, delta: f32) {
+        *self.elapsed += delta;
+        if *self.elapsed >= 1.0 {
+            warn!(self, "A second has passed!");
+        }
+    }
+}
+
+
+#[test]
+fn test_testing_harness() {
+    let run: NodeRun = run_node(Accumulator::new(), 4, 0.25);
+    
+    assert_eq!(*run.node_as::<Accumulator>().unwrap().elapsed, 1.0);
+    assert!(run.status.is_active());
+    assert!(run.has_warnings());
+    assert!(!run.has_panicked());
+}
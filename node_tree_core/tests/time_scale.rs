@@ -0,0 +1,43 @@
+use node_tree::prelude::*;
+use node_tree::trees::TreeSimple;
+
+
+class! {
+    dec Clock;
+
+    let elapsed: f32 = 0.0;
+
+    hk process(&mut self, delta: f32) {
+        *self.elapsed += delta;
+    }
+}
+
+
+#[test]
+fn test_time_scale() {
+    let scene: NodeScene = scene! {
+        Clock: "World" {
+            Clock: "Ui"
+        }
+    };
+
+    let mut tree: Box<TreeSimple> = TreeSimple::new(scene, LoggerVerbosity::NoDebug);
+    let     ui:   RID             = tree.get_node_rid(NodePath::from_str("/World/Ui"), None).unwrap();
+    tree.get_node_mut(ui).unwrap().set_ignores_time_scale(true);
+    
+    // The world runs in slow motion while the UI keeps running in real time.
+    tree.set_time_scale(0.5);
+    assert_eq!(tree.time_scale(), 0.5);
+    for _ in 0..4 {
+        tree.process_with_delta(0.25);
+    }
+
+    let elapsed = |tree: &TreeSimple, rid: RID| *tree.get_node(rid).unwrap().as_any().downcast_ref::<Clock>().unwrap().elapsed;
+    assert_eq!(elapsed(&tree, 0),  0.5);
+    assert_eq!(elapsed(&tree, ui), 1.0);
+
+    // Time can also be frozen entirely.
+    tree.set_time_scale(0.0);
+    tree.process_with_delta(0.25);
+    assert_eq!(elapsed(&tree, 0), 0.5);
+}
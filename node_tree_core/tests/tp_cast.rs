@@ -0,0 +1,47 @@
+use node_tree::prelude::*;
+use node_tree::trees::TreeSimple;
+
+
+class! {
+    dec CastRoot;
+
+    hk ready(&mut self) {
+        let mut soldiers: u8 = 0;
+        for child in self.children() {
+            if child.is::<Soldier>() {
+                let soldier: Tp<Soldier> = child.cast().unwrap();
+                assert_eq!(*soldier.rank, 3);
+                soldiers += 1;
+            } else {
+                let err: String = child.cast::<Soldier>().err().to_option().unwrap();
+                assert!(err.contains("\"Medic\""));
+                assert!(err.contains("Soldier"));
+            }
+        }
+        assert_eq!(soldiers, 2);
+    }
+}
+
+class! {
+    dec Soldier;
+
+    let rank: u8 = 3;
+}
+
+class! {
+    dec Medic;
+}
+
+
+#[test]
+fn test_tp_cast() {
+    let scene: NodeScene = scene! {
+        CastRoot {
+            Soldier,
+            Medic,
+            Soldier
+        }
+    };
+    
+    TreeSimple::new(scene, LoggerVerbosity::NoDebug);
+}
@@ -0,0 +1,43 @@
+use std::collections::HashSet;
+
+use node_tree::prelude::*;
+use node_tree::trees::TreeSimple;
+
+
+class! {
+    dec Unit;
+
+    hk ready(&mut self) {
+        if !self.is_root() {
+            return;
+        }
+
+        // Pointers to the same node are equal even when they were resolved differently.
+        let mut seen: HashSet<TpDyn> = HashSet::new();
+        assert!(seen.insert(self.get_node_dyn(nodepath!("A")).unwrap()));
+        assert!(!seen.insert(self.children()[0]));
+        assert!(!seen.insert(self.resolve("/Root/A").unwrap()));
+        assert!(seen.insert(self.get_node_dyn(nodepath!("B")).unwrap()));
+        assert_eq!(seen.len(), 2);
+
+        let mut typed: HashSet<Tp<Unit>> = HashSet::new();
+        typed.insert(self.get_child(0).unwrap());
+        typed.insert(self.get_node(nodepath!("A")).unwrap());
+        typed.insert(self.get_child(1).unwrap());
+        assert_eq!(typed.len(), 2);
+        assert!(self.get_child::<Unit>(0).unwrap() != self.get_child::<Unit>(1).unwrap());
+    }
+}
+
+
+#[test]
+fn test_tp_identity() {
+    let scene: NodeScene = scene! {
+        Unit: "Root" {
+            Unit: "A",
+            Unit: "B"
+        }
+    };
+
+    TreeSimple::new(scene, LoggerVerbosity::NoDebug);
+}
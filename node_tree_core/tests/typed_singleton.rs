@@ -0,0 +1,52 @@
+use node_tree::prelude::*;
+use node_tree::trees::TreeSimple;
+
+
+class! {
+    dec Game;
+}
+
+class! {
+    dec Settings;
+
+    export let volume: f32 = 0.5;
+
+    hk ready(&mut self) {
+        self.register_as_singleton("Settings".to_string());
+    }
+}
+
+class! {
+    dec Player;
+
+    hk process(&mut self, _delta: f32) {
+        let settings: Tp<Settings> = self.expect_singleton("Settings");
+        assert_eq!(*settings.volume, 0.5);
+
+        assert!(self.singleton::<Player>("Settings").is_none());
+        assert!(self.singleton::<Settings>("Audio").is_none());
+    }
+}
+
+
+#[test]
+fn test_typed_singleton() {
+    let scene: NodeScene = scene! {
+        Game {
+            Settings,
+            Player
+        }
+    };
+
+    let mut tree: Box<TreeSimple> = TreeSimple::new(scene, LoggerVerbosity::NoDebug);
+    tree.process_with_delta(0.0);
+
+    assert_eq!(*tree.get_singleton::<Settings>("Settings").unwrap().volume, 0.5);
+    assert!(tree.get_singleton::<Game>("Settings").is_none());
+    assert!(tree.get_singleton::<Settings>("Audio").is_none());
+
+    let missing = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        tree.expect_singleton::<Settings>("Audio");
+    }));
+    assert!(missing.is_err());
+}
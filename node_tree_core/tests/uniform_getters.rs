@@ -0,0 +1,41 @@
+use node_tree::prelude::*;
+use node_tree::trees::TreeSimple;
+
+
+class! {
+    dec GetterRoot;
+
+    hk ready(&mut self) {
+        let child:     TpDyn = self.get_node_dyn(NodePath::from_str("Child")).unwrap();
+        let child_rid: RID   = child.rid();
+
+        // An already known RID or pointer resolves to the same node as its path.
+        assert_eq!(self.get_node_dyn(child_rid).unwrap(), child);
+        assert_eq!(self.get_node_dyn(child).unwrap(), child);
+        assert_eq!(self.get_node::<GetterChild>(child_rid).unwrap().rid(), child_rid);
+
+        let typed: Tp<GetterChild> = child.to().unwrap();
+        assert_eq!(self.get_node_dyn(typed).unwrap(), child);
+
+        // Invalid RIDs are rejected.
+        assert!(self.get_node_dyn(RID::MAX).is_err());
+        assert!(self.tree().unwrap().get_node_rid(RID::MAX, None).is_none());
+    }
+}
+
+class! {
+    dec GetterChild;
+}
+
+
+#[test]
+fn test_uniform_getters() {
+    let scene: NodeScene = scene! {
+        GetterRoot {
+            GetterChild: "Child"
+        }
+    };
+
+    let tree: Box<TreeSimple> = TreeSimple::new(scene, LoggerVerbosity::NoDebug);
+    assert_eq!(tree.get_node_rid(0, None), Some(0));
+}
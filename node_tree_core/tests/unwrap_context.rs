@@ -0,0 +1,39 @@
+use std::cell::RefCell;
+use std::panic::{ self, AssertUnwindSafe };
+use std::rc::Rc;
+
+use node_tree::prelude::*;
+use node_tree::trees::TreeSimple;
+
+
+class! {
+    dec Lookup;
+}
+
+
+#[test]
+fn test_unwrap_context() {
+    let scene: NodeScene = scene! {
+        Lookup {
+            Lookup: "Inner"
+        }
+    };
+
+    let     captured: Rc<RefCell<Option<PanicContext>>> = Rc::new(RefCell::new(None));
+    let     handle:   Rc<RefCell<Option<PanicContext>>> = captured.clone();
+    let mut tree:     Box<TreeSimple>                    = TreeSimple::new(scene, LoggerVerbosity::NoDebug);
+    let     inner:    RID                                = tree.root().children_rids()[0];
+
+    tree.set_panic_handler(move |context| *handle.borrow_mut() = Some(context.clone()));
+    
+    // A failed unwrap names the node that performed the lookup, along with the error.
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        tree.get_node(inner).unwrap().get_node_dyn(nodepath!("Missing")).unwrap();
+    }));
+    assert!(result.is_err());
+
+    let context: PanicContext = captured.borrow_mut().take().unwrap();
+    assert!(context.message.contains("called `TreeResult::unwrap()` on an `Err` value"));
+    assert!(context.message.contains("Missing"));
+    assert!(context.message.ends_with("(owned by \"/Lookup/Inner\")"));
+}
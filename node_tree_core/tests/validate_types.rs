@@ -0,0 +1,48 @@
+use std::fmt::Debug;
+use std::marker::PhantomData;
+
+use node_tree::prelude::*;
+
+
+#[derive(Debug, Clone, Abstract, Register)]
+pub struct Generic<T: Debug + Clone + 'static> {
+    base:   NodeBase,
+    marker: PhantomData<T>
+}
+
+impl <T: Debug + Clone + 'static> Generic<T> {
+    fn new() -> Self {
+        Generic { base: NodeBase::new("Generic".to_string()), marker: PhantomData }
+    }
+}
+
+impl <T: Debug + Clone + 'static> Node for Generic<T> {}
+
+
+class! {
+    dec Known;
+}
+
+
+#[test]
+fn test_validate_types() {
+    let valid: NodeScene = scene! {
+        Known {
+            Known
+        }
+    };
+    assert_eq!(valid.validate_types(), Ok(()));
+
+    // Generic nodes cannot be registered, so they are reported once each.
+    let mut invalid: NodeScene = scene! {
+        Known {
+            Known
+        }
+    };
+    invalid.append(NodeScene::new(Generic::<u8>::new()));
+    invalid.append(NodeScene::new(Generic::<u8>::new()));
+    invalid.append(NodeScene::new(Generic::<u16>::new()));
+
+    let unknown: Vec<String> = invalid.validate_types().unwrap_err();
+    assert_eq!(unknown, [Generic::<u8>::new().name_as_type(), Generic::<u16>::new().name_as_type()]);
+}
@@ -0,0 +1,40 @@
+use node_tree::prelude::*;
+use node_tree::trees::TreeSimple;
+
+
+class! {
+    dec Unit;
+
+    export let hp: u32;
+
+    hk _init(hp: u32) {}
+}
+
+
+#[test]
+fn test_with_node() {
+    let scene: NodeScene = scene! {
+        Unit(10): "Root" {
+            Unit(5): "Player",
+            Unit(3): "Enemy"
+        }
+    };
+
+    let mut tree:   Box<TreeSimple> = TreeSimple::new(scene, LoggerVerbosity::NoDebug);
+    let     player: RID             = tree.root().children_rids()[0];
+    let     enemy:  RID             = tree.root().children_rids()[1];
+
+    // Mutate the player while also using the tree.
+    let hp: Option<u32> = tree.with_node(player, |node, tree| {
+        let player: &mut Unit = node.as_any_mut().downcast_mut::<Unit>().unwrap();
+        *player.hp += 1;
+        
+        tree.queue_free(enemy);
+        *player.hp
+    });
+    assert_eq!(hp, Some(6));
+    assert_eq!(tree.with_node(RID::MAX, |_, _| ()), None);
+
+    tree.process();
+    assert_eq!(tree.root().num_children(), 1);
+}
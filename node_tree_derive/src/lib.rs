@@ -50,12 +50,13 @@ pub fn r#abstract(input: TokenStream) -> TokenStream {
     // Parse the input tokens into a syntax tree,
     // and get the name of the struct.
     let input: DeriveInput = parse_macro_input!(input as DeriveInput);
-    let name:  syn::Ident  = input.ident;
+    let name:  &syn::Ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
 
     // Expanded the code to host the boilerplate implementations of the NodeAbstract, Deref, and
     // DerefMut traits.
     let expanded = quote! {
-        impl node_tree::traits::node::NodeAbstract for #name {
+        impl #impl_generics node_tree::traits::node::NodeAbstract for #name #ty_generics #where_clause {
             fn base(&self) -> &node_tree::structs::node_base::NodeBase {
                 &self.base
             }
@@ -101,14 +102,14 @@ pub fn r#abstract(input: TokenStream) -> TokenStream {
             }
         }
 
-        impl std::ops::Deref for #name {
+        impl #impl_generics std::ops::Deref for #name #ty_generics #where_clause {
             type Target = node_tree::structs::node_base::NodeBase;
             fn deref(&self) -> &Self::Target {
                 &self.base
             }
         }
 
-        impl std::ops::DerefMut for #name {
+        impl #impl_generics std::ops::DerefMut for #name #ty_generics #where_clause {
             fn deref_mut(&mut self) -> &mut Self::Target {
                 &mut self.base
             }
@@ -126,10 +127,14 @@ pub fn r#abstract(input: TokenStream) -> TokenStream {
 
 
 
-#[proc_macro_derive(Register)]
+#[proc_macro_derive(Register, attributes(schema))]
 pub fn derive_registered(input: TokenStream) -> TokenStream {
     let ast:    DeriveInput             = parse_macro_input!(input as DeriveInput);
     let name:   &syn::Ident             = &ast.ident;
+    let schema: Schema                  = match Schema::from_attributes(&ast.attrs) {
+        Ok(schema) => schema,
+        Err(err)   => return err.to_compile_error().into()
+    };
     let fields: &punc::Punctuated<_, _> = match &ast.data {
         syn::Data::Struct(data_struct) => match &data_struct.fields {
             syn::Fields::Named(syn::FieldsNamed { named, .. }) => named,
@@ -143,39 +148,85 @@ pub fn derive_registered(input: TokenStream) -> TokenStream {
         .filter(|field| field.ident.as_ref().unwrap() != "base")
         .map(|field| field.ident.as_ref().unwrap())
         .collect();
+    let field_strs: Vec<syn::LitStr> = field_names.iter()
+        .map(|name| syn::LitStr::new(&name.to_string(), name.span()))
+        .collect();
+    let field_types: Vec<&syn::Type> = fields
+        .iter()
+        .filter(|field| field.ident.as_ref().unwrap() != "base")
+        .map(|field| &field.ty)
+        .collect();
+
+    // The patched values are bound by index rather than by field name, as joining the prefix with a
+    // field name that starts with an underscore would not be snake case.
+    let patch_names: Vec<syn::Ident> = field_names.iter()
+        .enumerate()
+        .map(|(idx, name)| syn::Ident::new(&format!("__patch_{}", idx), name.span()))
+        .collect();
 
     // Initialize deserialization lines from the fields.
-    let mut type_definitions: Vec<TokenStream2>      = Vec::new();
-    let     type_define_ptr:  *mut Vec<TokenStream2> = &mut type_definitions as *mut _;
-    let     deserialization:  Vec<TokenStream2>      = fields.iter()
+    // The field types are referred to via qualified paths, as type aliases within the generated
+    // function would not be able to refer to the struct's generic parameters.
+    let deserialization: Vec<TokenStream2> = fields.iter()
         .filter(|field| field.ident.as_ref().unwrap() != "base")
         .map(|field| {
             let field_name: &syn::Ident = field.ident.as_ref().expect("Field must be named");
             let field_type: &syn::Type  = &field.ty;
             
-            // Create a unique ident for the type; this is to avoid having to parse colons between
-            // generic arguments and the type.
-            let unique_ident: syn::Ident = syn::Ident::new(&format!("Unique{}", type_definitions.len()), proc_macro::Span::call_site().into());
-            unsafe { &mut *type_define_ptr }.push(quote! {
-                type #unique_ident = #field_type;
-            });
-            
             quote! {
                 #field_name: {
-                    if #unique_ident::is_ghost_export_type() {
-                        #unique_ident::void()
+                    if <#field_type as node_tree::traits::exportable::Exportable>::is_ghost_export_type() {
+                        <#field_type as node_tree::traits::exportable::Voidable>::void()
                     } else {
-                        #unique_ident::from_value(
+                        <#field_type as node_tree::traits::exportable::Exportable>::try_from_value(
                             owned_state.remove(stringify!(#field_name)).ok_or(format!("corrupt save data; `{}` missing", stringify!(#field_name)))?
-                        ).ok_or(format!("corrupt save data; `{}` invalid type", stringify!(#field_name)))?
+                        ).map_err(|err| format!("corrupt save data; `{}` is invalid: {}", stringify!(#field_name), err))?
                     }
                 }
             }
-        }).collect(); // We need to collect here so that the unique identities are created here and now!
+        }).collect();
+
+    // Generate the schema versioning functions if they were specified.
+    let schema_version: TokenStream2 = match &schema.version {
+        Some(version) => quote! {
+            fn schema_version() -> u32 where Self: Sized { #version }
+        },
+        None => TokenStream2::new()
+    };
+    let schema_migrate: TokenStream2 = match &schema.migrate {
+        Some(migrate) => quote! {
+            fn migrate(version: u32, owned_state: &mut node_tree::services::node_registry::SFieldMap) where Self: Sized {
+                #migrate(version, owned_state)
+            }
+        },
+        None => TokenStream2::new()
+    };
+
+    // Generic nodes cannot be registered for deserialization automatically, as there is no way to
+    // know which concrete types will be used.
+    let static_name:  syn::Ident   = syn::Ident::new(&format!("__static_init_{}", name.to_string().to_lowercase()), name.span());
+    let static_init:  TokenStream2 = if ast.generics.params.is_empty() {
+        quote! {
+            
+            // Runs before main.
+            #[node_tree::ctor::ctor]
+            unsafe fn #static_name() {
+                node_tree::services::node_registry::register_deserializer(std::any::type_name::<#name>().into(), Box::new(|s_field_map| {
+                    let node: #name = #name::load_from_owned(s_field_map)?;
+                    Ok(Box::new(node) as Box<dyn node_tree::traits::node::Node>)
+                }));
+            };
+        }
+    } else {
+        TokenStream2::new()
+    };
+
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+    let expanded: TokenStream2 = quote! {
+        impl #impl_generics Registered for #name #ty_generics #where_clause {
+            #schema_version
+            #schema_migrate
 
-    let static_name: syn::Ident   = syn::Ident::new(&format!("__static_init_{}", name.to_string().to_lowercase()), name.span());
-    let expanded:    TokenStream2 = quote! {
-        impl Registered for #name {
             fn save_from_owned(&self) -> node_tree::services::node_registry::FieldMap {
                 let mut map = node_tree::services::node_registry::FieldMap::new();
                 #(
@@ -184,26 +235,300 @@ pub fn derive_registered(input: TokenStream) -> TokenStream {
                         Box::new(self.#field_names.clone()),
                     );
                 )*
+                map.insert(
+                    Box::<str>::from(node_tree::services::node_registry::SCHEMA_VERSION_KEY),
+                    Box::new(<Self as Registered>::schema_version())
+                );
                 map
             }
 
+            fn exported_fields(&self) -> Vec<(String, node_tree::services::node_registry::Value)> {
+                let mut fields: Vec<(String, node_tree::services::node_registry::Value)> = Vec::new();
+                #(
+                    if !unsafe { node_tree::traits::exportable::Exportable::is_ghost_export(&self.#field_names) } {
+                        fields.push((
+                            stringify!(#field_names).to_string(),
+                            node_tree::traits::exportable::Exportable::to_value(&self.#field_names)
+                        ));
+                    }
+                )*
+                fields
+            }
+
+            fn set_exported_field(&mut self, name: &str, value: node_tree::services::node_registry::Value) -> Result<(), String> {
+                match name {
+                    #(
+                        #field_strs => {
+                            if unsafe { node_tree::traits::exportable::Exportable::is_ghost_export(&self.#field_names) } {
+                                return Err(format!("`{}` is not an exported field of `{}`", name, stringify!(#name)));
+                            }
+                            
+                            self.#field_names = <#field_types as node_tree::traits::exportable::Exportable>::try_from_value(value)
+                                .map_err(|err| format!("invalid value for the field `{}` of `{}`: {}", name, stringify!(#name), err))?;
+                            Ok(())
+                        },
+                    )*
+                    _ => Err(format!("`{}` has no field named `{}`", stringify!(#name), name))
+                }
+            }
+
+            fn apply_field_patch(&mut self, mut patch: node_tree::services::node_registry::SFieldMap) -> Result<(), String> {
+                patch.remove(node_tree::services::node_registry::SCHEMA_VERSION_KEY);
+                
+                // Every value is deserialized before any are assigned, so that a bad patch leaves
+                // the node untouched.
+                #(
+                    let #patch_names: Option<#field_types> = match patch.remove(#field_strs) {
+                        Some(value) => {
+                            if <#field_types as node_tree::traits::exportable::Exportable>::is_ghost_export_type() {
+                                return Err(format!("`{}` is not an exported field of `{}`", #field_strs, stringify!(#name)));
+                            }
+                            Some(<#field_types as node_tree::traits::exportable::Exportable>::try_from_value(value)
+                                .map_err(|err| format!("invalid value for the field `{}` of `{}`: {}", #field_strs, stringify!(#name), err))?)
+                        },
+                        None => None
+                    };
+                )*
+                if let Some(field) = patch.keys().next() {
+                    return Err(format!("`{}` has no field named `{}`", stringify!(#name), field));
+                }
+                
+                #(
+                    if let Some(value) = #patch_names {
+                        self.#field_names = value;
+                    }
+                )*
+                Ok(())
+            }
+
             fn load_from_owned(mut owned_state: node_tree::services::node_registry::SFieldMap) -> Result<Self, String> where Self: Sized {
-                #(#type_definitions)*
+                
+                // Bring the data up to date with the current schema before deserializing it.
+                let version: u32 = owned_state.remove(node_tree::services::node_registry::SCHEMA_VERSION_KEY)
+                    .map(|version| <u32 as node_tree::traits::exportable::Exportable>::from_value(version).ok_or("corrupt save data; invalid schema version".to_string()))
+                    .transpose()?
+                    .unwrap_or(0);
+                
+                if version > <Self as Registered>::schema_version() {
+                    return Err(format!("save data has schema version {} which is newer than the supported version {}", version, <Self as Registered>::schema_version()));
+                }
+                if version < <Self as Registered>::schema_version() {
+                    <Self as Registered>::migrate(version, &mut owned_state);
+                }
+                
                 Ok(Self {
                     base: node_tree::prelude::NodeBase::new(stringify!(#name).to_string()),
                     #(#deserialization,)*
                 })
             }
         }
-        
-        // Runs before main.
-        #[node_tree::ctor::ctor]
-        unsafe fn #static_name() {
-            node_tree::services::node_registry::register_deserializer(std::any::type_name::<#name>().into(), Box::new(|s_field_map| {
-                let node: #name = #name::load_from_owned(s_field_map)?;
-                Ok(Box::new(node) as Box<dyn node_tree::traits::node::Node>)
-            }));
-        };
+        #static_init
+    };
+
+    expanded.into()
+}
+
+
+/// The options of the `schema` attribute used by the `Register` derive macro.
+#[derive(Default)]
+struct Schema {
+    version: Option<syn::LitInt>,
+    migrate: Option<syn::ExprPath>
+}
+
+impl Schema {
+    
+    /// Parses the schema options from the `#[schema(version = N, migrate = "path")]` attribute, if
+    /// present.
+    fn from_attributes(attribs: &[syn::Attribute]) -> syn::Result<Self> {
+        let mut schema: Schema = Schema::default();
+        for attrib in attribs.iter().filter(|attrib| attrib.path.is_ident("schema")) {
+            let syn::Meta::List(list) = attrib.parse_meta()? else {
+                return Err(syn::Error::new_spanned(attrib, "Expected `#[schema(version = N, migrate = \"path\")]`"));
+            };
+
+            for nested in list.nested {
+                match nested {
+                    syn::NestedMeta::Meta(syn::Meta::NameValue(pair)) if pair.path.is_ident("version") => match pair.lit {
+                        syn::Lit::Int(version) => schema.version = Some(version),
+                        lit                    => return Err(syn::Error::new_spanned(lit, "The schema version must be an integer"))
+                    },
+                    syn::NestedMeta::Meta(syn::Meta::NameValue(pair)) if pair.path.is_ident("migrate") => match pair.lit {
+                        syn::Lit::Str(path) => schema.migrate = Some(path.parse()?),
+                        lit                 => return Err(syn::Error::new_spanned(lit, "The migration function must be given as a string path"))
+                    },
+                    other => return Err(syn::Error::new_spanned(other, "Unknown schema option; expected `version` or `migrate`"))
+                }
+            }
+        }
+        Ok(schema)
+    }
+}
+
+
+/*
+ * Exportable
+ */
+
+
+/// Implements `Exportable` for an enum, including enums whose variants carry data.
+/// Variants are saved under an externally tagged encoding:
+/// - Unit variants are saved as a bare string, such as `"Idle"`.
+/// - Tuple variants with a single field are saved as `{ Variant = <field> }`.
+/// - Tuple variants with several fields are saved as `{ Variant = [<field>, ...] }`.
+/// - Struct variants are saved as `{ Variant = { field = <field>, ... } }`.
+///
+/// # Note
+/// For fieldless enums, `impl_exportable_enum!` may be used instead to save variants by index.
+/// To be used as an exported field of a node, the enum must also implement `Default`.
+#[proc_macro_derive(Exportable)]
+pub fn derive_exportable(input: TokenStream) -> TokenStream {
+    let ast:      DeriveInput             = parse_macro_input!(input as DeriveInput);
+    let name:     &syn::Ident             = &ast.ident;
+    let variants: &punc::Punctuated<_, _> = match &ast.data {
+        syn::Data::Enum(data_enum) => &data_enum.variants,
+        _ => panic!("Exportable can only be derived for enums"),
+    };
+
+    let value: TokenStream2 = quote! { node_tree::services::node_registry::Value };
+    let error: TokenStream2 = quote! { node_tree::traits::exportable::ExportError };
+    let kind:  TokenStream2 = quote! { node_tree::traits::exportable::ValueKind };
+    
+    // Generate the serialization and deserialization arms for each variant.
+    let mut to_arms:   Vec<TokenStream2> = Vec::new();
+    let mut unit_arms: Vec<TokenStream2> = Vec::new();
+    let mut data_arms: Vec<TokenStream2> = Vec::new();
+    for variant in variants.iter() {
+        let variant_name: &syn::Ident = &variant.ident;
+        let variant_str:  String      = variant_name.to_string();
+
+        match &variant.fields {
+            syn::Fields::Unit => {
+                to_arms.push(quote! {
+                    Self::#variant_name => #value::from(#variant_str)
+                });
+                unit_arms.push(quote! {
+                    #variant_str => Ok(Self::#variant_name)
+                });
+            },
+            syn::Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
+                let field_type: &syn::Type = &fields.unnamed[0].ty;
+                
+                to_arms.push(quote! {
+                    Self::#variant_name(field) => std::iter::once((#variant_str, node_tree::traits::exportable::Exportable::to_value(field))).collect::<#value>()
+                });
+                data_arms.push(quote! {
+                    #variant_str => Ok(Self::#variant_name(
+                        <#field_type as node_tree::traits::exportable::Exportable>::try_from_value(payload).map_err(|err| err.at(format!("`{}`", #variant_str)))?
+                    ))
+                });
+            },
+            syn::Fields::Unnamed(fields) => {
+                let field_types: Vec<&syn::Type>  = fields.unnamed.iter().map(|field| &field.ty).collect();
+                let bindings:    Vec<syn::Ident>  = (0..field_types.len()).map(|i| syn::Ident::new(&format!("field_{i}"), variant_name.span())).collect();
+                let indices:     Vec<usize>       = (0..field_types.len()).collect();
+                let len:         usize            = field_types.len();
+
+                to_arms.push(quote! {
+                    Self::#variant_name(#(#bindings),*) => std::iter::once((#variant_str, vec![#(node_tree::traits::exportable::Exportable::to_value(#bindings)),*].into_iter().collect::<#value>())).collect::<#value>()
+                });
+                data_arms.push(quote! {
+                    #variant_str => match payload {
+                        #value::Array(arr) => {
+                            let mut elements: Vec<#value> = arr.into_iter().collect();
+                            if elements.len() != #len {
+                                return Err(#error::LengthMismatch { expected: #len, found: elements.len() }.at(format!("`{}`", #variant_str)));
+                            }
+                            
+                            let mut elements = elements.drain(..);
+                            Ok(Self::#variant_name(#(
+                                <#field_types as node_tree::traits::exportable::Exportable>::try_from_value(elements.next().unwrap())
+                                    .map_err(|err| err.at(format!("[{}]", #indices)).at(format!("`{}`", #variant_str)))?
+                            ),*))
+                        },
+                        payload => Err(#error::KindMismatch { expected: #kind::Array, found: #kind::of(&payload) }.at(format!("`{}`", #variant_str)))
+                    }
+                });
+            },
+            syn::Fields::Named(fields) => {
+                let field_names: Vec<&syn::Ident> = fields.named.iter().map(|field| field.ident.as_ref().unwrap()).collect();
+                let field_strs:  Vec<String>      = field_names.iter().map(|name| name.to_string()).collect();
+                let field_types: Vec<&syn::Type>  = fields.named.iter().map(|field| &field.ty).collect();
+
+                to_arms.push(quote! {
+                    Self::#variant_name { #(#field_names),* } => std::iter::once((#variant_str, vec![#((#field_strs, node_tree::traits::exportable::Exportable::to_value(#field_names))),*].into_iter().collect::<#value>())).collect::<#value>()
+                });
+                data_arms.push(quote! {
+                    #variant_str => match payload {
+                        #value::InlineTable(mut table) => Ok(Self::#variant_name { #(
+                            #field_names: <#field_types as node_tree::traits::exportable::Exportable>::try_from_value(
+                                table.remove(#field_strs).ok_or_else(|| #error::Invalid { expected: format!("the field `{}`", #field_strs), found: #kind::Table }.at(format!("`{}`", #variant_str)))?
+                            ).map_err(|err| err.at(format!("`{}`", #field_strs)).at(format!("`{}`", #variant_str)))?
+                        ),* }),
+                        payload => Err(#error::KindMismatch { expected: #kind::Table, found: #kind::of(&payload) }.at(format!("`{}`", #variant_str)))
+                    }
+                });
+            }
+        }
+    }
+
+    // Only accept the kinds of value that the enum's variants could have been saved as.
+    let unit_branch: TokenStream2 = if unit_arms.is_empty() {
+        TokenStream2::new()
+    } else {
+        quote! {
+            #value::String(variant) => match variant.value().as_str() {
+                #(#unit_arms,)*
+                _ => Err(unknown(#kind::String))
+            },
+        }
+    };
+    let data_branch: TokenStream2 = if data_arms.is_empty() {
+        TokenStream2::new()
+    } else {
+        quote! {
+            #value::InlineTable(table) => {
+                if table.len() != 1 {
+                    return Err(unknown(#kind::Table));
+                }
+
+                let (variant, payload) = table.into_iter().next().unwrap();
+                match variant.as_str() {
+                    #(#data_arms,)*
+                    _ => Err(unknown(#kind::Table))
+                }
+            },
+        }
+    };
+
+    // Every generic type parameter must be exportable for the enum to be.
+    let mut generics: syn::Generics = ast.generics.clone();
+    for param in generics.type_params_mut() {
+        param.bounds.push(syn::parse_quote!(node_tree::traits::exportable::Exportable));
+    }
+    
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let expanded: TokenStream2 = quote! {
+        impl #impl_generics node_tree::traits::exportable::Exportable for #name #ty_generics #where_clause {
+            fn to_value(&self) -> #value {
+                match self {
+                    #(#to_arms,)*
+                }
+            }
+
+            fn from_value(value: #value) -> Option<Self> where Self: Sized {
+                <Self as node_tree::traits::exportable::Exportable>::try_from_value(value).ok()
+            }
+
+            fn try_from_value(value: #value) -> Result<Self, #error> where Self: Sized {
+                let unknown = |found: #kind| #error::Invalid { expected: std::any::type_name::<Self>().to_string(), found };
+                match value {
+                    #unit_branch
+                    #data_branch
+                    value => Err(unknown(#kind::of(&value)))
+                }
+            }
+        }
     };
 
     expanded.into()
@@ -496,15 +821,37 @@ struct Field {
     public:  bool,
     ty:      syn::Type,
     kind:    FieldKind,
+    notify:  bool,
     init:    Option<syn::Expr>
 }
 
+/// Every hook that can be defined via `hk` within `class!`.
+/// This must be kept in sync with the functions of the `Node` trait.
+const HOOK_NAMES: &[&str] = &[
+    "_init",
+    "loaded",
+    "ready",
+    "process",
+    "input",
+    "terminal",
+    "dependency_ready",
+    "child_added",
+    "child_removed",
+    "try_ready",
+    "try_process",
+    "try_terminal",
+    "reinstance",
+    "process_mode",
+    "process_order",
+    "log_identity"
+];
+
 struct Hook {
     name:    syn::Ident,
     attribs: Vec<syn::Attribute>,
     sig:     Option<syn::Receiver>,
     args:    Vec<syn::PatType>,
-    out:     Option<syn::Ident>,
+    out:     Option<syn::Type>,
     body:    syn::Block
 }
 
@@ -558,12 +905,24 @@ impl Parse for Class {
 
             // Parse the item kind or a unique statement keyword if there is one.
             let mut item_kind:      FieldKind          = FieldKind::Regular;
+            let mut notify:         bool               = false;
             let mut unique_starter: Option<syn::Ident> = None;
             if input.peek(syn::Ident) {
                 let token:      syn::Ident = input.parse::<syn::Ident>()?;
                 let token_name: &str       = &token.to_string();
                 match token_name {
                     "export" => {
+                        if input.peek(tok::Paren) {
+                            let content;
+                            parenthesized!(content in input);
+
+                            let option: syn::Ident = content.parse()?;
+                            if &option.to_string() != "notify" {
+                                return Err(syn::Error::new_spanned(option, "'export' only supports 'notify' as an option"));
+                            }
+                            notify = true;
+                        }
+
                         if input.peek(syn::Ident) {
                             let next_token: syn::Ident = input.parse::<syn::Ident>()?;
                             if &next_token.to_string() == "default" {
@@ -622,6 +981,13 @@ impl Parse for Class {
                             return Err(syn::Error::new_spanned(token, "Hooks cannot have field attributes"));
                         }
                         let hook_name: syn::Ident = input.parse()?;
+                        if !HOOK_NAMES.contains(&hook_name.to_string().as_str()) {
+                            return Err(syn::Error::new_spanned(&hook_name, format!(
+                                "Unknown hook `{}`; valid hooks are: {}",
+                                hook_name,
+                                HOOK_NAMES.iter().map(|name| format!("`{name}`")).collect::<Vec<_>>().join(", ")
+                            )));
+                        }
 
                         // Parse the arguments.
                         let content;
@@ -639,7 +1005,7 @@ impl Parse for Class {
                         .collect::<Vec<_>>();
 
                         // Parse the output (if there is one!).
-                        let out: Option<syn::Ident> = if input.peek(Token![->]) {
+                        let out: Option<syn::Type> = if input.peek(Token![->]) {
                             input.parse::<Token![->]>()?;
                             Some(input.parse()?)
                         } else {
@@ -692,6 +1058,7 @@ impl Parse for Class {
                     kind:    item_kind,
                     public:  is_public.is_some(),
                     ty,
+                    notify,
                     init:    default_value
                 });
             }
@@ -775,8 +1142,14 @@ impl Parse for Class {
 ///     export         let some_parameter: String;
 ///     export default let some_parameter_default: bool;
 ///
+///     // Exportable fields marked with `notify` are kept private, and instead generate a getter
+///     // `hp()`, a setter `set_hp()`, and a `hp_changed` signal which is emitted by the setter.
+///     export(notify) let hp: u32;
+///
 ///     // Hooks are any system functions that can be overridden.
-///     // This include the constructor `_init()`, `loaded()`, `ready()`, `process()`, `terminal()`, and `process_mode()`.
+///     // This include the constructor `_init()`, `loaded()`, `ready()`, `process()`, `input()`, `terminal()`, their fallible
+///     // `try_ready()`, `try_process()` and `try_terminal()` variants, `dependency_ready()`, `child_added()`,
+///     // `child_removed()`, `reinstance()`, `process_mode()`, `process_order()`, and `log_identity()`.
 ///
 ///     /// The constructor may only need to be implemented if there exists fields that do not have
 ///     /// a default value.
@@ -848,10 +1221,12 @@ pub fn class(input: TokenStream) -> TokenStream {
             kind,
             public,
             ty,
+            notify,
             ..
         } = field;
 
-        let visibility: TokenStream2 = if *public { quote! { pub } } else { TokenStream2::new() };
+        // Fields with change notification are only accessible through their getters and setters.
+        let visibility: TokenStream2 = if *public && !*notify { quote! { pub } } else { TokenStream2::new() };
         match kind {
             FieldKind::Regular        => quote! { #(#attribs)* #visibility #name: node_tree::structs::node_field::Field<#ty>           },
             FieldKind::Export         => quote! { #(#attribs)* #visibility #name: node_tree::structs::node_field::ExportableField<#ty> },
@@ -861,6 +1236,30 @@ pub fn class(input: TokenStream) -> TokenStream {
         }
     });
 
+    // Generate the change signals, getters and setters of any `export(notify)` fields.
+    let notify_fields: Vec<&Field> = fields.iter().filter(|field| field.notify).collect();
+    let notify_signals = notify_fields.iter().map(|Field { name, public, ty, .. }| {
+        let visibility:  TokenStream2 = if *public { quote! { pub } } else { TokenStream2::new() };
+        let signal_name: syn::Ident   = syn::Ident::new(&format!("{}_changed", name), name.span());
+        quote! { #visibility #signal_name: node_tree::prelude::Signal<#ty> }
+    });
+
+    let notify_accessors = notify_fields.iter().map(|Field { name, public, ty, .. }| {
+        let visibility:  TokenStream2 = if *public { quote! { pub } } else { TokenStream2::new() };
+        let signal_name: syn::Ident   = syn::Ident::new(&format!("{}_changed", name), name.span());
+        let setter_name: syn::Ident   = syn::Ident::new(&format!("set_{}", name), name.span());
+        quote! {
+            #visibility fn #name(&self) -> &#ty {
+                &self.#name
+            }
+
+            #visibility fn #setter_name(&mut self, value: #ty) {
+                *self.#name = value;
+                self.#signal_name.emit(self.#name.clone());
+            }
+        }
+    });
+
     // Generate the constructor.
     // Take note if an _init definition is not required.
     const INIT: &str = "_init";
@@ -868,12 +1267,13 @@ pub fn class(input: TokenStream) -> TokenStream {
     let needs_init: bool          = fields.iter().any(|field| field.init.is_none() && !field.kind.supports_default_init());
     let init_hook:  Option<&Hook> = hooks.iter().find(|hook| hook.name == INIT);
 
-    let constructor_signals = signals.iter().map(|signal| {
-        let signal_name: &syn::Ident = &signal.name;
-        quote! {
-            #signal_name: node_tree::prelude::Signal::new()
-        }
-    });
+    let constructor_signals = signals.iter().map(|signal| signal.name.clone())
+        .chain(notify_fields.iter().map(|field| syn::Ident::new(&format!("{}_changed", field.name), field.name.span())))
+        .map(|signal_name| {
+            quote! {
+                #signal_name: node_tree::prelude::Signal::new()
+            }
+        });
 
     let constructor_fields = fields.iter().map(|field| {
         let Field {
@@ -944,6 +1344,18 @@ pub fn class(input: TokenStream) -> TokenStream {
         }
     });
 
+    // Generate a `reinstance()` implementation which goes through the node registry, unless one was
+    // provided by the user.
+    let reinstance_impl: TokenStream2 = if hooks.iter().any(|hook| hook.name == "reinstance") {
+        TokenStream2::new()
+    } else {
+        quote! {
+            fn reinstance(&self) -> Box<dyn node_tree::prelude::Node> {
+                node_tree::services::node_registry::reinstance(self).unwrap_or_else(|_| self.clone_as_instance())
+            }
+        }
+    };
+
     // Generate the functions.
     let func_impls = funcs.iter().map(|Func { attribs, public, declare }| {
         let visibility: TokenStream2 = if *public { quote! { pub } } else { TokenStream2::new() };
@@ -991,22 +1403,25 @@ pub fn class(input: TokenStream) -> TokenStream {
     };
 
     let expanded: TokenStream2 = quote! {
-        #(#attribs)*
         #[derive(Debug, Clone, node_tree::prelude::Abstract, node_tree::prelude::Register)]
+        #(#attribs)*
         #visibility struct #name {
             base: node_tree::prelude::NodeBase,
             #(#signal_fields,)*
+            #(#notify_signals,)*
             #(#custom_fields,)*
         }
 
         impl #name {
             #(#const_fields)*
             #constructor
+            #(#notify_accessors)*
             #(#func_impls)*
         }
 
         impl node_tree::prelude::Node for #name {
             #(#hook_impls)*
+            #reinstance_impl
         }
     };
     TokenStream::from(expanded)
@@ -1023,7 +1438,8 @@ struct Connection {
     signal_name:  syn::Ident,
     one_shot:     bool,
     tree_pointer: syn::Ident,
-    callback:     syn::Ident
+    callback:     syn::Ident,
+    owned_by:     Option<syn::Expr>
 }
 
 impl Parse for Connection {
@@ -1044,11 +1460,25 @@ impl Parse for Connection {
         let _punct:        tok::Dot   = input.parse()?;
         let  callback:     syn::Ident = input.parse()?;
 
+        // Parse the optional `(owned_by node)` clause.
+        let mut owned_by: Option<syn::Expr> = None;
+        if input.peek(syn::token::Paren) {
+            let content;
+            syn::parenthesized!(content in input);
+
+            let keyword: syn::Ident = content.parse()?;
+            if keyword != "owned_by" {
+                return Err(syn::Error::new_spanned(keyword, "Expected `owned_by`"));
+            }
+            owned_by = Some(content.parse()?);
+        }
+
         Ok(Connection {
             signal_name,
             one_shot,
             tree_pointer,
-            callback
+            callback,
+            owned_by
         })
     }
 }
@@ -1068,24 +1498,138 @@ impl Parse for Connection {
 /// connect! { signal_name -> tp.constant_listener };
 /// connect! { signal_name ~> tp.one_shot_listener };
 /// ```
+///
+/// A connection may also be owned by a node, in which case it is automatically disconnected once
+/// that node leaves the tree (by being freed or removed as a child). Owning connections by the
+/// listening node means that they never outlive it:
+/// ```rust, ignore
+/// connect! { signal_name -> tp.constant_listener (owned_by tp) };
+/// ```
 #[proc_macro]
 pub fn connect(input: TokenStream) -> TokenStream {
     let Connection {
         signal_name,
         one_shot,
         tree_pointer,
-        callback
+        callback,
+        owned_by
     } = parse_macro_input!(input as Connection);
 
     // TODO: Support argument passing!
     
     let connect_type: TokenStream2 = if one_shot { quote! { connect_once } } else { quote! { connect } };
+    
+    // The owner is resolved to its base up front, as the owner may be the tree pointer that is
+    // moved into the listener.
+    let (owner, ownership): (TokenStream2, TokenStream2) = match owned_by {
+        Some(owner) => (
+            quote! {
+                let owner_: *const node_tree::prelude::NodeBase = { let owner_: &node_tree::prelude::NodeBase = &(#owner); owner_ };
+            },
+            quote! {
+                (*owner_).on_tree_exit(self.#signal_name.disconnector(_rid));
+            }
+        ),
+        None => (TokenStream2::new(), TokenStream2::new())
+    };
     TokenStream::from(quote! {
         unsafe { // Enforce `move,` as without it a segfault occurs!
-            let tp_: node_tree::prelude::Tp<_> = #tree_pointer;
-            self.#signal_name.#connect_type(move |args| {
+            #owner
+            let tp_:  node_tree::prelude::Tp<_> = #tree_pointer;
+            let _rid: node_tree::prelude::RID   = self.#signal_name.#connect_type(move |args| {
                 tp_.#callback(&args)
             });
+            #ownership
+        }
+    })
+}
+
+
+/*
+ * Main
+ *      Attribute
+ */
+
+
+/// The options of the `main` attribute macro.
+struct MainArgs {
+    root:      syn::Type,
+    verbosity: syn::Ident
+}
+
+impl Parse for MainArgs {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut root:      Option<syn::Type>  = None;
+        let mut verbosity: Option<syn::Ident> = None;
+        
+        while !input.is_empty() {
+            let option: syn::Ident = input.parse()?;
+            input.parse::<Token![=]>()?;
+            
+            match option.to_string().as_str() {
+                "root"      => root      = Some(input.parse()?),
+                "verbosity" => verbosity = Some(input.parse()?),
+                _           => return Err(syn::Error::new_spanned(option, "Unknown option; expected `root` or `verbosity`"))
+            }
+
+            if !input.is_empty() {
+                input.parse::<Token![,]>()?;
+            }
+        }
+
+        Ok(MainArgs {
+            root:      root.ok_or(input.error("Expected a root node type, such as `root = MyRoot`"))?,
+            verbosity: verbosity.unwrap_or(syn::Ident::new("NoDebug", proc_macro2::Span::call_site()))
+        })
+    }
+}
+
+/// Sets up and runs a `TreeSimple` with the given root node type via `TreeSimple::run()` until the
+/// tree terminates, so any target frame rate set during setup is honoured.
+/// The root node is created through its `new()` constructor, and the verbosity is given as a
+/// variant of `LoggerVerbosity`, which defaults to `NoDebug`.
+///
+/// The body of the annotated function is used as setup code. If the function takes no
+/// arguments, it is run before the tree is created. If it takes a single `&mut TreeSimple`
+/// argument, it is run after the tree is created but before the first frame is processed:
+/// ```rust, ignore
+/// #[node_tree::main(root = MyRoot, verbosity = All)]
+/// fn main(tree: &mut TreeSimple) {
+///     tree.set_target_fps(Some(60.0));
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn main(args: TokenStream, input: TokenStream) -> TokenStream {
+    let MainArgs { root, verbosity } = parse_macro_input!(args as MainArgs);
+    let func: syn::ItemFn = parse_macro_input!(input as syn::ItemFn);
+
+    let syn::ItemFn { attrs, vis, sig, block } = func;
+    let name:   &syn::Ident = &sig.ident;
+    let inputs: &punc::Punctuated<syn::FnArg, tok::Comma> = &sig.inputs;
+
+    if !matches!(sig.output, syn::ReturnType::Default) {
+        return syn::Error::new_spanned(&sig.output, "The `main` attribute only supports functions without a return type")
+            .to_compile_error()
+            .into();
+    }
+
+    // Run the setup code either before or after the tree is created, depending on whether it
+    // takes the tree as an argument.
+    let (pre_setup, post_setup): (TokenStream2, TokenStream2) = match inputs.len() {
+        0 => (quote! { (|| #block)(); }, TokenStream2::new()),
+        1 => (TokenStream2::new(), quote! { (|#inputs| #block)(&mut tree); }),
+        _ => return syn::Error::new_spanned(inputs, "The `main` attribute only supports functions with no arguments, or a single `&mut TreeSimple` argument")
+            .to_compile_error()
+            .into()
+    };
+
+    TokenStream::from(quote! {
+        #(#attrs)*
+        #vis fn #name() {
+            #pre_setup
+            let mut tree: Box<node_tree::trees::TreeSimple> = node_tree::trees::TreeSimple::new(<#root>::new(), node_tree::prelude::LoggerVerbosity::#verbosity);
+            #post_setup
+            tree.run();
         }
     })
 }